@@ -0,0 +1,179 @@
+//! Benchmarks of the upstream constitutive models and finite element blocks that the bindings wrap.
+//!
+//! The models and blocks implemented in this crate, such as plasticity and heat conduction, are timed in-process by `conspire.benchmark`.
+
+use conspire::{
+    constitutive::solid::{
+        elastic::{AlmansiHamel, Elastic},
+        hyperelastic::{
+            ArrudaBoyce, Fung, Gent, Hencky, MooneyRivlin, NeoHookean, SaintVenantKirchhoff,
+        },
+    },
+    fem::{
+        NodalCoordinates, NodalReferenceCoordinates,
+        block::{
+            Block, Connectivity, element::linear::Tetrahedron,
+            solid::elastic::ElasticFiniteElementBlock,
+        },
+    },
+    mechanics::{DeformationGradient, Scalar},
+};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+const DEFORMATION_GRADIENT: [[Scalar; 3]; 3] =
+    [[1.1, 0.05, -0.02], [0.03, 0.95, 0.04], [-0.01, 0.02, 1.05]];
+
+const DIVISIONS: [usize; 3] = [1, 2, 4];
+
+/// Divides each cube of a structured grid of the unit cube into the six tetrahedra around its main diagonal.
+fn unit_cube(divisions: usize) -> (Connectivity<4>, Vec<[Scalar; 3]>) {
+    let nodes = divisions + 1;
+    let node = |[i, j, k]: [usize; 3]| i + nodes * (j + nodes * k);
+    let nodal_coordinates: Vec<[Scalar; 3]> = (0..nodes)
+        .flat_map(|k| (0..nodes).flat_map(move |j| (0..nodes).map(move |i| [i, j, k])))
+        .map(|index| index.map(|a| a as Scalar / divisions as Scalar))
+        .collect();
+    let connectivity = (0..divisions)
+        .flat_map(|k| (0..divisions).flat_map(move |j| (0..divisions).map(move |i| [i, j, k])))
+        .flat_map(|corner| {
+            [
+                [0, 1, 2],
+                [0, 2, 1],
+                [1, 0, 2],
+                [1, 2, 0],
+                [2, 0, 1],
+                [2, 1, 0],
+            ]
+            .map(|axes| {
+                let mut index = corner;
+                let mut element = [node(index); 4];
+                axes.iter().zip(1..).for_each(|(&axis, vertex)| {
+                    index[axis] += 1;
+                    element[vertex] = node(index)
+                });
+                if volume(&nodal_coordinates, &element) < 0.0 {
+                    element.swap(2, 3)
+                }
+                element
+            })
+        })
+        .collect();
+    (connectivity, nodal_coordinates)
+}
+
+fn volume(nodal_coordinates: &[[Scalar; 3]], element: &[usize; 4]) -> Scalar {
+    let [a, b, c] = [1, 2, 3].map(|k| {
+        [0, 1, 2].map(|i| nodal_coordinates[element[k]][i] - nodal_coordinates[element[0]][i])
+    });
+    a[0] * (b[1] * c[2] - b[2] * c[1]) - a[1] * (b[0] * c[2] - b[2] * c[0])
+        + a[2] * (b[0] * c[1] - b[1] * c[0])
+}
+
+fn deformed(reference_nodal_coordinates: &[[Scalar; 3]]) -> NodalCoordinates {
+    NodalCoordinates::from(
+        reference_nodal_coordinates
+            .iter()
+            .map(|coordinates| {
+                DEFORMATION_GRADIENT.map(|row| (0..3).map(|j| row[j] * coordinates[j]).sum())
+            })
+            .collect::<Vec<[Scalar; 3]>>(),
+    )
+}
+
+macro_rules! benchmark_models {
+    ($criterion: ident, $($name: ident { $($parameter: ident: $value: expr),+ $(,)? }),+ $(,)?) => {
+        let deformation_gradient = DeformationGradient::from(DEFORMATION_GRADIENT);
+        let meshes: Vec<_> = DIVISIONS.iter().map(|&divisions| unit_cube(divisions)).collect();
+        $(
+            let model = $name { $($parameter: $value),+ };
+            $criterion.bench_function(concat!("cauchy_stress/", stringify!($name)), |bencher| {
+                bencher.iter(|| model.cauchy_stress(black_box(&deformation_gradient)).unwrap())
+            });
+            let mut forces = $criterion.benchmark_group(concat!("nodal_forces/", stringify!($name)));
+            for (connectivity, reference_nodal_coordinates) in &meshes {
+                let block: Block<_, Tetrahedron, 1, 3, 4, 4> = Block::from((
+                    $name { $($parameter: $value),+ },
+                    connectivity.clone(),
+                    NodalReferenceCoordinates::from(reference_nodal_coordinates.clone()),
+                ));
+                let nodal_coordinates = deformed(reference_nodal_coordinates);
+                forces.bench_with_input(
+                    BenchmarkId::from_parameter(connectivity.len()),
+                    &nodal_coordinates,
+                    |bencher, nodal_coordinates| {
+                        bencher
+                            .iter(|| block.nodal_forces(black_box(nodal_coordinates)).unwrap())
+                    },
+                );
+            }
+            forces.finish();
+            let mut stiffnesses =
+                $criterion.benchmark_group(concat!("nodal_stiffnesses/", stringify!($name)));
+            for (connectivity, reference_nodal_coordinates) in &meshes {
+                let block: Block<_, Tetrahedron, 1, 3, 4, 4> = Block::from((
+                    $name { $($parameter: $value),+ },
+                    connectivity.clone(),
+                    NodalReferenceCoordinates::from(reference_nodal_coordinates.clone()),
+                ));
+                let nodal_coordinates = deformed(reference_nodal_coordinates);
+                stiffnesses.bench_with_input(
+                    BenchmarkId::from_parameter(connectivity.len()),
+                    &nodal_coordinates,
+                    |bencher, nodal_coordinates| {
+                        bencher.iter(|| {
+                            block.nodal_stiffnesses(black_box(nodal_coordinates)).unwrap()
+                        })
+                    },
+                );
+            }
+            stiffnesses.finish();
+        )+
+    };
+}
+
+fn models(criterion: &mut Criterion) {
+    benchmark_models!(
+        criterion,
+        AlmansiHamel {
+            bulk_modulus: 13.0,
+            shear_modulus: 3.0,
+        },
+        ArrudaBoyce {
+            bulk_modulus: 13.0,
+            shear_modulus: 3.0,
+            number_of_links: 8.0,
+        },
+        Fung {
+            bulk_modulus: 13.0,
+            shear_modulus: 3.0,
+            extra_modulus: 1.0,
+            exponent: 1.0,
+        },
+        Gent {
+            bulk_modulus: 13.0,
+            shear_modulus: 3.0,
+            extensibility: 23.0,
+        },
+        Hencky {
+            bulk_modulus: 13.0,
+            shear_modulus: 3.0,
+        },
+        MooneyRivlin {
+            bulk_modulus: 13.0,
+            shear_modulus: 3.0,
+            extra_modulus: 1.0,
+        },
+        NeoHookean {
+            bulk_modulus: 13.0,
+            shear_modulus: 3.0,
+        },
+        SaintVenantKirchhoff {
+            bulk_modulus: 13.0,
+            shear_modulus: 3.0,
+        },
+    );
+}
+
+criterion_group!(benches, models);
+criterion_main!(benches);
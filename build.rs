@@ -8,7 +8,9 @@ use conspire::{
             doc::neo_hookean, doc::saint_venant_kirchhoff,
         },
     },
-    math::integrate::doc::{EXPLICIT, bogacki_shampine, dormand_prince, verner_8, verner_9},
+    math::integrate::doc::{
+        EXPLICIT, IMPLICIT, backward_euler, bogacki_shampine, dormand_prince, verner_8, verner_9,
+    },
 };
 
 fn main() -> Result<(), Error> {
@@ -23,6 +25,8 @@ fn math() -> Result<(), Error> {
         dormand_prince(),
         verner_8(),
         verner_9(),
+        vec![["math/integrate/implicit", IMPLICIT]],
+        backward_euler(),
     ];
     methods.iter().try_for_each(|method| {
         let path = method[0][0];
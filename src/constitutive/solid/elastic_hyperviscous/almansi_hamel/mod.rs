@@ -0,0 +1,5 @@
+super::elastic_hyperviscous!(
+    elastic_hyperviscous,
+    AlmansiHamel,
+    [bulk_modulus, shear_modulus, bulk_viscosity, shear_viscosity],
+);
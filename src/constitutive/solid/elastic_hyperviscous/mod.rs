@@ -0,0 +1,160 @@
+mod almansi_hamel;
+
+use pyo3::prelude::*;
+
+pub use almansi_hamel::AlmansiHamel;
+
+pub fn register_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<AlmansiHamel>()
+}
+
+macro_rules! elastic_hyperviscous {
+    ($module: ident, $model: ident, [$($parameter: ident),+ $(,)?] $(, $method: item)* $(,)?) => {
+        use crate::{
+            PyErrGlue,
+            math::{PyTensorRank2, PyTensorRank4},
+            constitutive::solid::elastic::shared
+        };
+        use conspire::{
+            constitutive::{
+                fluid::viscous::Viscous,
+                solid::{
+                    Solid,
+                    elastic_hyperviscous::ElasticHyperviscous,
+                    $module::$model as Inner,
+                    viscoelastic::Viscoelastic,
+                },
+            },
+            mechanics::Scalar,
+        };
+        use numpy::{PyArray2, PyArray4};
+        use pyo3::prelude::*;
+        shared!($model, $($parameter),+);
+        #[pymethods]
+        impl $model {
+            #[new]
+            fn new($($parameter: Scalar),+) -> Self {
+                Self (
+                    Inner {
+                        $($parameter),+
+                    }
+                )
+            }
+            $(
+                /// @private
+                #[getter]
+                pub fn $parameter(&self) -> Scalar {
+                    self.0.$parameter()
+                }
+            )+
+            #[doc = include_str!("cauchy_stress.md")]
+            fn cauchy_stress<'py>(
+                &self,
+                py: Python<'py>,
+                deformation_gradient: [[Scalar; 3]; 3],
+                deformation_gradient_rate: [[Scalar; 3]; 3],
+            ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+                PyTensorRank2::from(
+                    self.0.cauchy_stress(
+                        &deformation_gradient.into(),
+                        &deformation_gradient_rate.into(),
+                    )?
+                ).into_pyarray(py)
+            }
+            #[doc = include_str!("cauchy_rate_tangent_stiffness.md")]
+            fn cauchy_rate_tangent_stiffness<'py>(
+                &self,
+                py: Python<'py>,
+                deformation_gradient: [[Scalar; 3]; 3],
+                deformation_gradient_rate: [[Scalar; 3]; 3],
+            ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
+                PyTensorRank4::from(
+                    self.0.cauchy_rate_tangent_stiffness(
+                        &deformation_gradient.into(),
+                        &deformation_gradient_rate.into(),
+                    )?
+                ).into_pyarray(py)
+            }
+            #[doc = include_str!("first_piola_kirchhoff_stress.md")]
+            fn first_piola_kirchhoff_stress<'py>(
+                &self,
+                py: Python<'py>,
+                deformation_gradient: [[Scalar; 3]; 3],
+                deformation_gradient_rate: [[Scalar; 3]; 3],
+            ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+                PyTensorRank2::from(
+                    self.0.first_piola_kirchhoff_stress(
+                        &deformation_gradient.into(),
+                        &deformation_gradient_rate.into(),
+                    )?
+                ).into_pyarray(py)
+            }
+            #[doc = include_str!("first_piola_kirchhoff_rate_tangent_stiffness.md")]
+            fn first_piola_kirchhoff_rate_tangent_stiffness<'py>(
+                &self,
+                py: Python<'py>,
+                deformation_gradient: [[Scalar; 3]; 3],
+                deformation_gradient_rate: [[Scalar; 3]; 3],
+            ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
+                PyTensorRank4::from(
+                    self.0.first_piola_kirchhoff_rate_tangent_stiffness(
+                        &deformation_gradient.into(),
+                        &deformation_gradient_rate.into(),
+                    )?
+                ).into_pyarray(py)
+            }
+            #[doc = include_str!("second_piola_kirchhoff_stress.md")]
+            fn second_piola_kirchhoff_stress<'py>(
+                &self,
+                py: Python<'py>,
+                deformation_gradient: [[Scalar; 3]; 3],
+                deformation_gradient_rate: [[Scalar; 3]; 3],
+            ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+                PyTensorRank2::from(
+                    self.0.second_piola_kirchhoff_stress(
+                        &deformation_gradient.into(),
+                        &deformation_gradient_rate.into(),
+                    )?
+                ).into_pyarray(py)
+            }
+            #[doc = include_str!("second_piola_kirchhoff_rate_tangent_stiffness.md")]
+            fn second_piola_kirchhoff_rate_tangent_stiffness<'py>(
+                &self,
+                py: Python<'py>,
+                deformation_gradient: [[Scalar; 3]; 3],
+                deformation_gradient_rate: [[Scalar; 3]; 3],
+            ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
+                PyTensorRank4::from(
+                    self.0.second_piola_kirchhoff_rate_tangent_stiffness(
+                        &deformation_gradient.into(),
+                        &deformation_gradient_rate.into(),
+                    )?
+                ).into_pyarray(py)
+            }
+            #[doc = include_str!("viscous_dissipation.md")]
+            fn viscous_dissipation(
+                &self,
+                deformation_gradient: [[Scalar; 3]; 3],
+                deformation_gradient_rate: [[Scalar; 3]; 3],
+            ) -> Result<Scalar, PyErrGlue> {
+                Ok(self.0.viscous_dissipation(
+                    &deformation_gradient.into(),
+                    &deformation_gradient_rate.into(),
+                )?)
+            }
+            #[doc = include_str!("dissipation_potential.md")]
+            fn dissipation_potential(
+                &self,
+                deformation_gradient: [[Scalar; 3]; 3],
+                deformation_gradient_rate: [[Scalar; 3]; 3],
+            ) -> Result<Scalar, PyErrGlue> {
+                Ok(self.0.dissipation_potential(
+                    &deformation_gradient.into(),
+                    &deformation_gradient_rate.into(),
+                )?)
+            }
+            $($method)*
+        }
+    };
+}
+pub(crate) use elastic_hyperviscous;
@@ -0,0 +1,9 @@
+mod saint_venant_kirchhoff;
+
+use pyo3::prelude::*;
+
+pub use saint_venant_kirchhoff::SaintVenantKirchhoff;
+
+pub fn register_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<SaintVenantKirchhoff>()
+}
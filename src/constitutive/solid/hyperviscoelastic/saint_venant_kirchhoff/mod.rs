@@ -0,0 +1,16 @@
+use conspire::constitutive::solid::hyperviscoelastic::Hyperviscoelastic;
+
+crate::constitutive::solid::elastic_hyperviscous::elastic_hyperviscous!(
+    hyperviscoelastic,
+    SaintVenantKirchhoff,
+    [bulk_modulus, shear_modulus, bulk_viscosity, shear_viscosity],
+    #[doc = include_str!("helmholtz_free_energy_density.md")]
+    fn helmholtz_free_energy_density(
+        &self,
+        deformation_gradient: [[Scalar; 3]; 3],
+    ) -> Result<Scalar, PyErrGlue> {
+        Ok(self
+            .0
+            .helmholtz_free_energy_density(&deformation_gradient.into())?)
+    },
+);
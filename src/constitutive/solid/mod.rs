@@ -1,31 +1,62 @@
 pub mod elastic;
+pub mod elastic_hyperviscous;
 pub mod hyperelastic;
+pub mod hyperviscoelastic;
 
 use conspire::constitutive::solid::{
     elastic::doc::DOC as ELASTIC, hyperelastic::doc::DOC as HYPERELASTIC,
 };
 use pyo3::prelude::*;
 
+const ELASTIC_HYPERVISCOUS: &str = include_str!("elastic_hyperviscous/doc.md");
+const HYPERVISCOELASTIC: &str = include_str!("hyperviscoelastic/doc.md");
+
 pub fn register_module(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     let submodule_elastic = PyModule::new(py, "elastic")?;
+    let submodule_elastic_hyperviscous = PyModule::new(py, "elastic_hyperviscous")?;
     let submodule_hyperelastic = PyModule::new(py, "hyperelastic")?;
+    let submodule_hyperviscoelastic = PyModule::new(py, "hyperviscoelastic")?;
     submodule_elastic.setattr(
         "__doc__",
         ELASTIC.replace("```math", "$$").replace("```", "$$"),
     )?;
+    submodule_elastic_hyperviscous.setattr(
+        "__doc__",
+        ELASTIC_HYPERVISCOUS
+            .replace("```math", "$$")
+            .replace("```", "$$"),
+    )?;
     submodule_hyperelastic.setattr(
         "__doc__",
         HYPERELASTIC.replace("```math", "$$").replace("```", "$$"),
     )?;
+    submodule_hyperviscoelastic.setattr(
+        "__doc__",
+        HYPERVISCOELASTIC
+            .replace("```math", "$$")
+            .replace("```", "$$"),
+    )?;
     m.add_submodule(&submodule_elastic)?;
+    m.add_submodule(&submodule_elastic_hyperviscous)?;
     m.add_submodule(&submodule_hyperelastic)?;
+    m.add_submodule(&submodule_hyperviscoelastic)?;
     elastic::register_module(&submodule_elastic)?;
+    elastic_hyperviscous::register_module(&submodule_elastic_hyperviscous)?;
     hyperelastic::register_module(&submodule_hyperelastic)?;
+    hyperviscoelastic::register_module(&submodule_hyperviscoelastic)?;
     py.import("sys")?
         .getattr("modules")?
         .set_item("conspire.constitutive.solid.elastic", submodule_elastic)?;
+    py.import("sys")?.getattr("modules")?.set_item(
+        "conspire.constitutive.solid.elastic_hyperviscous",
+        submodule_elastic_hyperviscous,
+    )?;
     py.import("sys")?.getattr("modules")?.set_item(
         "conspire.constitutive.solid.hyperelastic",
         submodule_hyperelastic,
+    )?;
+    py.import("sys")?.getattr("modules")?.set_item(
+        "conspire.constitutive.solid.hyperviscoelastic",
+        submodule_hyperviscoelastic,
     )
 }
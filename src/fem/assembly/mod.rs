@@ -0,0 +1,192 @@
+use crate::{
+    PyErrGlue,
+    fem::{
+        Block, Model,
+        constraint::Constraint,
+        load::Load,
+        solve::{Conditions, NewtonRaphson},
+    },
+};
+use conspire::{fem::block::Connectivity, mechanics::Scalar};
+use ndarray::{Array2, Array4};
+use numpy::{PyArray2, PyArray4};
+use pyo3::{prelude::*, types::PyDict};
+
+/// Finite element assembly of blocks sharing nodes.
+#[pyclass]
+pub struct Assembly {
+    pub(super) blocks: Vec<Py<Block>>,
+}
+
+impl Assembly {
+    pub(super) fn forces(
+        &self,
+        py: Python,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Array2<Scalar>, PyErrGlue> {
+        let mut forces = Array2::zeros((nodal_coordinates.len(), 3));
+        self.try_for_each_block(py, |block| {
+            forces += &block.forces(py, Some(&nodal_coordinates))?;
+            Ok(())
+        })?;
+        Ok(forces)
+    }
+    pub(super) fn stiffnesses(
+        &self,
+        py: Python,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Array4<Scalar>, PyErrGlue> {
+        let nodes = nodal_coordinates.len();
+        let mut stiffnesses = Array4::zeros((nodes, nodes, 3, 3));
+        self.try_for_each_block(py, |block| {
+            stiffnesses += &block.stiffnesses(py, Some(&nodal_coordinates))?;
+            Ok(())
+        })?;
+        Ok(stiffnesses)
+    }
+    /// Evaluates each block in turn, numbering an inverted element across the blocks of the assembly.
+    fn try_for_each_block(
+        &self,
+        py: Python,
+        mut function: impl FnMut(&Block) -> Result<(), PyErrGlue>,
+    ) -> Result<(), PyErrGlue> {
+        self.blocks
+            .iter()
+            .try_fold(0, |offset, block| {
+                let block = block.borrow(py);
+                function(&block).map_err(|error| error.offset(offset))?;
+                Ok(offset + block.with_fields(py, |fields| fields.elements().connectivity().len()))
+            })
+            .map(|_| ())
+    }
+}
+
+#[pymethods]
+impl Assembly {
+    #[new]
+    pub(super) fn new(
+        py: Python,
+        blocks: Vec<(Model, Connectivity<4>)>,
+        reference_nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Self, PyErr> {
+        Ok(Self {
+            blocks: blocks
+                .into_iter()
+                .map(|(model, connectivity)| {
+                    Py::new(
+                        py,
+                        Block::new(
+                            py,
+                            model,
+                            connectivity,
+                            reference_nodal_coordinates.clone(),
+                            None,
+                        )?,
+                    )
+                })
+                .collect::<Result<_, PyErr>>()?,
+        })
+    }
+    /// The blocks in the assembly.
+    #[getter]
+    fn blocks(&self, py: Python) -> Vec<Py<Block>> {
+        self.blocks
+            .iter()
+            .map(|block| block.clone_ref(py))
+            .collect()
+    }
+    /// $$
+    /// A = \sum_{\mathrm{blocks}}\int_{\Omega_b} a\,dV
+    /// $$
+    ///
+    /// Elastic blocks define no free energy, so an assembly with an elastic block raises a `TypeError` like the block itself.
+    fn helmholtz_free_energy(
+        &self,
+        py: Python,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Scalar, PyErrGlue> {
+        let mut energy = 0.0;
+        self.try_for_each_block(py, |block| {
+            energy += block.energy(py, Some(&nodal_coordinates))?;
+            Ok(())
+        })?;
+        Ok(energy)
+    }
+    /// $$
+    /// \mathbf{f}_a = \sum_{\mathrm{blocks}}\mathbf{f}_a^b
+    /// $$
+    fn nodal_forces<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+        Ok(PyArray2::from_owned_array(
+            py,
+            self.forces(py, nodal_coordinates)?,
+        ))
+    }
+    /// $$
+    /// \mathbf{K}_{ab} = \sum_{\mathrm{blocks}}\mathbf{K}_{ab}^b
+    /// $$
+    fn nodal_stiffnesses<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
+        Ok(PyArray4::from_owned_array(
+            py,
+            self.stiffnesses(py, nodal_coordinates)?,
+        ))
+    }
+    /// Accepts the internal variables of each block from the latest evaluation of the nodal forces.
+    fn commit_state(&self, py: Python) {
+        self.blocks
+            .iter()
+            .for_each(|block| block.borrow(py).commit_state(py))
+    }
+    /// Discards the internal variables of each block from evaluations of the nodal forces since the last commit.
+    fn revert_state(&self, py: Python) {
+        self.blocks
+            .iter()
+            .for_each(|block| block.borrow(py).revert_state(py))
+    }
+    /// $$
+    /// \mathbf{f}_a(\mathbf{x}) + \mathbf{f}_a^\mathrm{load}(\mathbf{x}) = \mathbf{0}\quad\forall a\notin\mathrm{fixed}
+    /// $$
+    #[pyo3(signature = (nodal_coordinates, fixed_nodes, loads = Vec::new(), constraints = Vec::new(), **kwargs))]
+    fn solve<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        fixed_nodes: Vec<usize>,
+        loads: Vec<Load>,
+        constraints: Vec<Constraint>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErr> {
+        let reference_nodal_coordinates = self
+            .blocks
+            .first()
+            .map(|block| {
+                block.borrow(py).with_fields(py, |fields| {
+                    fields.elements().reference_nodal_coordinates().to_vec()
+                })
+            })
+            .unwrap_or_default();
+        let solution = NewtonRaphson::from_kwargs(kwargs)?.solve(
+            py,
+            nodal_coordinates,
+            Conditions {
+                reference_nodal_coordinates: &reference_nodal_coordinates,
+                fixed_nodes: &fixed_nodes,
+                loads: &loads,
+                constraints: &constraints,
+            },
+            |nodal_coordinates| self.forces(py, nodal_coordinates),
+            |nodal_coordinates| self.stiffnesses(py, nodal_coordinates),
+        )?;
+        self.blocks
+            .iter()
+            .try_for_each(|block| block.borrow(py).set_coordinates(py, solution.clone()))?;
+        Ok(PyArray2::from_owned_array(py, Array2::from(solution)))
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{
+    PyErrGlue,
+    constitutive::solid::{
+        elastic::AlmansiHamel,
+        hyperelastic::{
+            ArrudaBoyce, Fung, Gent, Hencky, MooneyRivlin, NeoHookean, SaintVenantKirchhoff,
+        },
+    },
+    fem::{
+        Block, Model,
+        block::{Elements, Tensor, plastic::Plasticity, thermal::Conduction},
+        mesh::Mesh,
+    },
+};
+use conspire::mechanics::Scalar;
+use pyo3::{prelude::*, types::PyDict};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+pub fn register_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(benchmark, m)?)
+}
+
+const DEFORMATION_GRADIENT: Tensor = [[1.1, 0.05, -0.02], [0.03, 0.95, 0.04], [-0.01, 0.02, 1.05]];
+
+struct Settings {
+    samples: usize,
+    warm_up_time: Duration,
+    measurement_time: Scalar,
+    filter: Option<String>,
+}
+
+impl Settings {
+    fn selects(&self, id: &str) -> bool {
+        self.filter
+            .as_ref()
+            .is_none_or(|filter| id.contains(filter.as_str()))
+    }
+}
+
+/// Times per iteration in seconds of each sample.
+struct Measurement {
+    iterations: usize,
+    samples: Vec<Scalar>,
+}
+
+impl Measurement {
+    fn into_dict(mut self, py: Python) -> PyResult<Bound<PyDict>> {
+        self.samples.sort_unstable_by(Scalar::total_cmp);
+        let count = self.samples.len();
+        let mean = self.samples.iter().sum::<Scalar>() / count as Scalar;
+        let variance = self
+            .samples
+            .iter()
+            .map(|sample| (sample - mean).powi(2))
+            .sum::<Scalar>()
+            / (count - 1).max(1) as Scalar;
+        let median = if count.is_multiple_of(2) {
+            0.5 * (self.samples[count / 2 - 1] + self.samples[count / 2])
+        } else {
+            self.samples[count / 2]
+        };
+        let statistics = PyDict::new(py);
+        statistics.set_item("mean", mean)?;
+        statistics.set_item("median", median)?;
+        statistics.set_item("std", variance.sqrt())?;
+        statistics.set_item("min", self.samples[0])?;
+        statistics.set_item("max", self.samples[count - 1])?;
+        statistics.set_item("iterations", self.iterations)?;
+        statistics.set_item("samples", count)?;
+        Ok(statistics)
+    }
+}
+
+/// Runs the routine for the warm-up time to estimate its cost, then times each sample over enough iterations to fill the measurement time.
+fn measure<T>(
+    settings: &Settings,
+    mut routine: impl FnMut() -> Result<T, PyErrGlue>,
+) -> Result<Measurement, PyErrGlue> {
+    let start = Instant::now();
+    let mut iterations = 0;
+    loop {
+        black_box(routine()?);
+        iterations += 1;
+        if start.elapsed() >= settings.warm_up_time {
+            break;
+        }
+    }
+    let estimate = start.elapsed().as_secs_f64() / iterations as Scalar;
+    let iterations = ((settings.measurement_time / settings.samples as Scalar / estimate).ceil()
+        as usize)
+        .max(1);
+    let samples = (0..settings.samples)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                black_box(routine()?);
+            }
+            Ok(start.elapsed().as_secs_f64() / iterations as Scalar)
+        })
+        .collect::<Result<_, PyErrGlue>>()?;
+    Ok(Measurement {
+        iterations,
+        samples,
+    })
+}
+
+fn deformed(reference_nodal_coordinates: &[[Scalar; 3]]) -> Vec<[Scalar; 3]> {
+    reference_nodal_coordinates
+        .iter()
+        .map(|coordinates| {
+            DEFORMATION_GRADIENT.map(|row| (0..3).map(|j| row[j] * coordinates[j]).sum())
+        })
+        .collect()
+}
+
+fn models(py: Python) -> PyResult<Vec<(&'static str, Bound<PyAny>)>> {
+    let neo_hookean = py.get_type::<NeoHookean>().call1((13.0, 3.0))?;
+    Ok(vec![
+        (
+            "AlmansiHamel",
+            py.get_type::<AlmansiHamel>().call1((13.0, 3.0))?,
+        ),
+        (
+            "ArrudaBoyce",
+            py.get_type::<ArrudaBoyce>().call1((13.0, 3.0, 8.0))?,
+        ),
+        ("Fung", py.get_type::<Fung>().call1((13.0, 3.0, 1.0, 1.0))?),
+        ("Gent", py.get_type::<Gent>().call1((13.0, 3.0, 23.0))?),
+        ("Hencky", py.get_type::<Hencky>().call1((13.0, 3.0))?),
+        (
+            "MooneyRivlin",
+            py.get_type::<MooneyRivlin>().call1((13.0, 3.0, 1.0))?,
+        ),
+        ("NeoHookean", neo_hookean.clone()),
+        (
+            "Plasticity",
+            py.get_type::<Plasticity>().call1((neo_hookean, 0.1, 1.0))?,
+        ),
+        (
+            "SaintVenantKirchhoff",
+            py.get_type::<SaintVenantKirchhoff>().call1((13.0, 3.0))?,
+        ),
+    ])
+}
+
+/// Times the constitutive models and blocks in-process on structured tetrahedral meshes of the unit cube with the given numbers of divisions per edge.
+/// Each benchmark is identified by its operation, model, and number of elements, and the returned statistics are in seconds per iteration.
+/// Only the benchmarks whose identifiers contain the filter are run.
+#[pyfunction]
+#[pyo3(signature = (divisions = vec![1, 2, 4], samples = 10, warm_up_time = 0.05, measurement_time = 0.2, filter = None))]
+fn benchmark<'py>(
+    py: Python<'py>,
+    divisions: Vec<usize>,
+    samples: usize,
+    warm_up_time: Scalar,
+    measurement_time: Scalar,
+    filter: Option<String>,
+) -> Result<Bound<'py, PyDict>, PyErr> {
+    if samples == 0 {
+        return Err(PyErrGlue::new("The number of samples must be positive.").into());
+    }
+    if warm_up_time.is_nan() || warm_up_time < 0.0 {
+        return Err(PyErrGlue::new("The warm-up time must be nonnegative.").into());
+    }
+    if measurement_time.is_nan() || measurement_time <= 0.0 {
+        return Err(PyErrGlue::new("The measurement time must be positive.").into());
+    }
+    let settings = Settings {
+        samples,
+        warm_up_time: Duration::from_secs_f64(warm_up_time),
+        measurement_time,
+        filter,
+    };
+    let meshes = divisions
+        .iter()
+        .map(|&divisions| Mesh::unit_cube(divisions))
+        .collect::<Result<Vec<_>, _>>()?;
+    let results = PyDict::new(py);
+    for (name, model) in models(py)? {
+        let id = format!("cauchy_stress/{name}");
+        if settings.selects(&id) {
+            let reference_nodal_coordinates = vec![
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ];
+            let nodal_coordinates = deformed(&reference_nodal_coordinates);
+            let block = Block::new(
+                py,
+                model.extract::<Model>()?,
+                vec![[0, 1, 2, 3]],
+                reference_nodal_coordinates,
+                None,
+            )?;
+            let measurement = block.with_fields(py, |fields| {
+                measure(&settings, || fields.cauchy_stresses(&nodal_coordinates))
+            })?;
+            results.set_item(id, measurement.into_dict(py)?)?;
+        }
+        for (connectivity, reference_nodal_coordinates) in &meshes {
+            let elements = connectivity.len();
+            let ids = [
+                format!("nodal_forces/{name}/{elements}"),
+                format!("nodal_stiffnesses/{name}/{elements}"),
+            ];
+            if !ids.iter().any(|id| settings.selects(id)) {
+                continue;
+            }
+            let block = Block::new(
+                py,
+                model.extract::<Model>()?,
+                connectivity.clone(),
+                reference_nodal_coordinates.clone(),
+                None,
+            )?;
+            let [forces, stiffnesses] = ids;
+            block.with_fields_mut(py, |fields| {
+                fields.set_nodal_coordinates(deformed(reference_nodal_coordinates))?;
+                if settings.selects(&forces) {
+                    let measurement = measure(&settings, || fields.nodal_forces(None))?;
+                    results.set_item(forces, measurement.into_dict(py)?)?;
+                }
+                if settings.selects(&stiffnesses) {
+                    let measurement = measure(&settings, || fields.nodal_stiffnesses(None))?;
+                    results.set_item(stiffnesses, measurement.into_dict(py)?)?;
+                }
+                Ok::<_, PyErr>(())
+            })?;
+        }
+    }
+    for (connectivity, reference_nodal_coordinates) in &meshes {
+        let id = format!("nodal_conductances/HeatConduction/{}", connectivity.len());
+        if settings.selects(&id) {
+            let elements = Elements::new(connectivity, reference_nodal_coordinates)?;
+            let deformation_gradients =
+                elements.deformation_gradients(&deformed(reference_nodal_coordinates))?;
+            let conduction = Conduction::new(1.0);
+            let measurement = measure(&settings, || {
+                Ok(conduction.nodal_conductances(&elements, &deformation_gradients))
+            })?;
+            results.set_item(id, measurement.into_dict(py)?)?;
+        }
+    }
+    Ok(results)
+}
@@ -1,5 +1,10 @@
-use crate::{PyErrGlue, constitutive::solid::elastic as constitutive, fem::call_method};
+use crate::{
+    PyErrGlue,
+    constitutive::solid::elastic as constitutive,
+    fem::block::{self, Elements, Fields, Tangent, Tensor, tangent, tensor},
+};
 use conspire::{
+    constitutive::solid::elastic::Elastic,
     fem::{
         NodalCoordinates, NodalReferenceCoordinates,
         block::{
@@ -9,8 +14,7 @@ use conspire::{
     },
     mechanics::Scalar,
 };
-use ndarray::Array;
-use numpy::{PyArray2, PyArray4};
+use ndarray::{Array, Array2, Array4};
 use pyo3::prelude::*;
 
 pub const G: usize = 1;
@@ -18,6 +22,29 @@ pub const M: usize = 3;
 pub const N: usize = 4;
 pub const P: usize = N;
 
+/// Evaluates at the given nodal coordinates, or by reference in the stored current configuration if none are given.
+pub fn at<T>(
+    current: &NodalCoordinates,
+    nodal_coordinates: Option<&[[Scalar; 3]]>,
+    function: impl FnOnce(&NodalCoordinates) -> T,
+) -> T {
+    match nodal_coordinates {
+        Some(nodal_coordinates) => function(&NodalCoordinates::from(nodal_coordinates.to_vec())),
+        None => function(current),
+    }
+}
+
+/// Moves the stored current configuration to the given nodal coordinates.
+pub fn set(
+    elements: &Elements,
+    current: &mut NodalCoordinates,
+    nodal_coordinates: Vec<[Scalar; 3]>,
+) -> Result<(), PyErrGlue> {
+    elements.check_nodes(&nodal_coordinates)?;
+    *current = NodalCoordinates::from(nodal_coordinates);
+    Ok(())
+}
+
 #[pyclass]
 pub enum ElasticBlock {
     AlmansiHamel(Py<AlmansiHamel>),
@@ -46,29 +73,26 @@ impl ElasticBlock {
                     shear_modulus,
                     connectivity,
                     reference_nodal_coordinates,
-                );
+                )?;
                 Ok(Self::AlmansiHamel(Py::new(py, block)?))
             }
         }
     }
-    fn nodal_forces<'py>(
-        &self,
-        py: Python<'py>,
-        nodal_coordinates: Vec<[Scalar; 3]>,
-    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+}
+
+impl ElasticBlock {
+    pub(crate) fn with_fields<T>(&self, py: Python, function: impl FnOnce(&dyn Fields) -> T) -> T {
         match self {
-            Self::AlmansiHamel(model) => call_method!(model, py, "nodal_forces", nodal_coordinates),
+            Self::AlmansiHamel(model) => function(&*model.borrow(py)),
         }
     }
-    fn nodal_stiffnesses<'py>(
+    pub(crate) fn with_fields_mut<T>(
         &self,
-        py: Python<'py>,
-        nodal_coordinates: Vec<[Scalar; 3]>,
-    ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
+        py: Python,
+        function: impl FnOnce(&mut dyn Fields) -> T,
+    ) -> T {
         match self {
-            Self::AlmansiHamel(model) => {
-                call_method!(model, py, "nodal_stiffnesses", nodal_coordinates)
-            }
+            Self::AlmansiHamel(model) => function(&mut *model.borrow_mut(py)),
         }
     }
 }
@@ -77,6 +101,129 @@ impl ElasticBlock {
 pub struct AlmansiHamel {
     block:
         Block<conspire::constitutive::solid::elastic::AlmansiHamel, LinearTetrahedron, G, M, N, P>,
+    elements: Elements,
+    model: conspire::constitutive::solid::elastic::AlmansiHamel,
+    nodal_coordinates: NodalCoordinates,
+}
+
+impl Fields for AlmansiHamel {
+    fn elements(&self) -> &Elements {
+        &self.elements
+    }
+    fn elements_mut(&mut self) -> &mut Elements {
+        &mut self.elements
+    }
+    fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Result<Tensor, PyErrGlue> {
+        Ok(tensor(
+            self.model
+                .cauchy_stress(&(*deformation_gradient).into())?
+                .into(),
+        ))
+    }
+    fn first_piola_kirchhoff_tangent_stiffness(
+        &self,
+        deformation_gradient: &Tensor,
+    ) -> Result<Tangent, PyErrGlue> {
+        Ok(tangent(
+            self.model
+                .first_piola_kirchhoff_tangent_stiffness(&(*deformation_gradient).into())?
+                .into(),
+        ))
+    }
+    fn helmholtz_free_energy_density(
+        &self,
+        _deformation_gradient: &Tensor,
+    ) -> Option<Result<Scalar, PyErrGlue>> {
+        None
+    }
+    fn parameters(&self) -> Vec<(&'static str, Scalar)> {
+        vec![
+            ("bulk_modulus", self.model.bulk_modulus),
+            ("shear_modulus", self.model.shear_modulus),
+        ]
+    }
+    fn helmholtz_free_energy_with(
+        &self,
+        _parameters: &[Scalar],
+        _nodal_coordinates: &[[Scalar; 3]],
+    ) -> Option<Result<Scalar, PyErrGlue>> {
+        None
+    }
+    fn nodal_forces_with(
+        &self,
+        parameters: &[Scalar],
+        nodal_coordinates: &[[Scalar; 3]],
+    ) -> Result<Vec<Vec<Scalar>>, PyErrGlue> {
+        Ok(self
+            .with_parameters(parameters)?
+            .nodal_forces(&NodalCoordinates::from(nodal_coordinates.to_vec()))?
+            .into())
+    }
+    fn helmholtz_free_energy(
+        &self,
+        _nodal_coordinates: Option<&[[Scalar; 3]]>,
+    ) -> Option<Result<Scalar, PyErrGlue>> {
+        None
+    }
+    fn nodal_coordinates(&self) -> Vec<[Scalar; 3]> {
+        self.nodal_coordinates.clone().into()
+    }
+    fn set_nodal_coordinates(
+        &mut self,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<(), PyErrGlue> {
+        set(
+            &self.elements,
+            &mut self.nodal_coordinates,
+            nodal_coordinates,
+        )
+    }
+    fn nodal_forces(
+        &mut self,
+        nodal_coordinates: Option<&[[Scalar; 3]]>,
+    ) -> Result<Array2<Scalar>, PyErrGlue> {
+        let forces: Vec<Vec<Scalar>> = at(&self.nodal_coordinates, nodal_coordinates, |x| {
+            self.block.nodal_forces(x)
+        })?
+        .into();
+        Ok(Array2::from_shape_vec(
+            (forces.len(), 3),
+            forces.into_iter().flatten().collect(),
+        )?)
+    }
+    fn nodal_stiffnesses(
+        &self,
+        nodal_coordinates: Option<&[[Scalar; 3]]>,
+    ) -> Result<Array4<Scalar>, PyErrGlue> {
+        let nodes = self.elements.reference_nodal_coordinates().len();
+        let stiffnesses = at(&self.nodal_coordinates, nodal_coordinates, |x| {
+            self.block.nodal_stiffnesses(x)
+        })?;
+        Ok(Array::from_shape_vec(
+            (nodes, nodes, 3, 3),
+            stiffnesses.into(),
+        )?)
+    }
+}
+
+impl AlmansiHamel {
+    fn with_parameters(
+        &self,
+        parameters: &[Scalar],
+    ) -> Result<
+        Block<conspire::constitutive::solid::elastic::AlmansiHamel, LinearTetrahedron, G, M, N, P>,
+        PyErrGlue,
+    > {
+        let [bulk_modulus, shear_modulus] = block::parameters(parameters)?;
+        Ok(Block::from((
+            conspire::constitutive::solid::elastic::AlmansiHamel {
+                bulk_modulus,
+                shear_modulus,
+            },
+            self.elements.connectivity().clone(),
+            NodalReferenceCoordinates::from(self.elements.reference_nodal_coordinates().to_vec()),
+        )))
+    }
 }
 
 #[pymethods]
@@ -87,8 +234,14 @@ impl AlmansiHamel {
         shear_modulus: Scalar,
         connectivity: Connectivity<N>,
         reference_nodal_coordinates: Vec<[Scalar; 3]>,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, PyErr> {
+        Ok(Self {
+            elements: Elements::new(&connectivity, &reference_nodal_coordinates)?,
+            nodal_coordinates: NodalCoordinates::from(reference_nodal_coordinates.clone()),
+            model: conspire::constitutive::solid::elastic::AlmansiHamel {
+                bulk_modulus,
+                shear_modulus,
+            },
             block: Block::from((
                 conspire::constitutive::solid::elastic::AlmansiHamel {
                     bulk_modulus,
@@ -97,33 +250,6 @@ impl AlmansiHamel {
                 connectivity,
                 NodalReferenceCoordinates::from(reference_nodal_coordinates),
             )),
-        }
-    }
-    fn nodal_forces<'py>(
-        &self,
-        py: Python<'py>,
-        nodal_coordinates: Vec<[Scalar; 3]>,
-    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
-        let forces: Vec<Vec<Scalar>> = self
-            .block
-            .nodal_forces(&NodalCoordinates::from(nodal_coordinates))?
-            .into();
-        Ok(PyArray2::from_vec2(py, &forces)?)
-    }
-    fn nodal_stiffnesses<'py>(
-        &self,
-        py: Python<'py>,
-        nodal_coordinates: Vec<[Scalar; 3]>,
-    ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
-        let nodes = nodal_coordinates.len();
-        Ok(PyArray4::from_owned_array(
-            py,
-            Array::from_shape_vec(
-                (nodes, nodes, 3, 3),
-                self.block
-                    .nodal_stiffnesses(&NodalCoordinates::from(nodal_coordinates))?
-                    .into(),
-            )?,
-        ))
+        })
     }
 }
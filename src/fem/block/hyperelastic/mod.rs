@@ -1,12 +1,14 @@
 use crate::{
     PyErrGlue,
     constitutive::solid::hyperelastic as constitutive,
-    fem::{
-        block::elastic::{G, M, N, P},
-        call_method,
+    fem::block::{
+        self, Elements, Fields, Tangent, Tensor,
+        elastic::{G, M, N, P, at, set},
+        tangent, tensor,
     },
 };
 use conspire::{
+    constitutive::solid::{elastic::Elastic, hyperelastic::Hyperelastic},
     fem::{
         NodalCoordinates, NodalReferenceCoordinates,
         block::{
@@ -19,8 +21,7 @@ use conspire::{
     },
     mechanics::Scalar,
 };
-use ndarray::Array;
-use numpy::{PyArray2, PyArray4};
+use ndarray::{Array, Array2, Array4};
 use pyo3::prelude::*;
 
 #[pyclass]
@@ -45,29 +46,13 @@ enum HyperelasticModel<'py> {
     SaintVenantKirchhoff(Bound<'py, constitutive::SaintVenantKirchhoff>),
 }
 
-macro_rules! match_model {
-    ($self: ident, $py: ident, $name: literal, $nodal_coordinates: ident) => {
-        match $self {
-            Self::ArrudaBoyce(model) => call_method!(model, $py, $name, $nodal_coordinates),
-            Self::Fung(model) => call_method!(model, $py, $name, $nodal_coordinates),
-            Self::Gent(model) => call_method!(model, $py, $name, $nodal_coordinates),
-            Self::Hencky(model) => call_method!(model, $py, $name, $nodal_coordinates),
-            Self::MooneyRivlin(model) => call_method!(model, $py, $name, $nodal_coordinates),
-            Self::NeoHookean(model) => call_method!(model, $py, $name, $nodal_coordinates),
-            Self::SaintVenantKirchhoff(model) => {
-                call_method!(model, $py, $name, $nodal_coordinates)
-            }
-        }
-    };
-}
-
 macro_rules! hyperelastic_block_inner {
     ($py: ident, $model: ident, $name: ident, $connectivity: ident, $reference_nodal_coordinates: ident, $($parameter: expr),+ $(,)?) => {
         Ok(Self::$name(Py::new($py, $name::new(
             $($model.getattr(stringify!($parameter))?.extract()?),+,
             $connectivity,
             $reference_nodal_coordinates,
-        ))?))
+        )?)?))
     }
 }
 
@@ -109,26 +94,34 @@ impl HyperelasticBlock {
             [bulk_modulus, shear_modulus],
         )
     }
-    fn helmholtz_free_energy(
-        &self,
-        py: Python,
-        nodal_coordinates: Vec<[Scalar; 3]>,
-    ) -> Result<Scalar, PyErrGlue> {
-        match_model!(self, py, "helmholtz_free_energy", nodal_coordinates)
-    }
-    fn nodal_forces<'py>(
-        &self,
-        py: Python<'py>,
-        nodal_coordinates: Vec<[Scalar; 3]>,
-    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
-        match_model!(self, py, "nodal_forces", nodal_coordinates)
+}
+
+impl HyperelasticBlock {
+    pub(crate) fn with_fields<T>(&self, py: Python, function: impl FnOnce(&dyn Fields) -> T) -> T {
+        match self {
+            Self::ArrudaBoyce(model) => function(&*model.borrow(py)),
+            Self::Fung(model) => function(&*model.borrow(py)),
+            Self::Gent(model) => function(&*model.borrow(py)),
+            Self::Hencky(model) => function(&*model.borrow(py)),
+            Self::MooneyRivlin(model) => function(&*model.borrow(py)),
+            Self::NeoHookean(model) => function(&*model.borrow(py)),
+            Self::SaintVenantKirchhoff(model) => function(&*model.borrow(py)),
+        }
     }
-    fn nodal_stiffnesses<'py>(
+    pub(crate) fn with_fields_mut<T>(
         &self,
-        py: Python<'py>,
-        nodal_coordinates: Vec<[Scalar; 3]>,
-    ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
-        match_model!(self, py, "nodal_stiffnesses", nodal_coordinates)
+        py: Python,
+        function: impl FnOnce(&mut dyn Fields) -> T,
+    ) -> T {
+        match self {
+            Self::ArrudaBoyce(model) => function(&mut *model.borrow_mut(py)),
+            Self::Fung(model) => function(&mut *model.borrow_mut(py)),
+            Self::Gent(model) => function(&mut *model.borrow_mut(py)),
+            Self::Hencky(model) => function(&mut *model.borrow_mut(py)),
+            Self::MooneyRivlin(model) => function(&mut *model.borrow_mut(py)),
+            Self::NeoHookean(model) => function(&mut *model.borrow_mut(py)),
+            Self::SaintVenantKirchhoff(model) => function(&mut *model.borrow_mut(py)),
+        }
     }
 }
 
@@ -137,6 +130,136 @@ macro_rules! hyperelastic {
         #[pyclass]
         pub struct $model {
             block: Block<conspire::constitutive::solid::hyperelastic::$model, $element, G, M, $n, P>,
+            elements: Elements,
+            model: conspire::constitutive::solid::hyperelastic::$model,
+            nodal_coordinates: NodalCoordinates,
+        }
+        impl Fields for $model {
+            fn elements(&self) -> &Elements {
+                &self.elements
+            }
+            fn elements_mut(&mut self) -> &mut Elements {
+                &mut self.elements
+            }
+            fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Result<Tensor, PyErrGlue> {
+                Ok(tensor(
+                    self.model
+                        .cauchy_stress(&(*deformation_gradient).into())?
+                        .into(),
+                ))
+            }
+            fn first_piola_kirchhoff_tangent_stiffness(
+                &self,
+                deformation_gradient: &Tensor,
+            ) -> Result<Tangent, PyErrGlue> {
+                Ok(tangent(
+                    self.model
+                        .first_piola_kirchhoff_tangent_stiffness(&(*deformation_gradient).into())?
+                        .into(),
+                ))
+            }
+            fn helmholtz_free_energy_density(
+                &self,
+                deformation_gradient: &Tensor,
+            ) -> Option<Result<Scalar, PyErrGlue>> {
+                Some(
+                    self.model
+                        .helmholtz_free_energy_density(&(*deformation_gradient).into())
+                        .map_err(PyErrGlue::from),
+                )
+            }
+            fn parameters(&self) -> Vec<(&'static str, Scalar)> {
+                vec![$((stringify!($parameter), self.model.$parameter)),+]
+            }
+            fn helmholtz_free_energy_with(
+                &self,
+                parameters: &[Scalar],
+                nodal_coordinates: &[[Scalar; 3]],
+            ) -> Option<Result<Scalar, PyErrGlue>> {
+                Some(
+                    self.with_parameters(parameters).and_then(|block| {
+                        block
+                                .helmholtz_free_energy(&NodalCoordinates::from(nodal_coordinates.to_vec()))
+                            .map_err(PyErrGlue::from)
+                    }),
+                )
+            }
+            fn nodal_forces_with(
+                &self,
+                parameters: &[Scalar],
+                nodal_coordinates: &[[Scalar; 3]],
+            ) -> Result<Vec<Vec<Scalar>>, PyErrGlue> {
+                Ok(self
+                    .with_parameters(parameters)?
+                    .nodal_forces(&NodalCoordinates::from(nodal_coordinates.to_vec()))?
+                    .into())
+            }
+            fn helmholtz_free_energy(
+                &self,
+                nodal_coordinates: Option<&[[Scalar; 3]]>,
+            ) -> Option<Result<Scalar, PyErrGlue>> {
+                Some(
+                    at(&self.nodal_coordinates, nodal_coordinates, |x| {
+                        self.block.helmholtz_free_energy(x)
+                    })
+                    .map_err(PyErrGlue::from),
+                )
+            }
+            fn nodal_coordinates(&self) -> Vec<[Scalar; 3]> {
+                self.nodal_coordinates.clone().into()
+            }
+            fn set_nodal_coordinates(
+                &mut self,
+                nodal_coordinates: Vec<[Scalar; 3]>,
+            ) -> Result<(), PyErrGlue> {
+                set(&self.elements, &mut self.nodal_coordinates, nodal_coordinates)
+            }
+            fn nodal_forces(
+                &mut self,
+                nodal_coordinates: Option<&[[Scalar; 3]]>,
+            ) -> Result<Array2<Scalar>, PyErrGlue> {
+                let forces: Vec<Vec<Scalar>> = at(&self.nodal_coordinates, nodal_coordinates, |x| {
+                    self.block.nodal_forces(x)
+                })?
+                .into();
+                Ok(Array2::from_shape_vec(
+                    (forces.len(), 3),
+                    forces.into_iter().flatten().collect(),
+                )?)
+            }
+            fn nodal_stiffnesses(
+                &self,
+                nodal_coordinates: Option<&[[Scalar; 3]]>,
+            ) -> Result<Array4<Scalar>, PyErrGlue> {
+                let nodes = self.elements.reference_nodal_coordinates().len();
+                let stiffnesses = at(&self.nodal_coordinates, nodal_coordinates, |x| {
+                    self.block.nodal_stiffnesses(x)
+                })?;
+                Ok(Array::from_shape_vec(
+                    (nodes, nodes, 3, 3),
+                    stiffnesses.into(),
+                )?)
+            }
+        }
+        impl $model {
+            fn with_parameters(
+                &self,
+                parameters: &[Scalar],
+            ) -> Result<
+                Block<conspire::constitutive::solid::hyperelastic::$model, $element, G, M, $n, P>,
+                PyErrGlue,
+            > {
+                let [$($parameter),+] = block::parameters(parameters)?;
+                Ok(Block::from((
+                    conspire::constitutive::solid::hyperelastic::$model {
+                        $($parameter),+
+                    },
+                    self.elements.connectivity().clone(),
+                    NodalReferenceCoordinates::from(
+                        self.elements.reference_nodal_coordinates().to_vec(),
+                    ),
+                )))
+            }
         }
         #[pymethods]
         impl $model {
@@ -145,8 +268,13 @@ macro_rules! hyperelastic {
                 $($parameter: Scalar),+,
                 connectivity: Connectivity<$n>,
                 reference_nodal_coordinates: Vec<[Scalar; 3]>,
-            ) -> Self {
-                Self {
+            ) -> Result<Self, PyErr> {
+                Ok(Self {
+                    elements: Elements::new(&connectivity, &reference_nodal_coordinates)?,
+                    nodal_coordinates: NodalCoordinates::from(reference_nodal_coordinates.clone()),
+                    model: conspire::constitutive::solid::hyperelastic::$model {
+                        $($parameter),+
+                    },
                     block: Block::from((
                         conspire::constitutive::solid::hyperelastic::$model {
                             $($parameter),+
@@ -154,42 +282,7 @@ macro_rules! hyperelastic {
                         connectivity,
                         NodalReferenceCoordinates::from(reference_nodal_coordinates),
                     )),
-                }
-            }
-            fn helmholtz_free_energy(
-                &self,
-                nodal_coordinates: Vec<[Scalar; 3]>,
-            ) -> Result<Scalar, PyErrGlue> {
-                Ok(self
-                    .block
-                    .helmholtz_free_energy(&NodalCoordinates::from(nodal_coordinates))?)
-            }
-            fn nodal_forces<'py>(
-                &self,
-                py: Python<'py>,
-                nodal_coordinates: Vec<[Scalar; 3]>,
-            ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
-                let forces: Vec<Vec<Scalar>> = self
-                    .block
-                    .nodal_forces(&NodalCoordinates::from(nodal_coordinates))?
-                    .into();
-                Ok(PyArray2::from_vec2(py, &forces)?)
-            }
-            fn nodal_stiffnesses<'py>(
-                &self,
-                py: Python<'py>,
-                nodal_coordinates: Vec<[Scalar; 3]>,
-            ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
-                let nodes = nodal_coordinates.len();
-                Ok(PyArray4::from_owned_array(
-                    py,
-                    Array::from_shape_vec(
-                        (nodes, nodes, 3, 3),
-                        self.block
-                            .nodal_stiffnesses(&NodalCoordinates::from(nodal_coordinates))?
-                            .into(),
-                    )?,
-                ))
+                })
             }
         }
     };
@@ -1,2 +1,593 @@
 pub mod elastic;
 pub mod hyperelastic;
+pub mod plastic;
+pub mod thermal;
+pub mod viscoelastic;
+
+use crate::{
+    PyErrGlue,
+    fem::modal::nodal_mass_entries,
+    math::linalg::{
+        iterative::{Preconditioner, conjugate_gradient},
+        sparse::CompressedSparseRows,
+    },
+};
+use conspire::{fem::block::Connectivity, mechanics::Scalar};
+use ndarray::{Array2, Array4};
+use numpy::{Element, PyReadonlyArray2, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+pub type Tensor = [[Scalar; 3]; 3];
+
+pub type Tangent = [[Tensor; 3]; 3];
+
+const STANDARD_GRADIENT_VECTORS: [[Scalar; 3]; 4] = [
+    [-1.0, -1.0, -1.0],
+    [1.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0],
+    [0.0, 0.0, 1.0],
+];
+
+/// Linear tetrahedral elements in the reference configuration.
+///
+/// The upstream block keeps its connectivity and reference coordinates private,
+/// so these are the copies used for element fields, projections, and output.
+#[derive(Clone)]
+pub struct Elements {
+    connectivity: Connectivity<4>,
+    reference_nodal_coordinates: Vec<[Scalar; 3]>,
+    gradient_vectors: Vec<[[Scalar; 3]; 4]>,
+    node_sets: Vec<(String, Vec<usize>)>,
+    volumes: Vec<Scalar>,
+}
+
+impl Elements {
+    pub fn new(
+        connectivity: &Connectivity<4>,
+        reference_nodal_coordinates: &[[Scalar; 3]],
+    ) -> Result<Self, PyErrGlue> {
+        let nodes = reference_nodal_coordinates.len();
+        connectivity.iter().enumerate().try_for_each(
+            |(element, element_nodes)| match element_nodes.iter().find(|&&node| node >= nodes) {
+                Some(node) => Err(PyErrGlue::new(&format!(
+                    "Node {node} in element {element} is out of range for {nodes} nodes."
+                ))),
+                None => Ok(()),
+            },
+        )?;
+        let (gradient_vectors, volumes) = connectivity
+            .iter()
+            .enumerate()
+            .map(|(index, element)| {
+                let jacobian = jacobian(reference_nodal_coordinates, element);
+                let volume = determinant(&jacobian) / 6.0;
+                if volume.is_nan() || volume <= 0.0 {
+                    return Err(PyErrGlue::new(&format!(
+                        "Element {index} has a non-positive reference volume."
+                    )));
+                }
+                let inverse = inverse(&jacobian);
+                Ok((
+                    STANDARD_GRADIENT_VECTORS.map(|standard| {
+                        [0, 1, 2].map(|i| (0..3).map(|k| inverse[k][i] * standard[k]).sum())
+                    }),
+                    volume,
+                ))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+        Ok(Self {
+            connectivity: connectivity.clone(),
+            reference_nodal_coordinates: reference_nodal_coordinates.to_vec(),
+            gradient_vectors,
+            node_sets: Vec::new(),
+            volumes,
+        })
+    }
+    pub fn connectivity(&self) -> &Connectivity<4> {
+        &self.connectivity
+    }
+    pub fn reference_nodal_coordinates(&self) -> &[[Scalar; 3]] {
+        &self.reference_nodal_coordinates
+    }
+    pub fn gradient_vectors(&self) -> &[[[Scalar; 3]; 4]] {
+        &self.gradient_vectors
+    }
+    pub fn volumes(&self) -> &[Scalar] {
+        &self.volumes
+    }
+    pub fn node_sets(&self) -> &[(String, Vec<usize>)] {
+        &self.node_sets
+    }
+    pub fn set_node_sets(&mut self, node_sets: Vec<(String, Vec<usize>)>) -> Result<(), PyErrGlue> {
+        let nodes = self.reference_nodal_coordinates.len();
+        node_sets.iter().try_for_each(|(name, set)| {
+            match set.iter().find(|&&node| node >= nodes) {
+                Some(node) => Err(PyErrGlue::new(&format!(
+                    "Node {node} in node set {name} is out of range for {nodes} nodes."
+                ))),
+                None => Ok(()),
+            }
+        })?;
+        self.node_sets = node_sets;
+        Ok(())
+    }
+    pub fn check_nodes(&self, nodal_coordinates: &[[Scalar; 3]]) -> Result<(), PyErrGlue> {
+        if nodal_coordinates.len() == self.reference_nodal_coordinates.len() {
+            Ok(())
+        } else {
+            Err(PyErrGlue::new(&format!(
+                "Expected {} nodal coordinates, got {}.",
+                self.reference_nodal_coordinates.len(),
+                nodal_coordinates.len()
+            )))
+        }
+    }
+    pub fn check_jacobians(&self, nodal_coordinates: &[[Scalar; 3]]) -> Result<(), PyErrGlue> {
+        self.deformation_gradients(nodal_coordinates)?
+            .iter()
+            .enumerate()
+            .try_for_each(|(element, deformation_gradient)| {
+                check_jacobian(element, deformation_gradient)
+            })
+    }
+    /// Reports the first inverted element in place of an error from evaluating the elements, if there is one.
+    pub fn inverted_or(&self, nodal_coordinates: &[[Scalar; 3]], error: PyErrGlue) -> PyErrGlue {
+        self.check_jacobians(nodal_coordinates)
+            .err()
+            .unwrap_or(error)
+    }
+    pub fn deformation_gradients(
+        &self,
+        nodal_coordinates: &[[Scalar; 3]],
+    ) -> Result<Vec<Tensor>, PyErrGlue> {
+        self.check_nodes(nodal_coordinates)?;
+        Ok(self
+            .connectivity
+            .iter()
+            .zip(self.gradient_vectors.iter())
+            .map(|(element, gradient_vectors)| {
+                let mut deformation_gradient = [[0.0; 3]; 3];
+                element
+                    .iter()
+                    .zip(gradient_vectors.iter())
+                    .for_each(|(&node, gradient_vector)| {
+                        (0..3).for_each(|i| {
+                            (0..3).for_each(|j| {
+                                deformation_gradient[i][j] +=
+                                    nodal_coordinates[node][i] * gradient_vector[j]
+                            })
+                        })
+                    });
+                deformation_gradient
+            })
+            .collect())
+    }
+    /// Assembles the nodal forces of first Piola-Kirchhoff stresses that are constant over each element.
+    pub fn assemble_forces(&self, stresses: &[Tensor]) -> Array2<Scalar> {
+        let mut forces = Array2::zeros((self.reference_nodal_coordinates.len(), 3));
+        self.connectivity
+            .iter()
+            .zip(self.gradient_vectors.iter())
+            .zip(self.volumes.iter())
+            .zip(stresses.iter())
+            .for_each(|(((element, gradient_vectors), volume), stress)| {
+                element
+                    .iter()
+                    .zip(gradient_vectors.iter())
+                    .for_each(|(&node, gradient_vector)| {
+                        (0..3).for_each(|i| {
+                            forces[[node, i]] += volume
+                                * (0..3)
+                                    .map(|j| stress[i][j] * gradient_vector[j])
+                                    .sum::<Scalar>()
+                        })
+                    })
+            });
+        forces
+    }
+    /// Assembles the nodal stiffnesses of tangents that are constant over each element.
+    pub fn assemble_stiffnesses(&self, tangents: &[Tangent]) -> Array4<Scalar> {
+        let nodes = self.reference_nodal_coordinates.len();
+        let mut stiffnesses = Array4::zeros((nodes, nodes, 3, 3));
+        self.connectivity
+            .iter()
+            .zip(self.gradient_vectors.iter())
+            .zip(self.volumes.iter())
+            .zip(tangents.iter())
+            .for_each(|(((element, gradient_vectors), volume), tangent)| {
+                element
+                    .iter()
+                    .zip(gradient_vectors.iter())
+                    .for_each(|(&a, gradient_a)| {
+                        element
+                            .iter()
+                            .zip(gradient_vectors.iter())
+                            .for_each(|(&b, gradient_b)| {
+                                (0..3).for_each(|i| {
+                                    (0..3).for_each(|k| {
+                                        stiffnesses[[a, b, i, k]] += volume
+                                            * (0..3)
+                                                .flat_map(|j| (0..3).map(move |l| (j, l)))
+                                                .map(|(j, l)| {
+                                                    tangent[i][j][k][l]
+                                                        * gradient_a[j]
+                                                        * gradient_b[l]
+                                                })
+                                                .sum::<Scalar>()
+                                    })
+                                })
+                            })
+                    })
+            });
+        stiffnesses
+    }
+    fn nodal_volumes(&self, fraction: Scalar) -> Vec<Scalar> {
+        let mut nodal_volumes = vec![0.0; self.reference_nodal_coordinates.len()];
+        self.connectivity
+            .iter()
+            .zip(self.volumes.iter())
+            .for_each(|(element, volume)| {
+                element
+                    .iter()
+                    .for_each(|&node| nodal_volumes[node] += fraction * volume)
+            });
+        nodal_volumes
+    }
+    /// Projects a field that is constant over each element onto the nodes, either by solving the consistent mass system with preconditioned conjugate gradients or by lumping the mass.
+    pub fn project(
+        &self,
+        field: &[Vec<Scalar>],
+        lumped: bool,
+    ) -> Result<Vec<Vec<Scalar>>, PyErrGlue> {
+        let nodes = self.reference_nodal_coordinates.len();
+        let components = field.first().map_or(0, |values| values.len());
+        let lumped_masses = self.nodal_volumes(0.25);
+        let masses =
+            CompressedSparseRows::from_triplets((nodes, nodes), &nodal_mass_entries(self, 1.0));
+        let preconditioner = Preconditioner::new(&masses, Some("jacobi"))?;
+        (0..components)
+            .map(|component| {
+                let mut rhs = vec![0.0; nodes];
+                self.connectivity
+                    .iter()
+                    .zip(self.volumes.iter().zip(field.iter()))
+                    .for_each(|(element, (volume, values))| {
+                        element
+                            .iter()
+                            .for_each(|&node| rhs[node] += 0.25 * volume * values[component])
+                    });
+                if lumped {
+                    Ok(rhs
+                        .iter()
+                        .zip(lumped_masses.iter())
+                        .map(|(b, m)| if *m > 0.0 { b / m } else { 0.0 })
+                        .collect())
+                } else {
+                    conjugate_gradient(
+                        &masses,
+                        &rhs,
+                        vec![0.0; nodes],
+                        &preconditioner,
+                        1e-14,
+                        10 * nodes,
+                    )
+                }
+            })
+            .try_fold(
+                vec![Vec::with_capacity(components); nodes],
+                |mut nodal, values| {
+                    nodal
+                        .iter_mut()
+                        .zip(values?)
+                        .for_each(|(node, value)| node.push(value));
+                    Ok(nodal)
+                },
+            )
+    }
+}
+
+fn shape(shape: &[usize]) -> String {
+    match shape {
+        [size] => format!("({size},)"),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|size| size.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Extracts a two-dimensional array with the given number of columns, borrowing arrays of the right type directly and converting anything else with `numpy.asarray`.
+fn array2<'py, T: Element>(
+    array: &Bound<'py, PyAny>,
+    dtype: &str,
+    columns: usize,
+    expected: &str,
+) -> PyResult<PyReadonlyArray2<'py, T>> {
+    let array = match array.extract::<PyReadonlyArray2<T>>() {
+        Ok(array) => array,
+        Err(_) => {
+            let array = array
+                .py()
+                .import("numpy")?
+                .call_method1("asarray", (array, dtype))?;
+            let dimensions: Vec<usize> = array.getattr("shape")?.extract()?;
+            if dimensions.len() != 2 {
+                return Err(PyValueError::new_err(format!(
+                    "Expected {expected}, got {}.",
+                    shape(&dimensions)
+                )));
+            }
+            array.extract()?
+        }
+    };
+    if array.shape()[1] == columns {
+        Ok(array)
+    } else {
+        Err(PyValueError::new_err(format!(
+            "Expected {expected}, got {}.",
+            shape(array.shape())
+        )))
+    }
+}
+
+/// Reads reference nodal coordinates from an `(N, 3)` array of floating point numbers.
+pub fn reference_nodal_coordinates(array: &Bound<'_, PyAny>) -> PyResult<Vec<[Scalar; 3]>> {
+    Ok(array2::<Scalar>(
+        array,
+        "float64",
+        3,
+        "reference nodal coordinates with shape (N, 3)",
+    )?
+    .as_array()
+    .outer_iter()
+    .map(|node| [node[0], node[1], node[2]])
+    .collect())
+}
+
+/// Reads a connectivity from an `(E, 4)` array of integers, requiring distinct nodes within each element and every node to be used.
+pub fn connectivity(array: &Bound<'_, PyAny>, nodes: usize) -> PyResult<Connectivity<4>> {
+    let connectivity = element_connectivity(array, nodes)?;
+    check_used(nodes, &[&connectivity])?;
+    Ok(connectivity)
+}
+
+/// Reads a connectivity from an `(E, 4)` array of integers, requiring distinct nodes within each element.
+pub fn element_connectivity(array: &Bound<'_, PyAny>, nodes: usize) -> PyResult<Connectivity<4>> {
+    if array.extract::<PyReadonlyArray2<i64>>().is_err() {
+        let dtype = array
+            .py()
+            .import("numpy")?
+            .call_method1("asarray", (array,))?
+            .getattr("dtype")?;
+        if !matches!(
+            dtype.getattr("kind")?.extract::<String>()?.as_str(),
+            "i" | "u"
+        ) {
+            return Err(PyValueError::new_err(format!(
+                "Expected a connectivity of integers, got {}.",
+                dtype.str()?
+            )));
+        }
+    }
+    let array = array2::<i64>(array, "int64", 4, "a connectivity with shape (E, 4)")?;
+    array
+        .as_array()
+        .outer_iter()
+        .enumerate()
+        .map(|(element, row)| {
+            let mut element_nodes = [0; 4];
+            for (a, &node) in row.iter().enumerate() {
+                if node < 0 || node as usize >= nodes {
+                    return Err(PyValueError::new_err(format!(
+                        "Node {node} in element {element} is out of range for {nodes} nodes."
+                    )));
+                }
+                if row.iter().take(a).any(|&other| other == node) {
+                    return Err(PyValueError::new_err(format!(
+                        "Element {element} has duplicate node {node}."
+                    )));
+                }
+                element_nodes[a] = node as usize;
+            }
+            Ok(element_nodes)
+        })
+        .collect()
+}
+
+/// Requires every node to be used by some element of the connectivities.
+pub fn check_used(nodes: usize, connectivities: &[&Connectivity<4>]) -> PyResult<()> {
+    let mut used = vec![false; nodes];
+    connectivities
+        .iter()
+        .flat_map(|connectivity| connectivity.iter().flatten())
+        .for_each(|&node| used[node] = true);
+    match used.iter().position(|&used| !used) {
+        Some(node) => Err(PyValueError::new_err(format!(
+            "Node {node} is not used by any element."
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Constitutive fields evaluated over the elements of a block.
+pub(crate) trait Fields {
+    fn elements(&self) -> &Elements;
+    fn elements_mut(&mut self) -> &mut Elements;
+    fn cauchy_stress(&self, deformation_gradient: &Tensor) -> Result<Tensor, PyErrGlue>;
+    fn first_piola_kirchhoff_tangent_stiffness(
+        &self,
+        deformation_gradient: &Tensor,
+    ) -> Result<Tangent, PyErrGlue>;
+    fn helmholtz_free_energy_density(
+        &self,
+        deformation_gradient: &Tensor,
+    ) -> Option<Result<Scalar, PyErrGlue>>;
+    fn parameters(&self) -> Vec<(&'static str, Scalar)>;
+    fn helmholtz_free_energy_with(
+        &self,
+        parameters: &[Scalar],
+        nodal_coordinates: &[[Scalar; 3]],
+    ) -> Option<Result<Scalar, PyErrGlue>>;
+    fn nodal_forces_with(
+        &self,
+        parameters: &[Scalar],
+        nodal_coordinates: &[[Scalar; 3]],
+    ) -> Result<Vec<Vec<Scalar>>, PyErrGlue>;
+    /// Evaluates the Helmholtz free energy at the given nodal coordinates, or in the current configuration if none are given.
+    fn helmholtz_free_energy(
+        &self,
+        nodal_coordinates: Option<&[[Scalar; 3]]>,
+    ) -> Option<Result<Scalar, PyErrGlue>>;
+    /// Evaluates the nodal forces at the given nodal coordinates, or in the current configuration if none are given.
+    fn nodal_forces(
+        &mut self,
+        nodal_coordinates: Option<&[[Scalar; 3]]>,
+    ) -> Result<Array2<Scalar>, PyErrGlue>;
+    /// Evaluates the nodal stiffnesses at the given nodal coordinates, or in the current configuration if none are given.
+    fn nodal_stiffnesses(
+        &self,
+        nodal_coordinates: Option<&[[Scalar; 3]]>,
+    ) -> Result<Array4<Scalar>, PyErrGlue>;
+    /// The nodal coordinates of the current configuration.
+    fn nodal_coordinates(&self) -> Vec<[Scalar; 3]>;
+    /// Moves the current configuration to the given nodal coordinates.
+    fn set_nodal_coordinates(
+        &mut self,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<(), PyErrGlue>;
+    /// Reports the first inverted element at the given nodal coordinates, or in the current configuration if none are given, in place of an error from evaluating the elements.
+    fn inverted_or(
+        &self,
+        nodal_coordinates: Option<&[[Scalar; 3]]>,
+        error: PyErrGlue,
+    ) -> PyErrGlue {
+        match nodal_coordinates {
+            Some(nodal_coordinates) => self.elements().inverted_or(nodal_coordinates, error),
+            None => self
+                .elements()
+                .inverted_or(&self.nodal_coordinates(), error),
+        }
+    }
+    fn update_displacements(&mut self, displacements: &[[Scalar; 3]]) -> Result<(), PyErrGlue> {
+        self.elements().check_nodes(displacements)?;
+        let nodal_coordinates = self
+            .nodal_coordinates()
+            .iter()
+            .zip(displacements.iter())
+            .map(|(coordinates, displacement)| [0, 1, 2].map(|i| coordinates[i] + displacement[i]))
+            .collect();
+        self.set_nodal_coordinates(nodal_coordinates)
+    }
+    fn first_piola_kirchhoff_stress(
+        &self,
+        deformation_gradient: &Tensor,
+    ) -> Result<Tensor, PyErrGlue> {
+        let cauchy_stress = self.cauchy_stress(deformation_gradient)?;
+        let jacobian = determinant(deformation_gradient);
+        let inverse = inverse(deformation_gradient);
+        Ok([0, 1, 2].map(|i| {
+            [0, 1, 2].map(|j| {
+                jacobian
+                    * (0..3)
+                        .map(|k| cauchy_stress[i][k] * inverse[j][k])
+                        .sum::<Scalar>()
+            })
+        }))
+    }
+    fn cauchy_stresses(&self, nodal_coordinates: &[[Scalar; 3]]) -> Result<Vec<Tensor>, PyErrGlue> {
+        self.elements()
+            .deformation_gradients(nodal_coordinates)?
+            .iter()
+            .map(|deformation_gradient| self.cauchy_stress(deformation_gradient))
+            .collect()
+    }
+    fn helmholtz_free_energy_densities(
+        &self,
+        nodal_coordinates: &[[Scalar; 3]],
+    ) -> Result<Option<Vec<Scalar>>, PyErrGlue> {
+        self.elements()
+            .deformation_gradients(nodal_coordinates)?
+            .iter()
+            .map(|deformation_gradient| self.helmholtz_free_energy_density(deformation_gradient))
+            .collect::<Option<Result<_, _>>>()
+            .transpose()
+    }
+}
+
+/// Reads the given number of constitutive model parameters.
+pub fn parameters<const N: usize>(parameters: &[Scalar]) -> Result<[Scalar; N], PyErrGlue> {
+    parameters.try_into().map_err(|_| {
+        PyErrGlue::new(&format!(
+            "Expected {N} parameters, got {}.",
+            parameters.len()
+        ))
+    })
+}
+
+pub fn tensor(components: Vec<Vec<Scalar>>) -> Tensor {
+    [0, 1, 2].map(|i| [0, 1, 2].map(|j| components[i][j]))
+}
+
+pub fn tangent(components: Vec<Vec<Vec<Vec<Scalar>>>>) -> Tangent {
+    [0, 1, 2]
+        .map(|i| [0, 1, 2].map(|j| [0, 1, 2].map(|k| [0, 1, 2].map(|l| components[i][j][k][l]))))
+}
+
+pub fn check_jacobian(element: usize, deformation_gradient: &Tensor) -> Result<(), PyErrGlue> {
+    let jacobian = determinant(deformation_gradient);
+    if jacobian > 0.0 {
+        Ok(())
+    } else {
+        Err(PyErrGlue::inverted_element(element, jacobian))
+    }
+}
+
+pub fn jacobian(nodal_coordinates: &[[Scalar; 3]], element: &[usize; 4]) -> Tensor {
+    let origin = nodal_coordinates[element[0]];
+    [0, 1, 2].map(|i| [1, 2, 3].map(|a| nodal_coordinates[element[a]][i] - origin[i]))
+}
+
+pub const IDENTITY: Tensor = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+pub fn product(a: &Tensor, b: &Tensor) -> Tensor {
+    [0, 1, 2].map(|i| [0, 1, 2].map(|j| (0..3).map(|k| a[i][k] * b[k][j]).sum()))
+}
+
+pub fn transpose(tensor: &Tensor) -> Tensor {
+    [0, 1, 2].map(|i| [0, 1, 2].map(|j| tensor[j][i]))
+}
+
+pub fn determinant(tensor: &Tensor) -> Scalar {
+    tensor[0][0] * (tensor[1][1] * tensor[2][2] - tensor[1][2] * tensor[2][1])
+        - tensor[0][1] * (tensor[1][0] * tensor[2][2] - tensor[1][2] * tensor[2][0])
+        + tensor[0][2] * (tensor[1][0] * tensor[2][1] - tensor[1][1] * tensor[2][0])
+}
+
+pub fn inverse(tensor: &Tensor) -> Tensor {
+    let determinant = determinant(tensor);
+    [0, 1, 2].map(|i| {
+        [0, 1, 2].map(|j| {
+            let (a, b) = ((j + 1) % 3, (j + 2) % 3);
+            let (c, d) = ((i + 1) % 3, (i + 2) % 3);
+            (tensor[a][c] * tensor[b][d] - tensor[a][d] * tensor[b][c]) / determinant
+        })
+    })
+}
+
+pub fn von_mises_stress(cauchy_stress: &Tensor) -> Scalar {
+    let pressure = (cauchy_stress[0][0] + cauchy_stress[1][1] + cauchy_stress[2][2]) / 3.0;
+    (1.5 * (0..3)
+        .flat_map(|i| (0..3).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let deviatoric = cauchy_stress[i][j] - if i == j { pressure } else { 0.0 };
+            deviatoric * deviatoric
+        })
+        .sum::<Scalar>())
+    .sqrt()
+}
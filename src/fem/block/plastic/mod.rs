@@ -0,0 +1,429 @@
+use crate::{
+    PyErrGlue,
+    constitutive::solid::hyperelastic::NeoHookean,
+    fem::block::{
+        self, Elements, Fields, IDENTITY, Tangent, Tensor, check_jacobian, determinant, inverse,
+        product, transpose,
+    },
+};
+use conspire::{fem::block::Connectivity, mechanics::Scalar};
+use ndarray::{Array2, Array4};
+use pyo3::prelude::*;
+
+/// Plastic internal variables at an integration point.
+#[derive(Clone)]
+struct State {
+    plastic_right_cauchy_green_inverse: Tensor,
+    equivalent_plastic_strain: Scalar,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            plastic_right_cauchy_green_inverse: IDENTITY,
+            equivalent_plastic_strain: 0.0,
+        }
+    }
+}
+
+/// Result of the return mapping at an integration point.
+struct ReturnMap {
+    kirchhoff_stress: Tensor,
+    state: State,
+    jacobian: Scalar,
+    mean: Scalar,
+    trial: Tensor,
+    norm: Scalar,
+    increment: Scalar,
+}
+
+/// J2 plasticity with linear isotropic hardening of a Neo-Hookean elastic response.
+#[pyclass]
+#[derive(Clone, Copy)]
+pub struct Plasticity {
+    bulk_modulus: Scalar,
+    shear_modulus: Scalar,
+    yield_stress: Scalar,
+    hardening_modulus: Scalar,
+}
+
+impl Plasticity {
+    fn with_parameters(parameters: &[Scalar]) -> Result<Self, PyErrGlue> {
+        let [bulk_modulus, shear_modulus, yield_stress, hardening_modulus] =
+            block::parameters(parameters)?;
+        Ok(Self {
+            bulk_modulus,
+            shear_modulus,
+            yield_stress,
+            hardening_modulus,
+        })
+    }
+    /// Radial return mapping of the trial elastic state, returning the Kirchhoff stress, the updated internal variables, and the plastic multiplier.
+    fn return_map(&self, deformation_gradient: &Tensor, state: &State) -> ReturnMap {
+        let jacobian = determinant(deformation_gradient);
+        let isochoric = deformation_gradient.map(|row| row.map(|entry| entry / jacobian.cbrt()));
+        let left_cauchy_green = product(
+            &product(&isochoric, &state.plastic_right_cauchy_green_inverse),
+            &transpose(&isochoric),
+        );
+        let mean = (0..3).map(|i| left_cauchy_green[i][i]).sum::<Scalar>() / 3.0;
+        let trial = [0, 1, 2].map(|i| {
+            [0, 1, 2].map(|j| {
+                self.shear_modulus * (left_cauchy_green[i][j] - if i == j { mean } else { 0.0 })
+            })
+        });
+        let norm = trial.iter().flatten().map(|s| s * s).sum::<Scalar>().sqrt();
+        let yield_function = norm
+            - (2.0 / 3.0 as Scalar).sqrt()
+                * (self.yield_stress + self.hardening_modulus * state.equivalent_plastic_strain);
+        let (deviatoric, increment, state) = if yield_function > 0.0 {
+            let increment = yield_function
+                / (2.0 * self.shear_modulus * mean + 2.0 * self.hardening_modulus / 3.0);
+            let deviatoric = trial.map(|row| {
+                row.map(|s| s * (1.0 - 2.0 * self.shear_modulus * mean * increment / norm))
+            });
+            let elastic_left_cauchy_green = [0, 1, 2].map(|i| {
+                [0, 1, 2].map(|j| {
+                    deviatoric[i][j] / self.shear_modulus + if i == j { mean } else { 0.0 }
+                })
+            });
+            let isochoric_inverse = inverse(&isochoric);
+            (
+                deviatoric,
+                increment,
+                State {
+                    plastic_right_cauchy_green_inverse: product(
+                        &product(&isochoric_inverse, &elastic_left_cauchy_green),
+                        &transpose(&isochoric_inverse),
+                    ),
+                    equivalent_plastic_strain: state.equivalent_plastic_strain
+                        + (2.0 / 3.0 as Scalar).sqrt() * increment,
+                },
+            )
+        } else {
+            (trial, 0.0, state.clone())
+        };
+        let pressure = 0.5 * self.bulk_modulus * (jacobian - 1.0 / jacobian);
+        ReturnMap {
+            kirchhoff_stress: [0, 1, 2].map(|i| {
+                [0, 1, 2].map(|j| deviatoric[i][j] + if i == j { jacobian * pressure } else { 0.0 })
+            }),
+            state,
+            jacobian,
+            mean,
+            trial,
+            norm,
+            increment,
+        }
+    }
+    fn cauchy_stress(&self, deformation_gradient: &Tensor, state: &State) -> Tensor {
+        let return_map = self.return_map(deformation_gradient, state);
+        return_map
+            .kirchhoff_stress
+            .map(|row| row.map(|entry| entry / return_map.jacobian))
+    }
+    fn first_piola_kirchhoff_stress(
+        &self,
+        deformation_gradient: &Tensor,
+        state: &State,
+    ) -> (Tensor, State) {
+        let return_map = self.return_map(deformation_gradient, state);
+        (
+            product(
+                &return_map.kirchhoff_stress,
+                &transpose(&inverse(deformation_gradient)),
+            ),
+            return_map.state,
+        )
+    }
+    /// Consistent tangent of the return mapping,[^1] pulled back to the first Piola-Kirchhoff stress.
+    ///
+    /// [^1]: J.C. Simo and T.J.R. Hughes, Computational Inelasticity, Box 9.2 (1998).
+    fn first_piola_kirchhoff_tangent_stiffness(
+        &self,
+        deformation_gradient: &Tensor,
+        state: &State,
+    ) -> Tangent {
+        let ReturnMap {
+            kirchhoff_stress,
+            jacobian,
+            mean,
+            trial,
+            norm,
+            increment,
+            ..
+        } = self.return_map(deformation_gradient, state);
+        let shear_modulus = self.shear_modulus * mean;
+        let direction = if norm > 0.0 {
+            trial.map(|row| row.map(|s| s / norm))
+        } else {
+            [[0.0; 3]; 3]
+        };
+        let square = product(&direction, &direction);
+        let [beta_1, beta_3, beta_4] = if increment > 0.0 {
+            let beta_0 = 1.0 + self.hardening_modulus / (3.0 * shear_modulus);
+            let beta_1 = 2.0 * shear_modulus * increment / norm;
+            let beta_2 = (1.0 - 1.0 / beta_0) * 2.0 / 3.0 * norm * increment / shear_modulus;
+            [
+                beta_1,
+                1.0 / beta_0 - beta_1 + beta_2,
+                (1.0 / beta_0 - beta_1) * norm / shear_modulus,
+            ]
+        } else {
+            [0.0; 3]
+        };
+        let delta = |i: usize, j: usize| if i == j { 1.0 } else { 0.0 };
+        let spatial = |i: usize, j: usize, k: usize, l: usize| {
+            let symmetric = 0.5 * (delta(i, k) * delta(j, l) + delta(i, l) * delta(j, k));
+            let deviatoric = 2.0 * shear_modulus * (symmetric - delta(i, j) * delta(k, l) / 3.0)
+                - 2.0 / 3.0
+                    * norm
+                    * (direction[i][j] * delta(k, l) + delta(i, j) * direction[k][l]);
+            self.bulk_modulus * jacobian * jacobian * delta(i, j) * delta(k, l)
+                - self.bulk_modulus * (jacobian * jacobian - 1.0) * symmetric
+                + (1.0 - beta_1) * deviatoric
+                - 2.0 * shear_modulus * beta_3 * direction[i][j] * direction[k][l]
+                - 2.0
+                    * shear_modulus
+                    * beta_4
+                    * direction[i][j]
+                    * (square[k][l] - delta(k, l) / 3.0)
+        };
+        let inverse = inverse(deformation_gradient);
+        [0, 1, 2].map(|i| {
+            [0, 1, 2].map(|big_j| {
+                [0, 1, 2].map(|k| {
+                    [0, 1, 2].map(|big_l| {
+                        (0..3)
+                            .flat_map(|j| (0..3).map(move |l| (j, l)))
+                            .map(|(j, l)| {
+                                (spatial(i, j, k, l) + delta(i, k) * kirchhoff_stress[j][l])
+                                    * inverse[big_j][j]
+                                    * inverse[big_l][l]
+                            })
+                            .sum()
+                    })
+                })
+            })
+        })
+    }
+}
+
+#[pymethods]
+impl Plasticity {
+    #[new]
+    #[pyo3(signature = (model, yield_stress, hardening_modulus = 0.0))]
+    fn new(
+        model: PyRef<NeoHookean>,
+        yield_stress: Scalar,
+        hardening_modulus: Scalar,
+    ) -> Result<Self, PyErrGlue> {
+        if yield_stress.is_nan() || yield_stress <= 0.0 {
+            return Err(PyErrGlue::new("The yield stress must be positive."));
+        }
+        if hardening_modulus.is_nan() || hardening_modulus < 0.0 {
+            return Err(PyErrGlue::new("The hardening modulus must be nonnegative."));
+        }
+        Ok(Self {
+            bulk_modulus: model.bulk_modulus(),
+            shear_modulus: model.shear_modulus(),
+            yield_stress,
+            hardening_modulus,
+        })
+    }
+    /// @private
+    #[getter]
+    fn bulk_modulus(&self) -> Scalar {
+        self.bulk_modulus
+    }
+    /// @private
+    #[getter]
+    fn shear_modulus(&self) -> Scalar {
+        self.shear_modulus
+    }
+    /// @private
+    #[getter]
+    fn yield_stress(&self) -> Scalar {
+        self.yield_stress
+    }
+    /// @private
+    #[getter]
+    fn hardening_modulus(&self) -> Scalar {
+        self.hardening_modulus
+    }
+}
+
+/// Elastic-plastic block with committed and trial internal variables at each integration point.
+#[pyclass]
+pub struct PlasticBlock {
+    elements: Elements,
+    model: Plasticity,
+    nodal_coordinates: Vec<[Scalar; 3]>,
+    committed: Vec<State>,
+    trial: Vec<State>,
+}
+
+impl Fields for PlasticBlock {
+    fn elements(&self) -> &Elements {
+        &self.elements
+    }
+    fn elements_mut(&mut self) -> &mut Elements {
+        &mut self.elements
+    }
+    fn cauchy_stress(&self, _deformation_gradient: &Tensor) -> Result<Tensor, PyErrGlue> {
+        Err(PyErrGlue::new(
+            "The stress of an elastic-plastic block depends on the internal variables of each element.",
+        ))
+    }
+    fn first_piola_kirchhoff_tangent_stiffness(
+        &self,
+        _deformation_gradient: &Tensor,
+    ) -> Result<Tangent, PyErrGlue> {
+        Err(PyErrGlue::new(
+            "The tangent of an elastic-plastic block depends on the internal variables of each element.",
+        ))
+    }
+    fn cauchy_stresses(&self, nodal_coordinates: &[[Scalar; 3]]) -> Result<Vec<Tensor>, PyErrGlue> {
+        Ok(self
+            .elements
+            .deformation_gradients(nodal_coordinates)?
+            .iter()
+            .zip(self.committed.iter())
+            .map(|(deformation_gradient, state)| {
+                self.model.cauchy_stress(deformation_gradient, state)
+            })
+            .collect())
+    }
+    fn helmholtz_free_energy_density(
+        &self,
+        _deformation_gradient: &Tensor,
+    ) -> Option<Result<Scalar, PyErrGlue>> {
+        None
+    }
+    fn parameters(&self) -> Vec<(&'static str, Scalar)> {
+        vec![
+            ("bulk_modulus", self.model.bulk_modulus),
+            ("shear_modulus", self.model.shear_modulus),
+            ("yield_stress", self.model.yield_stress),
+            ("hardening_modulus", self.model.hardening_modulus),
+        ]
+    }
+    fn helmholtz_free_energy_with(
+        &self,
+        _parameters: &[Scalar],
+        _nodal_coordinates: &[[Scalar; 3]],
+    ) -> Option<Result<Scalar, PyErrGlue>> {
+        None
+    }
+    fn nodal_forces_with(
+        &self,
+        parameters: &[Scalar],
+        nodal_coordinates: &[[Scalar; 3]],
+    ) -> Result<Vec<Vec<Scalar>>, PyErrGlue> {
+        let (forces, _) =
+            self.forces(&Plasticity::with_parameters(parameters)?, nodal_coordinates)?;
+        Ok(forces.outer_iter().map(|force| force.to_vec()).collect())
+    }
+    fn helmholtz_free_energy(
+        &self,
+        _nodal_coordinates: Option<&[[Scalar; 3]]>,
+    ) -> Option<Result<Scalar, PyErrGlue>> {
+        None
+    }
+    fn nodal_coordinates(&self) -> Vec<[Scalar; 3]> {
+        self.nodal_coordinates.clone()
+    }
+    fn set_nodal_coordinates(
+        &mut self,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<(), PyErrGlue> {
+        self.elements.check_nodes(&nodal_coordinates)?;
+        self.nodal_coordinates = nodal_coordinates;
+        Ok(())
+    }
+    /// Evaluates the nodal forces from the committed state and keeps the resulting trial state.
+    fn nodal_forces(
+        &mut self,
+        nodal_coordinates: Option<&[[Scalar; 3]]>,
+    ) -> Result<Array2<Scalar>, PyErrGlue> {
+        let (forces, states) = self.forces(&self.model, self.configuration(nodal_coordinates))?;
+        self.trial = states;
+        Ok(forces)
+    }
+    /// Evaluates the consistent tangent of the return mapping from the committed state.
+    fn nodal_stiffnesses(
+        &self,
+        nodal_coordinates: Option<&[[Scalar; 3]]>,
+    ) -> Result<Array4<Scalar>, PyErrGlue> {
+        let tangents = self
+            .elements
+            .deformation_gradients(self.configuration(nodal_coordinates))?
+            .iter()
+            .zip(self.committed.iter())
+            .enumerate()
+            .map(|(element, (deformation_gradient, state))| {
+                check_jacobian(element, deformation_gradient)?;
+                Ok(self
+                    .model
+                    .first_piola_kirchhoff_tangent_stiffness(deformation_gradient, state))
+            })
+            .collect::<Result<Vec<Tangent>, PyErrGlue>>()?;
+        Ok(self.elements.assemble_stiffnesses(&tangents))
+    }
+}
+
+impl PlasticBlock {
+    pub fn new(
+        model: Plasticity,
+        connectivity: Connectivity<4>,
+        reference_nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Self, PyErrGlue> {
+        let states = vec![State::default(); connectivity.len()];
+        Ok(Self {
+            elements: Elements::new(&connectivity, &reference_nodal_coordinates)?,
+            nodal_coordinates: reference_nodal_coordinates,
+            model,
+            committed: states.clone(),
+            trial: states,
+        })
+    }
+    /// Returns the given nodal coordinates, or those of the current configuration if none are given.
+    fn configuration<'a>(
+        &'a self,
+        nodal_coordinates: Option<&'a [[Scalar; 3]]>,
+    ) -> &'a [[Scalar; 3]] {
+        nodal_coordinates.unwrap_or(&self.nodal_coordinates)
+    }
+    fn forces(
+        &self,
+        model: &Plasticity,
+        nodal_coordinates: &[[Scalar; 3]],
+    ) -> Result<(Array2<Scalar>, Vec<State>), PyErrGlue> {
+        let (stresses, states): (Vec<Tensor>, Vec<State>) = self
+            .elements
+            .deformation_gradients(nodal_coordinates)?
+            .iter()
+            .zip(self.committed.iter())
+            .enumerate()
+            .map(|(element, (deformation_gradient, state))| {
+                check_jacobian(element, deformation_gradient)?;
+                Ok(model.first_piola_kirchhoff_stress(deformation_gradient, state))
+            })
+            .collect::<Result<Vec<_>, PyErrGlue>>()?
+            .into_iter()
+            .unzip();
+        Ok((self.elements.assemble_forces(&stresses), states))
+    }
+    pub fn commit_state(&mut self) {
+        self.committed = self.trial.clone()
+    }
+    pub fn revert_state(&mut self) {
+        self.trial = self.committed.clone()
+    }
+    pub fn equivalent_plastic_strains(&self) -> Vec<Scalar> {
+        self.committed
+            .iter()
+            .map(|state| state.equivalent_plastic_strain)
+            .collect()
+    }
+}
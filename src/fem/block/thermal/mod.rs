@@ -0,0 +1,574 @@
+use crate::{
+    PyErrGlue,
+    fem::{
+        block::{self, Elements, IDENTITY, Tensor, determinant, elastic::N, inverse},
+        modal::nodal_mass_entries,
+    },
+    math::linalg::sparse::{CholeskyDecomposition, CompressedSparseRows},
+};
+use conspire::mechanics::Scalar;
+use ndarray::{Array2, Array3, ArrayView1};
+use numpy::{PyArray1, PyArray2};
+use pyo3::{prelude::*, types::PyDict};
+
+type Vector = [Scalar; 3];
+
+fn dot(u: &Vector, v: &Vector) -> Scalar {
+    u.iter().zip(v).map(|(u, v)| u * v).sum()
+}
+
+fn transposed_product(tensor: &Tensor, vector: &Vector) -> Vector {
+    [0, 1, 2].map(|i| (0..3).map(|k| tensor[k][i] * vector[k]).sum())
+}
+
+pub fn check_temperatures(
+    elements: &Elements,
+    nodal_temperatures: &[Scalar],
+) -> Result<(), PyErrGlue> {
+    let nodes = elements.reference_nodal_coordinates().len();
+    if nodal_temperatures.len() == nodes {
+        Ok(())
+    } else {
+        Err(PyErrGlue::new(&format!(
+            "Expected {nodes} nodal temperatures, got {}.",
+            nodal_temperatures.len()
+        )))
+    }
+}
+
+/// The weight of each node in the average temperature of an element.
+pub const NODAL_WEIGHT: Scalar = 0.25;
+
+/// Averages the nodal temperatures over each element.
+pub fn element_temperatures(elements: &Elements, nodal_temperatures: &[Scalar]) -> Vec<Scalar> {
+    elements
+        .connectivity()
+        .iter()
+        .map(|element| {
+            element
+                .iter()
+                .map(|&node| nodal_temperatures[node])
+                .sum::<Scalar>()
+                * NODAL_WEIGHT
+        })
+        .collect()
+}
+
+pub fn temperature_gradients(elements: &Elements, nodal_temperatures: &[Scalar]) -> Vec<Vector> {
+    elements
+        .connectivity()
+        .iter()
+        .zip(elements.gradient_vectors().iter())
+        .map(|(element, gradient_vectors)| {
+            [0, 1, 2].map(|i| {
+                element
+                    .iter()
+                    .zip(gradient_vectors.iter())
+                    .map(|(&node, gradient_vector)| nodal_temperatures[node] * gradient_vector[i])
+                    .sum()
+            })
+        })
+        .collect()
+}
+
+/// Fourier heat conduction over linear tetrahedral elements, isotropic in the current configuration.
+#[derive(Clone)]
+pub struct Conduction {
+    thermal_conductivity: Scalar,
+}
+
+impl Conduction {
+    pub fn new(thermal_conductivity: Scalar) -> Self {
+        Self {
+            thermal_conductivity,
+        }
+    }
+    pub fn thermal_conductivity(&self) -> Scalar {
+        self.thermal_conductivity
+    }
+    fn for_each_element(
+        &self,
+        elements: &Elements,
+        deformation_gradients: &[Tensor],
+        mut function: impl FnMut(&[usize; N], Scalar, [Vector; N], &Tensor),
+    ) {
+        elements
+            .connectivity()
+            .iter()
+            .zip(elements.gradient_vectors().iter())
+            .zip(elements.volumes().iter())
+            .zip(deformation_gradients.iter())
+            .for_each(
+                |(((element, gradient_vectors), volume), deformation_gradient)| {
+                    let inverse = inverse(deformation_gradient);
+                    let scale =
+                        self.thermal_conductivity * volume * determinant(deformation_gradient);
+                    function(
+                        element,
+                        scale,
+                        gradient_vectors
+                            .map(|gradient_vector| transposed_product(&inverse, &gradient_vector)),
+                        &inverse,
+                    )
+                },
+            )
+    }
+    /// $$
+    /// r_a = \int_\Omega k\,J\,\nabla_0 N_a\cdot\mathbf{C}^{-1}\cdot\nabla_0 T\,dV
+    /// $$
+    pub fn nodal_fluxes(
+        &self,
+        elements: &Elements,
+        deformation_gradients: &[Tensor],
+        nodal_temperatures: &[Scalar],
+    ) -> Vec<Scalar> {
+        let mut fluxes = vec![0.0; nodal_temperatures.len()];
+        let temperature_gradients = temperature_gradients(elements, nodal_temperatures);
+        let mut temperature_gradients = temperature_gradients.iter();
+        self.for_each_element(
+            elements,
+            deformation_gradients,
+            |element, scale, gradients, inverse| {
+                let temperature_gradient =
+                    transposed_product(inverse, temperature_gradients.next().unwrap());
+                element
+                    .iter()
+                    .zip(gradients.iter())
+                    .for_each(|(&node, gradient)| {
+                        fluxes[node] += scale * dot(gradient, &temperature_gradient)
+                    })
+            },
+        );
+        fluxes
+    }
+    /// The conductances of each pair of nodes within each element, as triplets of nodes and conductance.
+    pub fn nodal_conductance_entries(
+        &self,
+        elements: &Elements,
+        deformation_gradients: &[Tensor],
+    ) -> Vec<(usize, usize, Scalar)> {
+        let mut entries = Vec::with_capacity(N * N * elements.volumes().len());
+        self.for_each_element(
+            elements,
+            deformation_gradients,
+            |element, scale, gradients, _| {
+                element
+                    .iter()
+                    .zip(gradients.iter())
+                    .for_each(|(&a, gradient_a)| {
+                        element
+                            .iter()
+                            .zip(gradients.iter())
+                            .for_each(|(&b, gradient_b)| {
+                                entries.push((a, b, scale * dot(gradient_a, gradient_b)))
+                            })
+                    })
+            },
+        );
+        entries
+    }
+    /// $$
+    /// K_{ab} = \frac{\partial r_a}{\partial T_b}
+    /// $$
+    pub fn nodal_conductances(
+        &self,
+        elements: &Elements,
+        deformation_gradients: &[Tensor],
+    ) -> Array2<Scalar> {
+        let nodes = elements.reference_nodal_coordinates().len();
+        let mut conductances = Array2::zeros((nodes, nodes));
+        self.nodal_conductance_entries(elements, deformation_gradients)
+            .into_iter()
+            .for_each(|(a, b, conductance)| conductances[[a, b]] += conductance);
+        conductances
+    }
+    /// $$
+    /// \frac{\partial r_a}{\partial\mathbf{x}_b}
+    /// $$
+    pub fn nodal_couplings(
+        &self,
+        elements: &Elements,
+        deformation_gradients: &[Tensor],
+        nodal_temperatures: &[Scalar],
+    ) -> Array3<Scalar> {
+        let nodes = nodal_temperatures.len();
+        let mut couplings = Array3::zeros((nodes, nodes, 3));
+        let temperature_gradients = temperature_gradients(elements, nodal_temperatures);
+        let mut temperature_gradients = temperature_gradients.iter();
+        self.for_each_element(
+            elements,
+            deformation_gradients,
+            |element, scale, gradients, inverse| {
+                let temperature_gradient =
+                    transposed_product(inverse, temperature_gradients.next().unwrap());
+                element
+                    .iter()
+                    .zip(gradients.iter())
+                    .for_each(|(&a, gradient_a)| {
+                        let flux = dot(gradient_a, &temperature_gradient);
+                        element
+                            .iter()
+                            .zip(gradients.iter())
+                            .for_each(|(&b, gradient_b)| {
+                                let (flux_b, coupling) = (
+                                    dot(gradient_b, &temperature_gradient),
+                                    dot(gradient_a, gradient_b),
+                                );
+                                (0..3).for_each(|j| {
+                                    couplings[[a, b, j]] += scale
+                                        * (gradient_b[j] * flux
+                                            - gradient_a[j] * flux_b
+                                            - temperature_gradient[j] * coupling)
+                                })
+                            })
+                    })
+            },
+        );
+        couplings
+    }
+}
+
+/// Heat conduction over the elements of a single material.
+#[derive(Clone)]
+struct Region {
+    elements: Elements,
+    conduction: Conduction,
+    heat_capacity: Scalar,
+}
+
+type Entries = Vec<(usize, usize, Scalar)>;
+
+/// Heat conduction finite element block.
+#[pyclass]
+pub struct HeatConductionBlock {
+    regions: Vec<Region>,
+}
+
+impl HeatConductionBlock {
+    fn elements(&self) -> &Elements {
+        &self.regions[0].elements
+    }
+    fn nodes(&self) -> usize {
+        self.elements().reference_nodal_coordinates().len()
+    }
+    fn shared(&self, name: &str, value: impl Fn(&Region) -> Scalar) -> Result<Scalar, PyErrGlue> {
+        let first = value(&self.regions[0]);
+        if self.regions.iter().all(|region| value(region) == first) {
+            Ok(first)
+        } else {
+            Err(PyErrGlue::new(&format!(
+                "The blocks of the assembly have different values of the {name}."
+            )))
+        }
+    }
+    fn conductances(&self) -> Entries {
+        self.regions
+            .iter()
+            .flat_map(|region| {
+                region.conduction.nodal_conductance_entries(
+                    &region.elements,
+                    &vec![IDENTITY; region.elements.volumes().len()],
+                )
+            })
+            .collect()
+    }
+    fn capacitances(&self, lumped: bool) -> Entries {
+        self.regions
+            .iter()
+            .flat_map(|region| nodal_mass_entries(&region.elements, region.heat_capacity))
+            .map(|(a, b, capacitance)| (a, if lumped { a } else { b }, capacitance))
+            .collect()
+    }
+    fn dense(&self, entries: Entries) -> Array2<Scalar> {
+        let nodes = self.nodes();
+        let mut matrix = Array2::zeros((nodes, nodes));
+        entries
+            .into_iter()
+            .for_each(|(a, b, value)| matrix[[a, b]] += value);
+        matrix
+    }
+    /// Returns the index of each node among the free nodes, or none if the node is fixed, and the number of free nodes.
+    fn free_nodes(&self, fixed_nodes: &[usize]) -> Result<(Vec<Option<usize>>, usize), PyErrGlue> {
+        let nodes = self.nodes();
+        let mut fixed = vec![false; nodes];
+        fixed_nodes.iter().try_for_each(|&node| {
+            if node < nodes {
+                fixed[node] = true;
+                Ok(())
+            } else {
+                Err(PyErrGlue::new(&format!(
+                    "Fixed node {node} is out of range for {nodes} nodes."
+                )))
+            }
+        })?;
+        let mut count = 0;
+        let index = fixed
+            .into_iter()
+            .map(|fixed| {
+                (!fixed).then(|| {
+                    count += 1;
+                    count - 1
+                })
+            })
+            .collect();
+        Ok((index, count))
+    }
+    /// Restricts a weighted sum of matrices given as entries to the free nodes.
+    fn restrict(
+        index: &[Option<usize>],
+        count: usize,
+        terms: &[(&Entries, Scalar)],
+    ) -> CompressedSparseRows {
+        let entries: Entries = terms
+            .iter()
+            .flat_map(|&(entries, weight)| {
+                entries
+                    .iter()
+                    .filter_map(move |&(a, b, value)| Some((index[a]?, index[b]?, weight * value)))
+            })
+            .collect();
+        CompressedSparseRows::from_triplets((count, count), &entries)
+    }
+    fn heat_sources_or_zero(
+        &self,
+        heat_sources: Option<Vec<Scalar>>,
+    ) -> Result<Vec<Scalar>, PyErrGlue> {
+        let heat_sources = heat_sources.unwrap_or_else(|| vec![0.0; self.nodes()]);
+        check_temperatures(self.elements(), &heat_sources)?;
+        Ok(heat_sources)
+    }
+}
+
+#[pymethods]
+impl HeatConductionBlock {
+    #[new]
+    #[pyo3(signature = (connectivity, reference_nodal_coordinates, thermal_conductivity, heat_capacity = 1.0))]
+    fn new(
+        connectivity: &Bound<'_, PyAny>,
+        reference_nodal_coordinates: &Bound<'_, PyAny>,
+        thermal_conductivity: Scalar,
+        heat_capacity: Scalar,
+    ) -> PyResult<Self> {
+        Self::assembly(
+            vec![(connectivity.clone(), thermal_conductivity, heat_capacity)],
+            reference_nodal_coordinates,
+        )
+    }
+    /// Joins blocks of different materials sharing the reference nodal coordinates,
+    /// each given as a tuple of its connectivity, thermal conductivity, and heat capacity.
+    #[staticmethod]
+    fn assembly(
+        blocks: Vec<(Bound<'_, PyAny>, Scalar, Scalar)>,
+        reference_nodal_coordinates: &Bound<'_, PyAny>,
+    ) -> PyResult<Self> {
+        if blocks.is_empty() {
+            return Err(PyErrGlue::new("Expected at least one block.").into());
+        }
+        let reference_nodal_coordinates =
+            block::reference_nodal_coordinates(reference_nodal_coordinates)?;
+        let nodes = reference_nodal_coordinates.len();
+        let connectivities = blocks
+            .iter()
+            .map(|(connectivity, _, _)| block::element_connectivity(connectivity, nodes))
+            .collect::<PyResult<Vec<_>>>()?;
+        block::check_used(nodes, &connectivities.iter().collect::<Vec<_>>())?;
+        Ok(Self {
+            regions: connectivities
+                .iter()
+                .zip(blocks)
+                .map(|(connectivity, (_, thermal_conductivity, heat_capacity))| {
+                    Ok(Region {
+                        elements: Elements::new(connectivity, &reference_nodal_coordinates)?,
+                        conduction: Conduction::new(thermal_conductivity),
+                        heat_capacity,
+                    })
+                })
+                .collect::<Result<_, PyErrGlue>>()?,
+        })
+    }
+    /// @private
+    #[getter]
+    fn thermal_conductivity(&self) -> Result<Scalar, PyErrGlue> {
+        self.shared("thermal conductivity", |region| {
+            region.conduction.thermal_conductivity()
+        })
+    }
+    /// @private
+    #[getter]
+    fn heat_capacity(&self) -> Result<Scalar, PyErrGlue> {
+        self.shared("heat capacity", |region| region.heat_capacity)
+    }
+    /// $$
+    /// r_a = \int_\Omega k\,\nabla N_a\cdot\nabla T\,dV
+    /// $$
+    fn nodal_fluxes<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_temperatures: Vec<Scalar>,
+    ) -> Result<Bound<'py, PyArray1<Scalar>>, PyErrGlue> {
+        check_temperatures(self.elements(), &nodal_temperatures)?;
+        let mut fluxes = vec![0.0; nodal_temperatures.len()];
+        self.regions.iter().for_each(|region| {
+            region
+                .conduction
+                .nodal_fluxes(
+                    &region.elements,
+                    &vec![IDENTITY; region.elements.volumes().len()],
+                    &nodal_temperatures,
+                )
+                .into_iter()
+                .zip(fluxes.iter_mut())
+                .for_each(|(region_flux, flux)| *flux += region_flux)
+        });
+        Ok(PyArray1::from_vec(py, fluxes))
+    }
+    /// $$
+    /// K_{ab} = \int_\Omega k\,\nabla N_a\cdot\nabla N_b\,dV
+    /// $$
+    fn nodal_conductances<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<Scalar>> {
+        PyArray2::from_owned_array(py, self.dense(self.conductances()))
+    }
+    /// $$
+    /// C_{ab} = \int_\Omega c\,N_aN_b\,dV
+    /// $$
+    #[pyo3(signature = (lumped = false))]
+    fn nodal_capacitances<'py>(
+        &self,
+        py: Python<'py>,
+        lumped: bool,
+    ) -> Bound<'py, PyArray2<Scalar>> {
+        PyArray2::from_owned_array(py, self.dense(self.capacitances(lumped)))
+    }
+    /// $$
+    /// K_{ab}T_b = Q_a\quad\forall a\notin\mathrm{fixed}
+    /// $$
+    #[pyo3(signature = (nodal_temperatures, fixed_nodes, heat_sources = None))]
+    fn solve<'py>(
+        &self,
+        py: Python<'py>,
+        mut nodal_temperatures: Vec<Scalar>,
+        fixed_nodes: Vec<usize>,
+        heat_sources: Option<Vec<Scalar>>,
+    ) -> Result<Bound<'py, PyArray1<Scalar>>, PyErrGlue> {
+        check_temperatures(self.elements(), &nodal_temperatures)?;
+        let heat_sources = self.heat_sources_or_zero(heat_sources)?;
+        let (index, count) = self.free_nodes(&fixed_nodes)?;
+        let conductances = self.conductances();
+        let mut rhs = vec![0.0; count];
+        index
+            .iter()
+            .zip(heat_sources)
+            .for_each(|(&i, heat_source)| i.into_iter().for_each(|i| rhs[i] = heat_source));
+        conductances.iter().for_each(|&(a, b, conductance)| {
+            if let (Some(i), None) = (index[a], index[b]) {
+                rhs[i] -= conductance * nodal_temperatures[b]
+            }
+        });
+        let solution =
+            CholeskyDecomposition::new(&Self::restrict(&index, count, &[(&conductances, 1.0)]))?
+                .solve(&rhs)?;
+        index
+            .iter()
+            .zip(nodal_temperatures.iter_mut())
+            .for_each(|(&i, temperature)| i.into_iter().for_each(|i| *temperature = solution[i]));
+        Ok(PyArray1::from_vec(py, nodal_temperatures))
+    }
+    /// $$
+    /// \left(\mathbf{C} + \theta\,\Delta t\,\mathbf{K}\right)\cdot\mathbf{T}^{n+1} = \left[\mathbf{C} - (1 - \theta)\,\Delta t\,\mathbf{K}\right]\cdot\mathbf{T}^n + \Delta t\,\mathbf{Q}
+    /// $$
+    #[pyo3(signature = (nodal_temperatures, fixed_nodes, times, heat_sources = None, **kwargs))]
+    fn solve_transient<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_temperatures: Vec<Scalar>,
+        fixed_nodes: Vec<usize>,
+        times: Vec<Scalar>,
+        heat_sources: Option<Vec<Scalar>>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErr> {
+        let mut theta = 1.0;
+        let mut lumped = false;
+        if let Some(args) = kwargs {
+            args.into_iter().try_for_each(|(name, value)| {
+                match name.extract()? {
+                    "theta" => theta = value.extract()?,
+                    "lumped" => lumped = value.extract()?,
+                    name => {
+                        return Err(PyErrGlue::new(&format!(
+                            "Unexpected keyword argument {name}."
+                        ))
+                        .into());
+                    }
+                };
+                Ok::<(), PyErr>(())
+            })?
+        }
+        if !(0.0..=1.0).contains(&theta) {
+            return Err(PyErrGlue::new("The parameter theta must be between 0 and 1.").into());
+        }
+        if times.windows(2).any(|window| window[1] <= window[0]) {
+            return Err(PyErrGlue::new("The times must be strictly increasing.").into());
+        }
+        check_temperatures(self.elements(), &nodal_temperatures)?;
+        let heat_sources = self.heat_sources_or_zero(heat_sources)?;
+        let (index, count) = self.free_nodes(&fixed_nodes)?;
+        let conductances = self.conductances();
+        let capacitances = self.capacitances(lumped);
+        let mut history = Array2::zeros((times.len(), nodal_temperatures.len()));
+        let mut temperatures = nodal_temperatures;
+        let mut factorization: Option<(Scalar, CholeskyDecomposition)> = None;
+        for (step, window) in times.windows(2).enumerate() {
+            history
+                .row_mut(step)
+                .assign(&ArrayView1::from(&temperatures));
+            let time_step = window[1] - window[0];
+            let factorization = match factorization {
+                Some((previous, ref factorization)) if previous == time_step => factorization,
+                _ => {
+                    &factorization
+                        .insert((
+                            time_step,
+                            CholeskyDecomposition::new(&Self::restrict(
+                                &index,
+                                count,
+                                &[(&capacitances, 1.0), (&conductances, theta * time_step)],
+                            ))?,
+                        ))
+                        .1
+                }
+            };
+            let mut rhs = vec![0.0; count];
+            index
+                .iter()
+                .zip(heat_sources.iter())
+                .for_each(|(&i, heat_source)| {
+                    i.into_iter().for_each(|i| rhs[i] = time_step * heat_source)
+                });
+            capacitances.iter().for_each(|&(a, b, capacitance)| {
+                if let (Some(i), Some(_)) = (index[a], index[b]) {
+                    rhs[i] += capacitance * temperatures[b]
+                }
+            });
+            conductances.iter().for_each(|&(a, b, conductance)| {
+                if let Some(i) = index[a] {
+                    rhs[i] -= match index[b] {
+                        Some(_) => (1.0 - theta) * time_step,
+                        None => time_step,
+                    } * conductance
+                        * temperatures[b]
+                }
+            });
+            let solution = factorization.solve(&rhs)?;
+            index
+                .iter()
+                .zip(temperatures.iter_mut())
+                .for_each(|(&i, temperature)| {
+                    i.into_iter().for_each(|i| *temperature = solution[i])
+                });
+        }
+        if let Some(mut last) = history.rows_mut().into_iter().last() {
+            last.assign(&ArrayView1::from(&temperatures));
+        }
+        Ok(PyArray2::from_owned_array(py, history))
+    }
+}
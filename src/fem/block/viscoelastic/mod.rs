@@ -0,0 +1,405 @@
+use crate::{
+    PyErrGlue,
+    constitutive::solid::{elastic_hyperviscous, hyperviscoelastic},
+    fem::{
+        block::{
+            self,
+            elastic::{G, M, N, P},
+        },
+        load::Load,
+    },
+};
+use conspire::{
+    constitutive::solid::{
+        elastic_hyperviscous::AlmansiHamel, hyperviscoelastic::SaintVenantKirchhoff,
+    },
+    fem::{
+        NodalCoordinates, NodalCoordinatesHistory, NodalReferenceCoordinates, NodalVelocities,
+        NodalVelocitiesHistory,
+        block::{
+            Block,
+            element::linear::Tetrahedron as LinearTetrahedron,
+            solid::{
+                elastic_hyperviscous::ElasticHyperviscousFiniteElementBlock,
+                hyperviscoelastic::HyperviscoelasticFiniteElementBlock,
+                viscoelastic::ViscoelasticFiniteElementBlock,
+            },
+        },
+    },
+    math::{
+        Matrix, Tensor, Vector,
+        integrate::{DormandPrince, ImplicitDaeFirstOrderRoot},
+        optimize::{EqualityConstraint, NewtonRaphson},
+    },
+    mechanics::Scalar,
+};
+use ndarray::{Array, Array2, Array3, Array4, s};
+use numpy::{PyArray2, PyArray3, PyArray4};
+use pyo3::{prelude::*, types::PyDict};
+
+type History<'py> = (Bound<'py, PyArray3<Scalar>>, Bound<'py, PyArray3<Scalar>>);
+
+enum Inner {
+    AlmansiHamel(Block<AlmansiHamel, LinearTetrahedron, G, M, N, P>),
+    SaintVenantKirchhoff(Block<SaintVenantKirchhoff, LinearTetrahedron, G, M, N, P>),
+}
+
+#[derive(FromPyObject)]
+enum ViscoelasticModel<'py> {
+    AlmansiHamel(Bound<'py, elastic_hyperviscous::AlmansiHamel>),
+    SaintVenantKirchhoff(Bound<'py, hyperviscoelastic::SaintVenantKirchhoff>),
+}
+
+macro_rules! viscoelastic_block {
+    ($model: ident, $name: ident, $connectivity: ident, $reference_nodal_coordinates: ident) => {
+        Inner::$name(Block::from((
+            $name {
+                bulk_modulus: $model.getattr("bulk_modulus")?.extract()?,
+                shear_modulus: $model.getattr("shear_modulus")?.extract()?,
+                bulk_viscosity: $model.getattr("bulk_viscosity")?.extract()?,
+                shear_viscosity: $model.getattr("shear_viscosity")?.extract()?,
+            },
+            $connectivity,
+            NodalReferenceCoordinates::from($reference_nodal_coordinates),
+        )))
+    };
+}
+
+macro_rules! with_block {
+    ($self: expr, $block: ident => $expression: expr) => {
+        match &$self.block {
+            Inner::AlmansiHamel($block) => $expression,
+            Inner::SaintVenantKirchhoff($block) => $expression,
+        }
+    };
+}
+
+/// Finite element block of a viscoelastic constitutive model, where the nodal forces depend on both the nodal coordinates and the nodal velocities.
+#[pyclass]
+pub struct ViscoelasticBlock {
+    block: Inner,
+    nodes: usize,
+}
+
+impl ViscoelasticBlock {
+    fn check_nodes(&self, nodal_coordinates: &[[Scalar; 3]]) -> Result<(), PyErrGlue> {
+        if nodal_coordinates.len() == self.nodes {
+            Ok(())
+        } else {
+            Err(PyErrGlue::new(&format!(
+                "Expected {} nodal coordinates, got {}.",
+                self.nodes,
+                nodal_coordinates.len()
+            )))
+        }
+    }
+    fn check_velocities(&self, nodal_velocities: &[[Scalar; 3]]) -> Result<(), PyErrGlue> {
+        if nodal_velocities.len() == self.nodes {
+            Ok(())
+        } else {
+            Err(PyErrGlue::new(&format!(
+                "Expected {} nodal velocities, got {}.",
+                self.nodes,
+                nodal_velocities.len()
+            )))
+        }
+    }
+    fn forces(
+        &self,
+        nodal_coordinates: &NodalCoordinates,
+        nodal_velocities: &NodalVelocities,
+    ) -> Result<Array2<Scalar>, PyErrGlue> {
+        let forces: Vec<Vec<Scalar>> =
+            with_block!(self, block => block.nodal_forces(nodal_coordinates, nodal_velocities))?
+                .into();
+        Ok(Array2::from_shape_vec(
+            (forces.len(), 3),
+            forces.into_iter().flatten().collect(),
+        )?)
+    }
+    fn stiffnesses(
+        &self,
+        nodal_coordinates: &NodalCoordinates,
+        nodal_velocities: &NodalVelocities,
+    ) -> Result<Array4<Scalar>, PyErrGlue> {
+        let stiffnesses = with_block!(
+            self,
+            block => block.nodal_stiffnesses(nodal_coordinates, nodal_velocities)
+        )?;
+        Ok(Array::from_shape_vec(
+            (self.nodes, self.nodes, 3, 3),
+            stiffnesses.into(),
+        )?)
+    }
+}
+
+#[pymethods]
+impl ViscoelasticBlock {
+    #[new]
+    fn new(
+        model: ViscoelasticModel,
+        connectivity: &Bound<'_, PyAny>,
+        reference_nodal_coordinates: &Bound<'_, PyAny>,
+    ) -> Result<Self, PyErr> {
+        let reference_nodal_coordinates =
+            block::reference_nodal_coordinates(reference_nodal_coordinates)?;
+        let nodes = reference_nodal_coordinates.len();
+        let connectivity = block::connectivity(connectivity, nodes)?;
+        let block = match model {
+            ViscoelasticModel::AlmansiHamel(model) => viscoelastic_block!(
+                model,
+                AlmansiHamel,
+                connectivity,
+                reference_nodal_coordinates
+            ),
+            ViscoelasticModel::SaintVenantKirchhoff(model) => viscoelastic_block!(
+                model,
+                SaintVenantKirchhoff,
+                connectivity,
+                reference_nodal_coordinates
+            ),
+        };
+        Ok(Self { block, nodes })
+    }
+    /// $$
+    /// \mathbf{f}_a = \int_\Omega\mathbf{P}(\mathbf{F},\dot{\mathbf{F}})\cdot\nabla_0 N_a\,dV
+    /// $$
+    fn nodal_forces<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        nodal_velocities: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+        self.check_nodes(&nodal_coordinates)?;
+        self.check_velocities(&nodal_velocities)?;
+        Ok(PyArray2::from_owned_array(
+            py,
+            self.forces(
+                &NodalCoordinates::from(nodal_coordinates),
+                &NodalVelocities::from(nodal_velocities),
+            )?,
+        ))
+    }
+    /// $$
+    /// \mathbf{K}_{ab} = \frac{\partial\mathbf{f}_a}{\partial\mathbf{v}_b}
+    /// $$
+    fn nodal_stiffnesses<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        nodal_velocities: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
+        self.check_nodes(&nodal_coordinates)?;
+        self.check_velocities(&nodal_velocities)?;
+        Ok(PyArray4::from_owned_array(
+            py,
+            self.stiffnesses(
+                &NodalCoordinates::from(nodal_coordinates),
+                &NodalVelocities::from(nodal_velocities),
+            )?,
+        ))
+    }
+    /// $$
+    /// \Phi = \int_\Omega\phi(\mathbf{F},\dot{\mathbf{F}})\,dV
+    /// $$
+    fn viscous_dissipation(
+        &self,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        nodal_velocities: Vec<[Scalar; 3]>,
+    ) -> Result<Scalar, PyErrGlue> {
+        self.check_nodes(&nodal_coordinates)?;
+        self.check_velocities(&nodal_velocities)?;
+        Ok(with_block!(self, block => block.viscous_dissipation(
+            &NodalCoordinates::from(nodal_coordinates),
+            &NodalVelocities::from(nodal_velocities),
+        ))?)
+    }
+    /// $$
+    /// \Pi = \int_\Omega\left[\mathbf{P}^e(\mathbf{F}):\dot{\mathbf{F}} + \phi(\mathbf{F},\dot{\mathbf{F}})\right]dV
+    /// $$
+    fn dissipation_potential(
+        &self,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        nodal_velocities: Vec<[Scalar; 3]>,
+    ) -> Result<Scalar, PyErrGlue> {
+        self.check_nodes(&nodal_coordinates)?;
+        self.check_velocities(&nodal_velocities)?;
+        Ok(with_block!(self, block => block.dissipation_potential(
+            &NodalCoordinates::from(nodal_coordinates),
+            &NodalVelocities::from(nodal_velocities),
+        ))?)
+    }
+    /// $$
+    /// A = \int_\Omega a(\mathbf{F})\,dV
+    /// $$
+    fn helmholtz_free_energy(
+        &self,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Scalar, PyErrGlue> {
+        self.check_nodes(&nodal_coordinates)?;
+        match &self.block {
+            Inner::AlmansiHamel(_) => Err(PyErrGlue::new(
+                "The Helmholtz free energy is undefined for elastic-hyperviscous constitutive models.",
+            )),
+            Inner::SaintVenantKirchhoff(block) => {
+                Ok(block.helmholtz_free_energy(&NodalCoordinates::from(nodal_coordinates))?)
+            }
+        }
+    }
+    /// Integrates the nodal coordinates over the given times, returning the nodal coordinates and velocities at each time.
+    ///
+    /// $$
+    /// \mathbf{f}_a(\mathbf{x},\mathbf{v}) + \mathbf{f}_a^\mathrm{load}(\mathbf{x}) = \mathbf{0}\quad\forall a\notin\mathrm{fixed},\qquad \mathbf{v}_a = \bar{\mathbf{v}}_a\quad\forall a\in\mathrm{fixed}
+    /// $$
+    ///
+    /// The fixed nodes move with the given velocities, which are zero by default.
+    #[pyo3(signature = (nodal_coordinates, fixed_nodes, times, fixed_velocities = None, loads = Vec::new(), **kwargs))]
+    #[allow(clippy::too_many_arguments)]
+    fn solve<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        fixed_nodes: Vec<usize>,
+        times: Vec<Scalar>,
+        fixed_velocities: Option<Vec<[Scalar; 3]>>,
+        loads: Vec<Load>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> Result<History<'py>, PyErr> {
+        let mut solver = NewtonRaphson::default();
+        if let Some(args) = kwargs {
+            args.into_iter().try_for_each(|(name, value)| {
+                match name.extract()? {
+                    "abs_tol" => solver.abs_tol = value.extract()?,
+                    "max_steps" => solver.max_steps = value.extract()?,
+                    _ => (),
+                };
+                Ok::<(), PyErr>(())
+            })?
+        }
+        self.check_nodes(&nodal_coordinates)?;
+        let nodes = self.nodes;
+        if times.len() < 2 {
+            return Err(PyErrGlue::new("At least two times are required.").into());
+        }
+        if times.iter().any(|time| !time.is_finite())
+            || times.windows(2).any(|window| window[1] <= window[0])
+        {
+            return Err(PyErrGlue::new("The times must be strictly increasing.").into());
+        }
+        if fixed_nodes.is_empty() {
+            return Err(PyErrGlue::new("At least one node must be fixed.").into());
+        }
+        if let Some(node) = fixed_nodes.iter().find(|&&node| node >= nodes) {
+            return Err(PyErrGlue::new(&format!(
+                "Fixed node {node} is out of range for {nodes} nodes."
+            ))
+            .into());
+        }
+        let fixed_velocities =
+            fixed_velocities.unwrap_or_else(|| vec![[0.0; 3]; fixed_nodes.len()]);
+        if fixed_velocities.len() != fixed_nodes.len() {
+            return Err(PyErrGlue::new(&format!(
+                "Expected {} fixed velocities, got {}.",
+                fixed_nodes.len(),
+                fixed_velocities.len()
+            ))
+            .into());
+        }
+        if loads
+            .iter()
+            .any(|load| matches!(load, Load::RigidCylinder(_) | Load::RigidPlane(_)))
+        {
+            return Err(
+                PyErrGlue::new("Contact loads are not supported by viscoelastic blocks.").into(),
+            );
+        }
+        let mut prescribed = vec![None; nodes];
+        fixed_nodes
+            .iter()
+            .zip(fixed_velocities)
+            .for_each(|(&node, velocity)| prescribed[node] = Some(velocity));
+        let (rows, values): (Vec<Vector>, Vec<Scalar>) = prescribed
+            .iter()
+            .enumerate()
+            .filter_map(|(node, velocity)| velocity.map(|velocity| (node, velocity)))
+            .flat_map(|(node, velocity)| {
+                (0..3).map(move |i| {
+                    let mut row = vec![0.0; 3 * nodes];
+                    row[3 * node + i] = 1.0;
+                    (Vector::from(row), velocity[i])
+                })
+            })
+            .unzip();
+        let constraint =
+            EqualityConstraint::Linear(rows.into_iter().collect::<Matrix>(), Vector::from(values));
+        let function = |_: Scalar, x: &NodalCoordinates, v: &NodalVelocities| {
+            let mut forces = with_block!(self, block => block.nodal_forces(x, v))?;
+            if !loads.is_empty() {
+                let coordinates: Vec<[Scalar; 3]> = x.iter().map(|x| [x[0], x[1], x[2]]).collect();
+                loads
+                    .iter()
+                    .try_for_each(|load| {
+                        forces
+                            .iter_mut()
+                            .zip(load.forces(py, &coordinates)?.rows())
+                            .for_each(|(force, load_force)| {
+                                (0..3).for_each(|i| force[i] += load_force[i])
+                            });
+                        Ok::<_, PyErrGlue>(())
+                    })
+                    .map_err(|error| error.message)?;
+            }
+            Ok(forces)
+        };
+        let jacobian = |_: Scalar, x: &NodalCoordinates, v: &NodalVelocities| {
+            Ok(with_block!(self, block => block.nodal_stiffnesses(x, v))?)
+        };
+        let mut coordinates = Array3::zeros((times.len(), nodes, 3));
+        let mut velocities = Array3::zeros((times.len(), nodes, 3));
+        let mut current = NodalCoordinates::from(nodal_coordinates);
+        // Integrating each interval separately returns the solution at exactly the given times.
+        for (step, interval) in times.windows(2).enumerate() {
+            let (_, coordinates_history, velocities_history): (
+                _,
+                NodalCoordinatesHistory,
+                NodalVelocitiesHistory,
+            ) = DormandPrince::default()
+                .integrate(
+                    &function,
+                    &jacobian,
+                    NewtonRaphson {
+                        abs_tol: solver.abs_tol,
+                        max_steps: solver.max_steps,
+                        ..Default::default()
+                    },
+                    interval,
+                    current,
+                    |_| constraint.clone(),
+                )
+                .map_err(PyErrGlue::from)?;
+            let mut histories = coordinates_history.iter().zip(velocities_history.iter());
+            if step == 0
+                && let Some((x, v)) = histories.next()
+            {
+                assign(&mut coordinates, 0, x);
+                assign(&mut velocities, 0, v);
+            }
+            let (x, v) = histories.last().unwrap();
+            assign(&mut coordinates, step + 1, x);
+            assign(&mut velocities, step + 1, v);
+            current = x.clone();
+        }
+        Ok((
+            PyArray3::from_owned_array(py, coordinates),
+            PyArray3::from_owned_array(py, velocities),
+        ))
+    }
+}
+
+fn assign(history: &mut Array3<Scalar>, step: usize, values: &NodalCoordinates) {
+    history
+        .slice_mut(s![step, .., ..])
+        .iter_mut()
+        .zip(values.iter().flat_map(|value| value.iter()))
+        .for_each(|(entry, value)| *entry = *value);
+}
@@ -0,0 +1,266 @@
+use crate::{PyErrGlue, fem::load::cross};
+use conspire::mechanics::Scalar;
+use ndarray::Array2;
+use numpy::{PyArray1, PyArray2};
+use pyo3::prelude::*;
+
+pub fn register_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<LinearConstraint>()?;
+    m.add_class::<RigidBody>()
+}
+
+type Vector = [Scalar; 3];
+
+/// A coefficient multiplying one coordinate of one node.
+pub type Term = (usize, usize, Scalar);
+
+fn rotation(rotation_vector: Vector) -> [[Scalar; 3]; 3] {
+    let angle = rotation_vector.iter().map(|r| r * r).sum::<Scalar>().sqrt();
+    let skew = [
+        [0.0, -rotation_vector[2], rotation_vector[1]],
+        [rotation_vector[2], 0.0, -rotation_vector[0]],
+        [-rotation_vector[1], rotation_vector[0], 0.0],
+    ];
+    let (first, second) = if angle > 0.0 {
+        (angle.sin() / angle, (1.0 - angle.cos()) / (angle * angle))
+    } else {
+        (1.0, 0.5)
+    };
+    [0, 1, 2].map(|i| {
+        [0, 1, 2].map(|j| {
+            let identity = if i == j { 1.0 } else { 0.0 };
+            identity
+                + first * skew[i][j]
+                + second * (0..3).map(|k| skew[i][k] * skew[k][j]).sum::<Scalar>()
+        })
+    })
+}
+
+/// Linear multipoint constraint enforced with a Lagrange multiplier.
+#[pyclass]
+pub struct LinearConstraint {
+    terms: Vec<Term>,
+    value: Scalar,
+    multiplier: Scalar,
+}
+
+#[pymethods]
+impl LinearConstraint {
+    #[new]
+    #[pyo3(signature = (terms, value = 0.0))]
+    fn new(terms: Vec<Term>, value: Scalar) -> Result<Self, PyErrGlue> {
+        if let Some((_, i, _)) = terms.iter().find(|&&(_, i, _)| i > 2) {
+            return Err(PyErrGlue::new(&format!(
+                "Coordinate {i} is out of range for 3 dimensions."
+            )));
+        }
+        Ok(Self {
+            terms,
+            value,
+            multiplier: 0.0,
+        })
+    }
+    /// @private
+    #[getter]
+    fn terms(&self) -> Vec<Term> {
+        self.terms.clone()
+    }
+    /// @private
+    #[getter]
+    fn value(&self) -> Scalar {
+        self.value
+    }
+    /// $$
+    /// \sum_k c_k\,x_{a_ki_k} = g,\quad \mathbf{f}_{a_k} + \lambda\,c_k\,\mathbf{e}_{i_k} = \mathbf{0}
+    /// $$
+    #[getter]
+    fn multiplier(&self) -> Scalar {
+        self.multiplier
+    }
+}
+
+/// Rigid body tying a set of nodes to the motion of a reference point.
+///
+/// The translation and rotation of the reference point are prescribed rather than solved for,
+/// so the tied nodes are fixed at their rigid positions and the resultant load is reported as a reaction.
+#[pyclass]
+pub struct RigidBody {
+    nodes: Vec<usize>,
+    reference_point: Vector,
+    translation: Vector,
+    rotation: Vector,
+    reaction_force: Vector,
+    reaction_moment: Vector,
+}
+
+impl RigidBody {
+    fn position(&self) -> Vector {
+        [0, 1, 2].map(|i| self.reference_point[i] + self.translation[i])
+    }
+    fn prescribe(&self, reference_nodal_coordinates: &[Vector], nodal_coordinates: &mut [Vector]) {
+        let position = self.position();
+        let rotation = rotation(self.rotation);
+        self.nodes.iter().for_each(|&node| {
+            let offset =
+                [0, 1, 2].map(|i| reference_nodal_coordinates[node][i] - self.reference_point[i]);
+            nodal_coordinates[node] = [0, 1, 2]
+                .map(|i| position[i] + (0..3).map(|j| rotation[i][j] * offset[j]).sum::<Scalar>())
+        })
+    }
+    fn commit(&mut self, nodal_coordinates: &[Vector], forces: &Array2<Scalar>) {
+        let position = self.position();
+        self.reaction_force = [0.0; 3];
+        self.reaction_moment = [0.0; 3];
+        self.nodes.iter().for_each(|&node| {
+            let force = [0, 1, 2].map(|i| forces[[node, i]]);
+            let arm = [0, 1, 2].map(|i| nodal_coordinates[node][i] - position[i]);
+            let moment = cross(arm, force);
+            (0..3).for_each(|i| {
+                self.reaction_force[i] += force[i];
+                self.reaction_moment[i] += moment[i]
+            })
+        })
+    }
+}
+
+#[pymethods]
+impl RigidBody {
+    #[new]
+    #[pyo3(signature = (reference_point, nodes, translation = [0.0; 3], rotation = [0.0; 3]))]
+    fn new(
+        reference_point: Vector,
+        nodes: Vec<usize>,
+        translation: Vector,
+        rotation: Vector,
+    ) -> Self {
+        Self {
+            nodes,
+            reference_point,
+            translation,
+            rotation,
+            reaction_force: [0.0; 3],
+            reaction_moment: [0.0; 3],
+        }
+    }
+    /// @private
+    #[getter]
+    fn nodes(&self) -> Vec<usize> {
+        self.nodes.clone()
+    }
+    /// @private
+    #[getter]
+    fn reference_point<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<Scalar>> {
+        PyArray1::from_slice(py, &self.reference_point)
+    }
+    /// @private
+    #[getter]
+    fn translation<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<Scalar>> {
+        PyArray1::from_slice(py, &self.translation)
+    }
+    /// @private
+    #[getter]
+    fn rotation<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<Scalar>> {
+        PyArray1::from_slice(py, &self.rotation)
+    }
+    /// $$
+    /// \mathbf{R} = \sum_{a\in S}\mathbf{f}_a
+    /// $$
+    #[getter]
+    fn reaction_force<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<Scalar>> {
+        PyArray1::from_slice(py, &self.reaction_force)
+    }
+    /// $$
+    /// \mathbf{M} = \sum_{a\in S}(\mathbf{x}_a - \mathbf{x}_0)\times\mathbf{f}_a
+    /// $$
+    #[getter]
+    fn reaction_moment<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<Scalar>> {
+        PyArray1::from_slice(py, &self.reaction_moment)
+    }
+    /// $$
+    /// \mathbf{x}_a = \mathbf{X}_0 + \mathbf{u}_0 + \mathbf{R}(\boldsymbol{\theta})\cdot(\mathbf{X}_a - \mathbf{X}_0)
+    /// $$
+    fn nodal_coordinates<'py>(
+        &self,
+        py: Python<'py>,
+        reference_nodal_coordinates: Vec<Vector>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+        Constraint::check(&self.nodes, reference_nodal_coordinates.len())?;
+        let mut nodal_coordinates = reference_nodal_coordinates.clone();
+        self.prescribe(&reference_nodal_coordinates, &mut nodal_coordinates);
+        Ok(PyArray2::from_owned_array(
+            py,
+            Array2::from(nodal_coordinates),
+        ))
+    }
+}
+
+#[derive(FromPyObject)]
+pub enum Constraint {
+    LinearConstraint(Py<LinearConstraint>),
+    RigidBody(Py<RigidBody>),
+}
+
+impl Constraint {
+    fn check(nodes: &[usize], count: usize) -> Result<(), PyErrGlue> {
+        match nodes.iter().find(|&&node| node >= count) {
+            Some(node) => Err(PyErrGlue::new(&format!(
+                "Constrained node {node} is out of range for {count} nodes."
+            ))),
+            None => Ok(()),
+        }
+    }
+    /// Prescribes the coordinates of tied nodes relative to their reference coordinates and returns them.
+    pub fn prescribe(
+        &self,
+        py: Python,
+        reference_nodal_coordinates: &[Vector],
+        nodal_coordinates: &mut [Vector],
+    ) -> Result<Vec<usize>, PyErrGlue> {
+        match self {
+            Self::LinearConstraint(constraint) => {
+                let constraint = constraint.borrow(py);
+                let nodes: Vec<usize> = constraint.terms.iter().map(|&(node, _, _)| node).collect();
+                Self::check(&nodes, nodal_coordinates.len())?;
+                Ok(Vec::new())
+            }
+            Self::RigidBody(constraint) => {
+                let constraint = constraint.borrow(py);
+                Self::check(&constraint.nodes, nodal_coordinates.len())?;
+                if reference_nodal_coordinates.len() != nodal_coordinates.len() {
+                    return Err(PyErrGlue::new(&format!(
+                        "Expected {} nodal coordinates, got {}.",
+                        reference_nodal_coordinates.len(),
+                        nodal_coordinates.len()
+                    )));
+                }
+                constraint.prescribe(reference_nodal_coordinates, nodal_coordinates);
+                Ok(constraint.nodes.clone())
+            }
+        }
+    }
+    pub fn equation(&self, py: Python) -> Option<(Vec<Term>, Scalar)> {
+        match self {
+            Self::LinearConstraint(constraint) => {
+                let constraint = constraint.borrow(py);
+                Some((constraint.terms.clone(), constraint.value))
+            }
+            Self::RigidBody(_) => None,
+        }
+    }
+    pub fn commit(
+        &self,
+        py: Python,
+        nodal_coordinates: &[Vector],
+        forces: &Array2<Scalar>,
+        multipliers: &mut impl Iterator<Item = Scalar>,
+    ) {
+        match self {
+            Self::LinearConstraint(constraint) => {
+                constraint.borrow_mut(py).multiplier = multipliers.next().unwrap_or_default()
+            }
+            Self::RigidBody(constraint) => {
+                constraint.borrow_mut(py).commit(nodal_coordinates, forces)
+            }
+        }
+    }
+}
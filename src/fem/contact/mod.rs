@@ -0,0 +1,537 @@
+use crate::{
+    PyErrGlue,
+    fem::block::{Tensor, product},
+};
+use conspire::mechanics::Scalar;
+use ndarray::{Array1, Array2, Array4};
+use numpy::{PyArray1, PyArray2, PyArray4};
+use pyo3::{prelude::*, types::PyDict};
+
+type Vector = [Scalar; 3];
+
+fn dot(u: Vector, v: Vector) -> Scalar {
+    u[0] * v[0] + u[1] * v[1] + u[2] * v[2]
+}
+
+fn outer(u: Vector, v: Vector) -> Tensor {
+    u.map(|u_i| v.map(|v_j| u_i * v_j))
+}
+
+fn projection(n: Vector) -> Tensor {
+    [0, 1, 2].map(|i| [0, 1, 2].map(|j| if i == j { 1.0 } else { 0.0 } - n[i] * n[j]))
+}
+
+fn apply(tensor: &Tensor, v: Vector) -> Vector {
+    tensor.map(|row| dot(row, v))
+}
+
+fn unit(v: Vector, name: &str) -> Result<Vector, PyErrGlue> {
+    let norm = dot(v, v).sqrt();
+    if norm > 0.0 {
+        Ok(v.map(|v_i| v_i / norm))
+    } else {
+        Err(PyErrGlue::new(&format!("The {name} must be nonzero.")))
+    }
+}
+
+enum Surface {
+    Plane {
+        point: Vector,
+        normal: Vector,
+    },
+    Cylinder {
+        point: Vector,
+        axis: Vector,
+        radius: Scalar,
+        inside: bool,
+    },
+}
+
+impl Surface {
+    /// Returns the gap.
+    fn gap(&self, x: Vector) -> Scalar {
+        match self {
+            Self::Plane { point, normal } => dot([0, 1, 2].map(|i| x[i] - point[i]), *normal),
+            Self::Cylinder {
+                point,
+                axis,
+                radius,
+                inside,
+            } => {
+                let radial = Self::radial(point, axis, x);
+                let sign = if *inside { -1.0 } else { 1.0 };
+                sign * (dot(radial, radial).sqrt() - radius)
+            }
+        }
+    }
+    /// Returns the outward normal of the surface and the derivative of the normal,
+    /// or nothing on the axis of a cylinder, where the normal is undefined.
+    fn normal(&self, x: Vector) -> Option<(Vector, Tensor)> {
+        match self {
+            Self::Plane { normal, .. } => Some((*normal, [[0.0; 3]; 3])),
+            Self::Cylinder {
+                point,
+                axis,
+                inside,
+                ..
+            } => {
+                let radial = Self::radial(point, axis, x);
+                let distance = dot(radial, radial).sqrt();
+                if distance == 0.0 {
+                    return None;
+                }
+                let direction = radial.map(|r| r / distance);
+                let sign = if *inside { -1.0 } else { 1.0 };
+                let axial = projection(*axis);
+                let radial_projection = outer(direction, direction);
+                Some((
+                    direction.map(|d| sign * d),
+                    [0, 1, 2].map(|i| {
+                        [0, 1, 2].map(|j| sign * (axial[i][j] - radial_projection[i][j]) / distance)
+                    }),
+                ))
+            }
+        }
+    }
+    fn radial(point: &Vector, axis: &Vector, x: Vector) -> Vector {
+        let relative = [0, 1, 2].map(|i| x[i] - point[i]);
+        let along = dot(relative, *axis);
+        [0, 1, 2].map(|i| relative[i] - along * axis[i])
+    }
+}
+
+enum Traction {
+    Separated,
+    Stick(Vector),
+    Slip(Vector, Scalar),
+}
+
+/// Node-to-surface contact with a rigid surface.
+struct Contact {
+    surface: Surface,
+    nodes: Vec<usize>,
+    penalty: Scalar,
+    friction: Scalar,
+    augmented: bool,
+    abs_tol: Scalar,
+    multipliers: Vec<Scalar>,
+    anchors: Vec<Option<Vector>>,
+    starts: Vec<Vector>,
+}
+
+impl Contact {
+    fn new(
+        surface: Surface,
+        nodes: Vec<usize>,
+        penalty: Scalar,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> Result<Self, PyErr> {
+        let mut friction = 0.0;
+        let mut augmented = false;
+        let mut abs_tol = 1e-8;
+        if let Some(args) = kwargs {
+            args.into_iter().try_for_each(|(name, value)| {
+                match name.extract()? {
+                    "abs_tol" => abs_tol = value.extract()?,
+                    "augmented" => augmented = value.extract()?,
+                    "friction" => friction = value.extract()?,
+                    name => {
+                        return Err(PyErrGlue::new(&format!(
+                            "Unexpected keyword argument {name}."
+                        ))
+                        .into());
+                    }
+                };
+                Ok::<(), PyErr>(())
+            })?
+        }
+        if penalty <= 0.0 {
+            return Err(PyErrGlue::new("The penalty must be positive.").into());
+        }
+        if friction < 0.0 {
+            return Err(PyErrGlue::new("The friction coefficient must be nonnegative.").into());
+        }
+        if let Some((index, node)) = nodes
+            .iter()
+            .enumerate()
+            .find(|(index, node)| nodes[..*index].contains(node))
+        {
+            return Err(PyErrGlue::new(&format!(
+                "Contact node {node} is repeated at position {index}."
+            ))
+            .into());
+        }
+        Ok(Self {
+            surface,
+            multipliers: vec![0.0; nodes.len()],
+            anchors: vec![None; nodes.len()],
+            starts: Vec::new(),
+            nodes,
+            penalty,
+            friction,
+            augmented,
+            abs_tol,
+        })
+    }
+    fn check(&self, nodal_coordinates: &[Vector]) -> Result<(), PyErrGlue> {
+        let nodes = nodal_coordinates.len();
+        match self.nodes.iter().find(|&&node| node >= nodes) {
+            Some(node) => Err(PyErrGlue::new(&format!(
+                "Contact node {node} is out of range for {nodes} nodes."
+            ))),
+            None => Ok(()),
+        }
+    }
+    /// Returns the contact pressure, normal, curvature, and tangential traction,
+    /// or nothing if the node is separated from the surface.
+    fn evaluate(
+        &self,
+        index: usize,
+        x: Vector,
+    ) -> Result<Option<(Scalar, Vector, Tensor, Traction)>, PyErrGlue> {
+        let pressure = self.multipliers[index] - self.penalty * self.surface.gap(x);
+        if pressure <= 0.0 {
+            return Ok(None);
+        }
+        let Some((normal, curvature)) = self.surface.normal(x) else {
+            return Err(PyErrGlue::new(&format!(
+                "Contact node {} is on the axis, where the normal is undefined.",
+                self.nodes[index]
+            )));
+        };
+        let anchor = self.anchors[index].or_else(|| self.starts.get(index).copied());
+        let Some(anchor) = anchor.filter(|_| self.friction > 0.0) else {
+            return Ok(Some((pressure, normal, curvature, Traction::Separated)));
+        };
+        let tangential = projection(normal);
+        let trial = apply(
+            &tangential,
+            [0, 1, 2].map(|i| self.penalty * (anchor[i] - x[i])),
+        );
+        let magnitude = dot(trial, trial).sqrt();
+        let traction = if magnitude <= self.friction * pressure {
+            Traction::Stick(trial)
+        } else {
+            Traction::Slip(
+                trial.map(|t| self.friction * pressure * t / magnitude),
+                magnitude,
+            )
+        };
+        Ok(Some((pressure, normal, curvature, traction)))
+    }
+    fn forces(&self, nodal_coordinates: &[Vector]) -> Result<Array2<Scalar>, PyErrGlue> {
+        self.check(nodal_coordinates)?;
+        let mut nodal_forces = Array2::zeros((nodal_coordinates.len(), 3));
+        self.nodes
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, &node)| {
+                if let Some((pressure, normal, _, traction)) =
+                    self.evaluate(index, nodal_coordinates[node])?
+                {
+                    let tangential = match traction {
+                        Traction::Separated => [0.0; 3],
+                        Traction::Stick(t) | Traction::Slip(t, _) => t,
+                    };
+                    (0..3).for_each(|i| {
+                        nodal_forces[[node, i]] -= pressure * normal[i] + tangential[i]
+                    })
+                }
+                Ok::<_, PyErrGlue>(())
+            })?;
+        Ok(nodal_forces)
+    }
+    fn stiffnesses(&self, nodal_coordinates: &[Vector]) -> Result<Array4<Scalar>, PyErrGlue> {
+        self.check(nodal_coordinates)?;
+        let nodes = nodal_coordinates.len();
+        let mut nodal_stiffnesses = Array4::zeros((nodes, nodes, 3, 3));
+        self.nodes
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, &node)| {
+                if let Some((pressure, normal, curvature, traction)) =
+                    self.evaluate(index, nodal_coordinates[node])?
+                {
+                    let normal_normal = outer(normal, normal);
+                    let tangential = projection(normal);
+                    let frictional = match traction {
+                        Traction::Separated => [[0.0; 3]; 3],
+                        Traction::Stick(_) => tangential.map(|row| row.map(|t| self.penalty * t)),
+                        Traction::Slip(t, magnitude) => {
+                            let direction = t.map(|t_i| t_i / dot(t, t).sqrt());
+                            let slip = product(&projection(direction), &tangential);
+                            let coupling = outer(direction, normal);
+                            let scale = self.friction * self.penalty;
+                            [0, 1, 2].map(|i| {
+                                [0, 1, 2].map(|j| {
+                                    scale * (coupling[i][j] + pressure * slip[i][j] / magnitude)
+                                })
+                            })
+                        }
+                    };
+                    (0..3).for_each(|i| {
+                        (0..3).for_each(|j| {
+                            nodal_stiffnesses[[node, node, i, j]] +=
+                                self.penalty * normal_normal[i][j] - pressure * curvature[i][j]
+                                    + frictional[i][j]
+                        })
+                    })
+                }
+                Ok::<_, PyErrGlue>(())
+            })?;
+        Ok(nodal_stiffnesses)
+    }
+    fn gaps(&self, nodal_coordinates: &[Vector]) -> Result<Array1<Scalar>, PyErrGlue> {
+        self.check(nodal_coordinates)?;
+        Ok(self
+            .nodes
+            .iter()
+            .map(|&node| self.surface.gap(nodal_coordinates[node]))
+            .collect())
+    }
+    fn begin(&mut self, nodal_coordinates: &[Vector]) -> Result<(), PyErrGlue> {
+        self.check(nodal_coordinates)?;
+        self.starts = self
+            .nodes
+            .iter()
+            .map(|&node| nodal_coordinates[node])
+            .collect();
+        Ok(())
+    }
+    fn augment(&mut self, nodal_coordinates: &[Vector]) -> Result<bool, PyErrGlue> {
+        if !self.augmented {
+            return Ok(true);
+        }
+        let gaps = self.gaps(nodal_coordinates)?;
+        if gaps
+            .iter()
+            .zip(self.multipliers.iter())
+            .all(|(&gap, &multiplier)| {
+                multiplier - self.penalty * gap <= 0.0 || -gap <= self.abs_tol
+            })
+        {
+            return Ok(true);
+        }
+        self.multipliers
+            .iter_mut()
+            .zip(gaps.iter())
+            .for_each(|(multiplier, gap)| {
+                *multiplier = (*multiplier - self.penalty * gap).max(0.0)
+            });
+        Ok(false)
+    }
+    fn commit(&mut self, nodal_coordinates: &[Vector]) -> Result<(), PyErrGlue> {
+        self.check(nodal_coordinates)?;
+        self.anchors = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, &node)| {
+                let x = nodal_coordinates[node];
+                Ok(match self.evaluate(index, x)? {
+                    Some((_, _, _, Traction::Stick(_))) => {
+                        self.anchors[index].or_else(|| self.starts.get(index).copied())
+                    }
+                    Some((_, _, _, Traction::Slip(t, _))) => {
+                        Some([0, 1, 2].map(|i| x[i] + t[i] / self.penalty))
+                    }
+                    _ => None,
+                })
+            })
+            .collect::<Result<_, PyErrGlue>>()?;
+        self.starts.clear();
+        Ok(())
+    }
+}
+
+macro_rules! contact {
+    ($name: ident) => {
+        impl $name {
+            pub(crate) fn forces(
+                &self,
+                nodal_coordinates: &[Vector],
+            ) -> Result<Array2<Scalar>, PyErrGlue> {
+                self.contact.forces(nodal_coordinates)
+            }
+            pub(crate) fn stiffnesses(
+                &self,
+                nodal_coordinates: &[Vector],
+            ) -> Result<Array4<Scalar>, PyErrGlue> {
+                self.contact.stiffnesses(nodal_coordinates)
+            }
+            pub(crate) fn begin(&mut self, nodal_coordinates: &[Vector]) -> Result<(), PyErrGlue> {
+                self.contact.begin(nodal_coordinates)
+            }
+            pub(crate) fn augment(
+                &mut self,
+                nodal_coordinates: &[Vector],
+            ) -> Result<bool, PyErrGlue> {
+                self.contact.augment(nodal_coordinates)
+            }
+            pub(crate) fn commit(&mut self, nodal_coordinates: &[Vector]) -> Result<(), PyErrGlue> {
+                self.contact.commit(nodal_coordinates)
+            }
+        }
+    };
+}
+
+/// Contact between a set of nodes and a rigid plane.
+#[pyclass]
+pub struct RigidPlane {
+    contact: Contact,
+}
+
+contact!(RigidPlane);
+
+#[pymethods]
+impl RigidPlane {
+    #[new]
+    #[pyo3(signature = (point, normal, nodes, penalty, **kwargs))]
+    fn new(
+        point: [Scalar; 3],
+        normal: [Scalar; 3],
+        nodes: Vec<usize>,
+        penalty: Scalar,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> Result<Self, PyErr> {
+        Ok(Self {
+            contact: Contact::new(
+                Surface::Plane {
+                    point,
+                    normal: unit(normal, "normal")?,
+                },
+                nodes,
+                penalty,
+                kwargs,
+            )?,
+        })
+    }
+    /// The Lagrange multipliers of the contact nodes.
+    #[getter]
+    fn multipliers<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<Scalar>> {
+        PyArray1::from_slice(py, &self.contact.multipliers)
+    }
+    /// $$
+    /// g_a = g(\mathbf{x}_a)
+    /// $$
+    fn gaps<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray1<Scalar>>, PyErrGlue> {
+        Ok(PyArray1::from_owned_array(
+            py,
+            self.contact.gaps(&nodal_coordinates)?,
+        ))
+    }
+    /// $$
+    /// \mathbf{f}_a = -\langle\lambda_a - \epsilon g_a\rangle\mathbf{n}_a - \mathbf{t}_a
+    /// $$
+    fn nodal_forces<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+        Ok(PyArray2::from_owned_array(
+            py,
+            self.forces(&nodal_coordinates)?,
+        ))
+    }
+    /// $$
+    /// \mathbf{K}_{ab} = \frac{\partial\mathbf{f}_a}{\partial\mathbf{x}_b}
+    /// $$
+    fn nodal_stiffnesses<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
+        Ok(PyArray4::from_owned_array(
+            py,
+            self.stiffnesses(&nodal_coordinates)?,
+        ))
+    }
+}
+
+/// Contact between a set of nodes and a rigid cylinder.
+#[pyclass]
+pub struct RigidCylinder {
+    contact: Contact,
+}
+
+contact!(RigidCylinder);
+
+#[pymethods]
+impl RigidCylinder {
+    #[new]
+    #[pyo3(signature = (point, axis, radius, nodes, penalty, inside = false, **kwargs))]
+    fn new(
+        point: [Scalar; 3],
+        axis: [Scalar; 3],
+        radius: Scalar,
+        nodes: Vec<usize>,
+        penalty: Scalar,
+        inside: bool,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> Result<Self, PyErr> {
+        if radius <= 0.0 {
+            return Err(PyErrGlue::new("The radius must be positive.").into());
+        }
+        Ok(Self {
+            contact: Contact::new(
+                Surface::Cylinder {
+                    point,
+                    axis: unit(axis, "axis")?,
+                    radius,
+                    inside,
+                },
+                nodes,
+                penalty,
+                kwargs,
+            )?,
+        })
+    }
+    /// The Lagrange multipliers of the contact nodes.
+    #[getter]
+    fn multipliers<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<Scalar>> {
+        PyArray1::from_slice(py, &self.contact.multipliers)
+    }
+    /// $$
+    /// g_a = g(\mathbf{x}_a)
+    /// $$
+    fn gaps<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray1<Scalar>>, PyErrGlue> {
+        Ok(PyArray1::from_owned_array(
+            py,
+            self.contact.gaps(&nodal_coordinates)?,
+        ))
+    }
+    /// $$
+    /// \mathbf{f}_a = -\langle\lambda_a - \epsilon g_a\rangle\mathbf{n}_a - \mathbf{t}_a
+    /// $$
+    fn nodal_forces<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+        Ok(PyArray2::from_owned_array(
+            py,
+            self.forces(&nodal_coordinates)?,
+        ))
+    }
+    /// $$
+    /// \mathbf{K}_{ab} = \frac{\partial\mathbf{f}_a}{\partial\mathbf{x}_b}
+    /// $$
+    fn nodal_stiffnesses<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
+        Ok(PyArray4::from_owned_array(
+            py,
+            self.stiffnesses(&nodal_coordinates)?,
+        ))
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{
+    PyErrGlue,
+    fem::{System, block::Tensor, solve::NewtonRaphson},
+    math::linalg::solve_dense,
+};
+use conspire::mechanics::Scalar;
+use ndarray::{Array2, Array4};
+use numpy::{PyArray2, PyArray4};
+use pyo3::{prelude::*, types::PyDict};
+use std::collections::HashMap;
+
+type Homogenized<'py> = (Bound<'py, PyArray2<Scalar>>, Bound<'py, PyArray4<Scalar>>);
+
+/// Representative volume element with periodic boundary conditions.
+#[pyclass]
+pub struct RepresentativeVolumeElement {
+    system: System,
+    reference_nodal_coordinates: Vec<[Scalar; 3]>,
+    images: Vec<usize>,
+    dofs: Vec<Option<usize>>,
+    volume: Scalar,
+}
+
+impl RepresentativeVolumeElement {
+    fn dof(&self, node: usize, i: usize) -> Option<usize> {
+        self.dofs[self.images[node]].map(|dof| 3 * dof + i)
+    }
+    fn affine(&self, deformation_gradient: &Tensor) -> Vec<[Scalar; 3]> {
+        self.reference_nodal_coordinates
+            .iter()
+            .map(|reference| {
+                [0, 1, 2].map(|i| {
+                    (0..3)
+                        .map(|j| deformation_gradient[i][j] * reference[j])
+                        .sum()
+                })
+            })
+            .collect()
+    }
+    fn reduced_matrix(&self, stiffnesses: &Array4<Scalar>, size: usize) -> Vec<Vec<Scalar>> {
+        let nodes = self.images.len();
+        let mut matrix = vec![vec![0.0; size]; size];
+        (0..nodes).for_each(|a| {
+            (0..3).for_each(|i| {
+                if let Some(row) = self.dof(a, i) {
+                    (0..nodes).for_each(|b| {
+                        (0..3).for_each(|j| {
+                            if let Some(column) = self.dof(b, j) {
+                                matrix[row][column] += stiffnesses[[a, b, i, j]]
+                            }
+                        })
+                    })
+                }
+            })
+        });
+        matrix
+    }
+    fn size(&self) -> usize {
+        3 * self.dofs.iter().flatten().count()
+    }
+    fn equilibrium(
+        &self,
+        py: Python,
+        deformation_gradient: &Tensor,
+        solver: &NewtonRaphson,
+    ) -> Result<Vec<[Scalar; 3]>, PyErrGlue> {
+        let size = self.size();
+        let mut nodal_coordinates = self.affine(deformation_gradient);
+        for _ in 0..solver.max_steps {
+            let forces = self.system.forces(py, nodal_coordinates.clone())?;
+            let mut rhs = vec![0.0; size];
+            forces.indexed_iter().for_each(|((a, i), force)| {
+                if let Some(dof) = self.dof(a, i) {
+                    rhs[dof] -= force
+                }
+            });
+            if rhs.iter().map(|f| f * f).sum::<Scalar>().sqrt() < solver.abs_tol {
+                self.system.set_coordinates(py, &nodal_coordinates)?;
+                return Ok(nodal_coordinates);
+            }
+            let stiffnesses = self.system.stiffnesses(py, nodal_coordinates.clone())?;
+            let increments = solve_dense(self.reduced_matrix(&stiffnesses, size), rhs)?;
+            nodal_coordinates
+                .iter_mut()
+                .enumerate()
+                .for_each(|(a, coordinates)| {
+                    (0..3).for_each(|i| {
+                        if let Some(dof) = self.dof(a, i) {
+                            coordinates[i] += increments[dof]
+                        }
+                    })
+                });
+        }
+        Err(PyErrGlue::new("The maximum number of steps was reached."))
+    }
+}
+
+#[pymethods]
+impl RepresentativeVolumeElement {
+    #[new]
+    fn new(py: Python, system: System) -> Result<Self, PyErrGlue> {
+        let reference_nodal_coordinates = system.reference_nodal_coordinates(py)?;
+        let (minimum, maximum) = reference_nodal_coordinates.iter().fold(
+            ([Scalar::INFINITY; 3], [Scalar::NEG_INFINITY; 3]),
+            |(minimum, maximum), coordinates| {
+                (
+                    [0, 1, 2].map(|i| minimum[i].min(coordinates[i])),
+                    [0, 1, 2].map(|i| maximum[i].max(coordinates[i])),
+                )
+            },
+        );
+        let lengths = [0, 1, 2].map(|i| maximum[i] - minimum[i]);
+        let tolerance = 1e-6 * lengths.iter().fold(0.0, |a: Scalar, &b| a.max(b));
+        let key = |coordinates: [Scalar; 3]| {
+            [0, 1, 2].map(|i| ((coordinates[i] - minimum[i]) / tolerance).round() as i64)
+        };
+        let wrap = |coordinates: [Scalar; 3]| {
+            [0, 1, 2].map(|i| {
+                if (coordinates[i] - maximum[i]).abs() < tolerance {
+                    minimum[i]
+                } else {
+                    coordinates[i]
+                }
+            })
+        };
+        let mut independent = HashMap::new();
+        reference_nodal_coordinates
+            .iter()
+            .enumerate()
+            .filter(|&(_, &coordinates)| wrap(coordinates) == coordinates)
+            .for_each(|(node, &coordinates)| {
+                independent.entry(key(coordinates)).or_insert(node);
+            });
+        let images = reference_nodal_coordinates
+            .iter()
+            .enumerate()
+            .map(|(node, &coordinates)| {
+                independent
+                    .get(&key(wrap(coordinates)))
+                    .copied()
+                    .ok_or_else(|| PyErrGlue::new(&format!("Node {node} has no periodic image.")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut count = 0;
+        let dofs = (0..images.len())
+            .map(|node| {
+                if images[node] == node && node != images[0] {
+                    count += 1;
+                    Some(count - 1)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        Ok(Self {
+            system,
+            reference_nodal_coordinates,
+            images,
+            dofs,
+            volume: lengths.iter().product(),
+        })
+    }
+    /// The pairs of dependent and independent nodes.
+    #[getter]
+    fn node_pairs<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<usize>> {
+        let pairs: Vec<[usize; 2]> = self
+            .images
+            .iter()
+            .enumerate()
+            .filter(|&(node, &image)| node != image)
+            .map(|(node, &image)| [node, image])
+            .collect();
+        PyArray2::from_owned_array(py, Array2::from(pairs))
+    }
+    /// @private
+    #[getter]
+    fn volume(&self) -> Scalar {
+        self.volume
+    }
+    /// $$
+    /// \mathbf{x}_a = \mathbf{F}\cdot\mathbf{X}_a + \mathbf{w}_a,\quad\mathbf{w}_{a^+} = \mathbf{w}_{a^-}
+    /// $$
+    #[pyo3(signature = (deformation_gradient, **kwargs))]
+    fn solve<'py>(
+        &self,
+        py: Python<'py>,
+        deformation_gradient: Tensor,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErr> {
+        Ok(PyArray2::from_owned_array(
+            py,
+            Array2::from(self.equilibrium(
+                py,
+                &deformation_gradient,
+                &NewtonRaphson::from_kwargs(kwargs)?,
+            )?),
+        ))
+    }
+    /// $$
+    /// \bar{\mathbf{P}} = \frac{1}{V}\sum_a\mathbf{f}_a\otimes\mathbf{X}_a,\quad \bar{\mathcal{A}} = \frac{\partial\bar{\mathbf{P}}}{\partial\mathbf{F}}
+    /// $$
+    #[pyo3(signature = (deformation_gradient, **kwargs))]
+    fn homogenize<'py>(
+        &self,
+        py: Python<'py>,
+        deformation_gradient: Tensor,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> Result<Homogenized<'py>, PyErr> {
+        let nodal_coordinates = self.equilibrium(
+            py,
+            &deformation_gradient,
+            &NewtonRaphson::from_kwargs(kwargs)?,
+        )?;
+        let nodes = nodal_coordinates.len();
+        let reference = &self.reference_nodal_coordinates;
+        let forces = self.system.forces(py, nodal_coordinates.clone())?;
+        let stiffnesses = self.system.stiffnesses(py, nodal_coordinates)?;
+        let stress = Array2::from_shape_fn((3, 3), |(i, j)| {
+            (0..nodes)
+                .map(|a| forces[[a, i]] * reference[a][j])
+                .sum::<Scalar>()
+                / self.volume
+        });
+        let size = self.size();
+        let matrix = self.reduced_matrix(&stiffnesses, size);
+        let mut tangent = Array4::zeros((3, 3, 3, 3));
+        for k in 0..3 {
+            for l in 0..3 {
+                let mut rhs = vec![0.0; size];
+                (0..nodes).for_each(|a| {
+                    (0..3).for_each(|i| {
+                        if let Some(dof) = self.dof(a, i) {
+                            rhs[dof] -= (0..nodes)
+                                .map(|b| stiffnesses[[a, b, i, k]] * reference[b][l])
+                                .sum::<Scalar>()
+                        }
+                    })
+                });
+                let fluctuations = solve_dense(matrix.clone(), rhs)?;
+                let velocities: Vec<[Scalar; 3]> = (0..nodes)
+                    .map(|b| {
+                        [0, 1, 2].map(|j| {
+                            self.dof(b, j).map_or(0.0, |dof| fluctuations[dof])
+                                + if j == k { reference[b][l] } else { 0.0 }
+                        })
+                    })
+                    .collect();
+                for i in 0..3 {
+                    for j in 0..3 {
+                        tangent[[i, j, k, l]] = (0..nodes)
+                            .map(|a| {
+                                reference[a][j]
+                                    * (0..nodes)
+                                        .map(|b| {
+                                            (0..3)
+                                                .map(|m| {
+                                                    stiffnesses[[a, b, i, m]] * velocities[b][m]
+                                                })
+                                                .sum::<Scalar>()
+                                        })
+                                        .sum::<Scalar>()
+                            })
+                            .sum::<Scalar>()
+                            / self.volume
+                    }
+                }
+            }
+        }
+        Ok((
+            PyArray2::from_owned_array(py, stress),
+            PyArray4::from_owned_array(py, tangent),
+        ))
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{
+    PyErrGlue,
+    fem::contact::{RigidCylinder, RigidPlane},
+};
+use conspire::mechanics::Scalar;
+use ndarray::{Array2, Array4};
+use numpy::{PyArray2, PyArray4};
+use pyo3::prelude::*;
+
+pub fn register_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<BodyForce>()?;
+    m.add_class::<Pressure>()?;
+    m.add_class::<RigidCylinder>()?;
+    m.add_class::<RigidPlane>()?;
+    m.add_class::<Traction>()
+}
+
+type Face = [usize; 3];
+
+pub fn cross(u: [Scalar; 3], v: [Scalar; 3]) -> [Scalar; 3] {
+    [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ]
+}
+
+fn difference(u: [Scalar; 3], v: [Scalar; 3]) -> [Scalar; 3] {
+    [u[0] - v[0], u[1] - v[1], u[2] - v[2]]
+}
+
+fn check_nodes<const N: usize>(
+    entities: &[[usize; N]],
+    entity: &str,
+    nodes: usize,
+) -> Result<(), PyErrGlue> {
+    entities
+        .iter()
+        .enumerate()
+        .try_for_each(|(index, entity_nodes)| {
+            match entity_nodes.iter().find(|&&node| node >= nodes) {
+                Some(node) => Err(PyErrGlue::new(&format!(
+                    "Node {node} in {entity} {index} is out of range for {nodes} nodes."
+                ))),
+                None => Ok(()),
+            }
+        })
+}
+
+fn check_dead(nodal_forces: &Array2<Scalar>, nodes: usize) -> Result<(), PyErrGlue> {
+    if nodal_forces.nrows() == nodes {
+        Ok(())
+    } else {
+        Err(PyErrGlue::new(&format!(
+            "Expected a load on {nodes} nodes, got {}.",
+            nodal_forces.nrows()
+        )))
+    }
+}
+
+fn area_vector(nodal_coordinates: &[[Scalar; 3]], face: &Face) -> [Scalar; 3] {
+    let [a, b, c] = face.map(|node| nodal_coordinates[node]);
+    cross(difference(b, a), difference(c, a)).map(|area| 0.5 * area)
+}
+
+fn volume(nodal_coordinates: &[[Scalar; 3]], element: &[usize; 4]) -> Scalar {
+    let [a, b, c, d] = element.map(|node| nodal_coordinates[node]);
+    let [u, v, w] = [difference(b, a), difference(c, a), difference(d, a)];
+    let n = cross(v, w);
+    (u[0] * n[0] + u[1] * n[1] + u[2] * n[2]) / 6.0
+}
+
+/// Dead body force per unit reference volume.
+#[pyclass]
+pub struct BodyForce {
+    nodal_forces: Array2<Scalar>,
+}
+
+impl BodyForce {
+    pub fn forces(&self) -> Array2<Scalar> {
+        self.nodal_forces.clone()
+    }
+}
+
+#[pymethods]
+impl BodyForce {
+    #[new]
+    fn new(
+        body_force: [Scalar; 3],
+        connectivity: Vec<[usize; 4]>,
+        reference_nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Self, PyErrGlue> {
+        check_nodes(&connectivity, "element", reference_nodal_coordinates.len())?;
+        let mut nodal_forces = Array2::zeros((reference_nodal_coordinates.len(), 3));
+        connectivity.iter().for_each(|element| {
+            let weight = volume(&reference_nodal_coordinates, element) / 4.0;
+            element.iter().for_each(|&node| {
+                (0..3).for_each(|i| nodal_forces[[node, i]] -= weight * body_force[i])
+            })
+        });
+        Ok(Self { nodal_forces })
+    }
+    /// $$
+    /// \mathbf{f}_a = -\int_{\Omega_0} N_a\mathbf{b}\,dV
+    /// $$
+    fn nodal_forces<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<Scalar>> {
+        PyArray2::from_owned_array(py, self.forces())
+    }
+}
+
+/// Follower pressure on a set of faces.
+#[pyclass]
+pub struct Pressure {
+    pressure: Scalar,
+    faces: Vec<Face>,
+}
+
+impl Pressure {
+    fn forces(&self, nodal_coordinates: &[[Scalar; 3]]) -> Result<Array2<Scalar>, PyErrGlue> {
+        check_nodes(&self.faces, "face", nodal_coordinates.len())?;
+        let mut nodal_forces = Array2::zeros((nodal_coordinates.len(), 3));
+        self.faces.iter().for_each(|face| {
+            let area = area_vector(nodal_coordinates, face);
+            face.iter().for_each(|&node| {
+                (0..3).for_each(|i| nodal_forces[[node, i]] += self.pressure * area[i] / 3.0)
+            })
+        });
+        Ok(nodal_forces)
+    }
+    fn stiffnesses(&self, nodal_coordinates: &[[Scalar; 3]]) -> Result<Array4<Scalar>, PyErrGlue> {
+        let nodes = nodal_coordinates.len();
+        check_nodes(&self.faces, "face", nodes)?;
+        let mut nodal_stiffnesses = Array4::zeros((nodes, nodes, 3, 3));
+        self.faces.iter().for_each(|face| {
+            (0..3).for_each(|b| {
+                let edge = difference(
+                    nodal_coordinates[face[(b + 2) % 3]],
+                    nodal_coordinates[face[(b + 1) % 3]],
+                );
+                let skew = [
+                    [0.0, -edge[2], edge[1]],
+                    [edge[2], 0.0, -edge[0]],
+                    [-edge[1], edge[0], 0.0],
+                ];
+                face.iter().for_each(|&node_a| {
+                    (0..3).for_each(|i| {
+                        (0..3).for_each(|j| {
+                            nodal_stiffnesses[[node_a, face[b], i, j]] +=
+                                self.pressure * skew[i][j] / 6.0
+                        })
+                    })
+                })
+            })
+        });
+        Ok(nodal_stiffnesses)
+    }
+}
+
+#[pymethods]
+impl Pressure {
+    #[new]
+    fn new(pressure: Scalar, faces: Vec<Face>) -> Self {
+        Self { pressure, faces }
+    }
+    /// @private
+    #[getter]
+    fn pressure(&self) -> Scalar {
+        self.pressure
+    }
+    /// $$
+    /// \mathbf{f}_a = \int_{\partial\Omega} p\,N_a\,\mathbf{n}\,da
+    /// $$
+    fn nodal_forces<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+        Ok(PyArray2::from_owned_array(
+            py,
+            self.forces(&nodal_coordinates)?,
+        ))
+    }
+    /// $$
+    /// \mathbf{K}_{ab} = \frac{\partial\mathbf{f}_a}{\partial\mathbf{x}_b}
+    /// $$
+    fn nodal_stiffnesses<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
+        Ok(PyArray4::from_owned_array(
+            py,
+            self.stiffnesses(&nodal_coordinates)?,
+        ))
+    }
+}
+
+/// Dead traction per unit reference area on a set of faces.
+#[pyclass]
+pub struct Traction {
+    nodal_forces: Array2<Scalar>,
+}
+
+impl Traction {
+    pub fn forces(&self) -> Array2<Scalar> {
+        self.nodal_forces.clone()
+    }
+}
+
+#[pymethods]
+impl Traction {
+    #[new]
+    fn new(
+        traction: [Scalar; 3],
+        faces: Vec<Face>,
+        reference_nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Self, PyErrGlue> {
+        check_nodes(&faces, "face", reference_nodal_coordinates.len())?;
+        let mut nodal_forces = Array2::zeros((reference_nodal_coordinates.len(), 3));
+        faces.iter().for_each(|face| {
+            let area = area_vector(&reference_nodal_coordinates, face);
+            let weight = (area[0] * area[0] + area[1] * area[1] + area[2] * area[2]).sqrt() / 3.0;
+            face.iter().for_each(|&node| {
+                (0..3).for_each(|i| nodal_forces[[node, i]] -= weight * traction[i])
+            })
+        });
+        Ok(Self { nodal_forces })
+    }
+    /// $$
+    /// \mathbf{f}_a = -\int_{\partial\Omega_0} N_a\mathbf{t}\,dA
+    /// $$
+    fn nodal_forces<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<Scalar>> {
+        PyArray2::from_owned_array(py, self.forces())
+    }
+}
+
+#[derive(FromPyObject)]
+pub enum Load {
+    BodyForce(Py<BodyForce>),
+    Pressure(Py<Pressure>),
+    RigidCylinder(Py<RigidCylinder>),
+    RigidPlane(Py<RigidPlane>),
+    Traction(Py<Traction>),
+}
+
+impl Load {
+    pub fn forces(
+        &self,
+        py: Python,
+        nodal_coordinates: &[[Scalar; 3]],
+    ) -> Result<Array2<Scalar>, PyErrGlue> {
+        let nodes = nodal_coordinates.len();
+        match self {
+            Self::BodyForce(load) => {
+                let forces = load.borrow(py).forces();
+                check_dead(&forces, nodes)?;
+                Ok(forces)
+            }
+            Self::Pressure(load) => load.borrow(py).forces(nodal_coordinates),
+            Self::RigidCylinder(load) => load.borrow(py).forces(nodal_coordinates),
+            Self::RigidPlane(load) => load.borrow(py).forces(nodal_coordinates),
+            Self::Traction(load) => {
+                let forces = load.borrow(py).forces();
+                check_dead(&forces, nodes)?;
+                Ok(forces)
+            }
+        }
+    }
+    pub fn stiffnesses(
+        &self,
+        py: Python,
+        nodal_coordinates: &[[Scalar; 3]],
+    ) -> Result<Option<Array4<Scalar>>, PyErrGlue> {
+        Ok(match self {
+            Self::Pressure(load) => Some(load.borrow(py).stiffnesses(nodal_coordinates)?),
+            Self::RigidCylinder(load) => Some(load.borrow(py).stiffnesses(nodal_coordinates)?),
+            Self::RigidPlane(load) => Some(load.borrow(py).stiffnesses(nodal_coordinates)?),
+            _ => None,
+        })
+    }
+    pub fn begin(&self, py: Python, nodal_coordinates: &[[Scalar; 3]]) -> Result<(), PyErrGlue> {
+        match self {
+            Self::RigidCylinder(load) => load.borrow_mut(py).begin(nodal_coordinates),
+            Self::RigidPlane(load) => load.borrow_mut(py).begin(nodal_coordinates),
+            _ => Ok(()),
+        }
+    }
+    pub fn augment(
+        &self,
+        py: Python,
+        nodal_coordinates: &[[Scalar; 3]],
+    ) -> Result<bool, PyErrGlue> {
+        match self {
+            Self::RigidCylinder(load) => load.borrow_mut(py).augment(nodal_coordinates),
+            Self::RigidPlane(load) => load.borrow_mut(py).augment(nodal_coordinates),
+            _ => Ok(true),
+        }
+    }
+    pub fn commit(&self, py: Python, nodal_coordinates: &[[Scalar; 3]]) -> Result<(), PyErrGlue> {
+        match self {
+            Self::RigidCylinder(load) => load.borrow_mut(py).commit(nodal_coordinates),
+            Self::RigidPlane(load) => load.borrow_mut(py).commit(nodal_coordinates),
+            _ => Ok(()),
+        }
+    }
+}
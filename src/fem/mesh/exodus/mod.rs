@@ -0,0 +1,390 @@
+use super::Mesh;
+use crate::PyErrGlue;
+use conspire::mechanics::Scalar;
+
+const NC_DIMENSION: u32 = 10;
+const NC_VARIABLE: u32 = 11;
+const NC_ATTRIBUTE: u32 = 12;
+
+const TETRAHEDRON_SIDES: [[usize; 3]; 4] = [[0, 1, 3], [1, 2, 3], [0, 3, 2], [0, 2, 1]];
+
+enum Values {
+    Text(Vec<u8>),
+    Integers(Vec<i64>),
+    Floats(Vec<Scalar>),
+}
+
+struct Variable {
+    name: String,
+    dimensions: Vec<usize>,
+    attributes: Vec<(String, Values)>,
+    nc_type: u32,
+    begin: usize,
+}
+
+struct NetCdf<'a> {
+    bytes: &'a [u8],
+    dimensions: Vec<(String, usize)>,
+    variables: Vec<Variable>,
+}
+
+struct Header<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    version: u8,
+}
+
+fn truncated() -> PyErrGlue {
+    PyErrGlue::new("The Exodus II file is truncated.")
+}
+
+fn text_from(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .trim_end_matches('\0')
+        .trim()
+        .to_string()
+}
+
+fn size_of(nc_type: u32) -> Result<usize, PyErrGlue> {
+    match nc_type {
+        1 | 2 | 7 => Ok(1),
+        3 | 8 => Ok(2),
+        4 | 5 | 9 => Ok(4),
+        6 | 10 | 11 => Ok(8),
+        _ => Err(PyErrGlue::new(&format!(
+            "Unknown netCDF data type {nc_type}."
+        ))),
+    }
+}
+
+fn decode(bytes: &[u8], nc_type: u32, count: usize) -> Result<Values, PyErrGlue> {
+    let size = size_of(nc_type)?;
+    let bytes = count
+        .checked_mul(size)
+        .and_then(|length| bytes.get(..length))
+        .ok_or_else(truncated)?;
+    let chunks = bytes.chunks_exact(size);
+    Ok(match nc_type {
+        2 => Values::Text(bytes.to_vec()),
+        5 => Values::Floats(
+            chunks
+                .map(|chunk| f32::from_be_bytes(chunk.try_into().unwrap()) as Scalar)
+                .collect(),
+        ),
+        6 => Values::Floats(
+            chunks
+                .map(|chunk| f64::from_be_bytes(chunk.try_into().unwrap()))
+                .collect(),
+        ),
+        _ => Values::Integers(
+            chunks
+                .map(|chunk| match nc_type {
+                    1 => chunk[0] as i8 as i64,
+                    7 => chunk[0] as i64,
+                    3 => i16::from_be_bytes(chunk.try_into().unwrap()) as i64,
+                    8 => u16::from_be_bytes(chunk.try_into().unwrap()) as i64,
+                    4 => i32::from_be_bytes(chunk.try_into().unwrap()) as i64,
+                    9 => u32::from_be_bytes(chunk.try_into().unwrap()) as i64,
+                    _ => i64::from_be_bytes(chunk.try_into().unwrap()),
+                })
+                .collect(),
+        ),
+    })
+}
+
+impl Header<'_> {
+    fn take(&mut self, count: usize) -> Result<&[u8], PyErrGlue> {
+        let bytes = self
+            .position
+            .checked_add(count)
+            .and_then(|end| self.bytes.get(self.position..end))
+            .ok_or_else(truncated)?;
+        self.position += count;
+        Ok(bytes)
+    }
+    fn u32(&mut self) -> Result<u32, PyErrGlue> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+    fn u64(&mut self) -> Result<u64, PyErrGlue> {
+        Ok(u64::from_be_bytes(self.take(8)?.try_into().unwrap()))
+    }
+    fn non_negative(&mut self) -> Result<usize, PyErrGlue> {
+        if self.version == 5 {
+            Ok(self.u64()? as usize)
+        } else {
+            Ok(self.u32()? as usize)
+        }
+    }
+    fn offset(&mut self) -> Result<usize, PyErrGlue> {
+        if self.version == 1 {
+            Ok(self.u32()? as usize)
+        } else {
+            Ok(self.u64()? as usize)
+        }
+    }
+    fn padded(&mut self, count: usize) -> Result<&[u8], PyErrGlue> {
+        let bytes = self.take(count.div_ceil(4).checked_mul(4).ok_or_else(truncated)?)?;
+        Ok(&bytes[..count])
+    }
+    fn name(&mut self) -> Result<String, PyErrGlue> {
+        let count = self.non_negative()?;
+        Ok(String::from_utf8_lossy(self.padded(count)?).to_string())
+    }
+    fn list(&mut self, tag: u32) -> Result<usize, PyErrGlue> {
+        let found = self.u32()?;
+        let count = self.non_negative()?;
+        if found == tag || (found == 0 && count == 0) {
+            Ok(count)
+        } else {
+            Err(PyErrGlue::new("The Exodus II file has a malformed header."))
+        }
+    }
+    fn attributes(&mut self) -> Result<Vec<(String, Values)>, PyErrGlue> {
+        (0..self.list(NC_ATTRIBUTE)?)
+            .map(|_| {
+                let name = self.name()?;
+                let nc_type = self.u32()?;
+                let count = self.non_negative()?;
+                let length = count.checked_mul(size_of(nc_type)?).ok_or_else(truncated)?;
+                let bytes = self.padded(length)?;
+                Ok((name, decode(bytes, nc_type, count)?))
+            })
+            .collect()
+    }
+}
+
+impl<'a> NetCdf<'a> {
+    fn new(bytes: &'a [u8]) -> Result<Self, PyErrGlue> {
+        if bytes.starts_with(b"\x89HDF") {
+            return Err(PyErrGlue::new(
+                "Exodus II files in the netCDF-4 (HDF5) format are not supported; convert to the classic format first.",
+            ));
+        }
+        let version = match bytes.get(..4) {
+            Some([b'C', b'D', b'F', version @ (1 | 2 | 5)]) => *version,
+            _ => return Err(PyErrGlue::new("The file is not an Exodus II file.")),
+        };
+        let mut header = Header {
+            bytes,
+            position: 4,
+            version,
+        };
+        header.non_negative()?;
+        let dimensions = (0..header.list(NC_DIMENSION)?)
+            .map(|_| Ok((header.name()?, header.non_negative()?)))
+            .collect::<Result<_, PyErrGlue>>()?;
+        header.attributes()?;
+        let variables = (0..header.list(NC_VARIABLE)?)
+            .map(|_| {
+                let name = header.name()?;
+                let dimensions = (0..header.non_negative()?)
+                    .map(|_| header.non_negative())
+                    .collect::<Result<_, _>>()?;
+                let attributes = header.attributes()?;
+                let nc_type = header.u32()?;
+                header.non_negative()?;
+                let begin = header.offset()?;
+                Ok(Variable {
+                    name,
+                    dimensions,
+                    attributes,
+                    nc_type,
+                    begin,
+                })
+            })
+            .collect::<Result<_, PyErrGlue>>()?;
+        Ok(Self {
+            bytes,
+            dimensions,
+            variables,
+        })
+    }
+    fn dimension(&self, name: &str) -> Option<usize> {
+        self.dimensions
+            .iter()
+            .find(|(dimension, _)| dimension == name)
+            .map(|(_, length)| *length)
+    }
+    fn variable(&self, name: &str) -> Option<&Variable> {
+        self.variables.iter().find(|variable| variable.name == name)
+    }
+    fn values(&self, name: &str) -> Result<Option<Values>, PyErrGlue> {
+        match self.variable(name) {
+            Some(variable) => {
+                let lengths = variable
+                    .dimensions
+                    .iter()
+                    .map(|&dimension| self.dimensions.get(dimension).map(|(_, length)| *length))
+                    .collect::<Option<Vec<usize>>>()
+                    .ok_or(PyErrGlue::new("The Exodus II file has a malformed header."))?;
+                if lengths.contains(&0) {
+                    return Ok(None);
+                }
+                let bytes = self.bytes.get(variable.begin..).ok_or_else(truncated)?;
+                Ok(Some(decode(
+                    bytes,
+                    variable.nc_type,
+                    lengths
+                        .iter()
+                        .try_fold(1_usize, |count, &length| count.checked_mul(length))
+                        .ok_or_else(truncated)?,
+                )?))
+            }
+            None => Ok(None),
+        }
+    }
+    fn floats(&self, name: &str) -> Result<Option<Vec<Scalar>>, PyErrGlue> {
+        match self.values(name)? {
+            Some(Values::Floats(values)) => Ok(Some(values)),
+            Some(_) => Err(PyErrGlue::new(&format!(
+                "Variable {name} is not floating-point."
+            ))),
+            None => Ok(None),
+        }
+    }
+    fn indices(&self, name: &str) -> Result<Vec<usize>, PyErrGlue> {
+        match self.values(name)? {
+            Some(Values::Integers(values)) => values
+                .into_iter()
+                .map(|value| {
+                    usize::try_from(value - 1).map_err(|_| {
+                        PyErrGlue::new(&format!("Variable {name} has invalid indices."))
+                    })
+                })
+                .collect(),
+            Some(_) => Err(PyErrGlue::new(&format!("Variable {name} is not integer."))),
+            None => Ok(vec![]),
+        }
+    }
+    fn names(&self, name: &str, ids: &str, count: usize) -> Result<Vec<String>, PyErrGlue> {
+        let ids = match self.values(ids)? {
+            Some(Values::Integers(ids)) => ids,
+            _ => (1..=count as i64).collect(),
+        };
+        let names = match self.values(name)? {
+            Some(Values::Text(text)) => text
+                .chunks((text.len() / count.max(1)).max(1))
+                .map(text_from)
+                .collect(),
+            _ => vec![],
+        };
+        Ok((0..count)
+            .map(|index| match names.get(index) {
+                Some(name) if !name.is_empty() => name.clone(),
+                _ => ids
+                    .get(index)
+                    .map(|id| id.to_string())
+                    .unwrap_or((index + 1).to_string()),
+            })
+            .collect())
+    }
+}
+
+pub fn read(bytes: &[u8]) -> Result<Mesh, PyErrGlue> {
+    let file = NetCdf::new(bytes)?;
+    if file.dimension("num_dim") != Some(3) {
+        return Err(PyErrGlue::new(
+            "Only three-dimensional Exodus II meshes are supported.",
+        ));
+    }
+    let nodes = file.dimension("num_nodes").unwrap_or(0);
+    let coordinates = match (
+        file.floats("coordx")?,
+        file.floats("coordy")?,
+        file.floats("coordz")?,
+    ) {
+        (Some(x), Some(y), Some(z)) => [x, y, z],
+        _ => match file.floats("coord")? {
+            Some(coordinates) => {
+                let mut components = coordinates.chunks(nodes.max(1)).map(|chunk| chunk.to_vec());
+                [(); 3].map(|_| components.next().unwrap_or_default())
+            }
+            None => return Err(PyErrGlue::new("The Exodus II file has no coordinates.")),
+        },
+    };
+    if coordinates.iter().any(|component| component.len() != nodes) {
+        return Err(PyErrGlue::new(
+            "The Exodus II file has malformed coordinates.",
+        ));
+    }
+    let reference_nodal_coordinates = (0..nodes)
+        .map(|node| {
+            [
+                coordinates[0][node],
+                coordinates[1][node],
+                coordinates[2][node],
+            ]
+        })
+        .collect();
+    let number_of_blocks = file.dimension("num_el_blk").unwrap_or(0);
+    let mut elements = vec![];
+    let blocks = file
+        .names("eb_names", "eb_prop1", number_of_blocks)?
+        .into_iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let connect = format!("connect{}", index + 1);
+            let element_type = match file.variable(&connect) {
+                Some(variable) => variable
+                    .attributes
+                    .iter()
+                    .find_map(|(attribute, value)| match (attribute.as_str(), value) {
+                        ("elem_type", Values::Text(text)) => Some(text_from(text).to_uppercase()),
+                        _ => None,
+                    })
+                    .unwrap_or_default(),
+                None => return Ok((name, vec![])),
+            };
+            let nodes_per_element = file
+                .dimension(&format!("num_nod_per_el{}", index + 1))
+                .unwrap_or(0);
+            if !element_type.starts_with("TET") || nodes_per_element != 4 {
+                return Err(PyErrGlue::new(&format!(
+                    "Unsupported element type {element_type} with {nodes_per_element} nodes in element block {name}."
+                )));
+            }
+            let connectivity: Vec<[usize; 4]> = file
+                .indices(&connect)?
+                .chunks_exact(4)
+                .map(|element| [element[0], element[1], element[2], element[3]])
+                .collect();
+            elements.extend_from_slice(&connectivity);
+            Ok((name, connectivity))
+        })
+        .collect::<Result<_, PyErrGlue>>()?;
+    let number_of_node_sets = file.dimension("num_node_sets").unwrap_or(0);
+    let node_sets = file
+        .names("ns_names", "ns_prop1", number_of_node_sets)?
+        .into_iter()
+        .enumerate()
+        .map(|(index, name)| Ok((name, file.indices(&format!("node_ns{}", index + 1))?)))
+        .collect::<Result<_, PyErrGlue>>()?;
+    let number_of_side_sets = file.dimension("num_side_sets").unwrap_or(0);
+    let side_sets = file
+        .names("ss_names", "ss_prop1", number_of_side_sets)?
+        .into_iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let faces = file
+                .indices(&format!("elem_ss{}", index + 1))?
+                .into_iter()
+                .zip(file.indices(&format!("side_ss{}", index + 1))?)
+                .map(
+                    |(element, side)| match (elements.get(element), TETRAHEDRON_SIDES.get(side)) {
+                        (Some(element), Some(side)) => Ok(side.map(|node| element[node])),
+                        _ => Err(PyErrGlue::new(&format!(
+                            "Side set {name} references a side that does not exist."
+                        ))),
+                    },
+                )
+                .collect::<Result<_, PyErrGlue>>()?;
+            Ok((name, faces))
+        })
+        .collect::<Result<_, PyErrGlue>>()?;
+    Ok(Mesh {
+        reference_nodal_coordinates,
+        blocks,
+        node_sets,
+        side_sets,
+    })
+}
@@ -0,0 +1,326 @@
+use super::Mesh;
+use crate::PyErrGlue;
+use conspire::{fem::block::Connectivity, mechanics::Scalar};
+use std::collections::{HashMap, HashSet};
+
+type Face = [usize; 3];
+
+const PERMUTATIONS: [[usize; 3]; 6] = [
+    [0, 1, 2],
+    [0, 2, 1],
+    [1, 0, 2],
+    [1, 2, 0],
+    [2, 0, 1],
+    [2, 1, 0],
+];
+
+fn volume(nodal_coordinates: &[[Scalar; 3]], element: &[usize; 4]) -> Scalar {
+    let [a, b, c, d] = element.map(|node| nodal_coordinates[node]);
+    let [u, v, w] = [b, c, d].map(|x| [0, 1, 2].map(|i| x[i] - a[i]));
+    (u[0] * (v[1] * w[2] - v[2] * w[1]) - u[1] * (v[0] * w[2] - v[2] * w[0])
+        + u[2] * (v[0] * w[1] - v[1] * w[0]))
+        / 6.0
+}
+
+fn oriented(nodal_coordinates: &[[Scalar; 3]], element: [usize; 4]) -> [usize; 4] {
+    if volume(nodal_coordinates, &element) < 0.0 {
+        [element[0], element[1], element[3], element[2]]
+    } else {
+        element
+    }
+}
+
+/// Splits each cell of a structured grid into six tetrahedra along its main diagonal, discarding tetrahedra of collapsed cells.
+fn structured(
+    divisions: [usize; 3],
+    node: impl Fn([usize; 3]) -> usize,
+    nodal_coordinates: &[[Scalar; 3]],
+) -> Connectivity<4> {
+    let mut connectivity = Vec::new();
+    for k in 0..divisions[2] {
+        for j in 0..divisions[1] {
+            for i in 0..divisions[0] {
+                PERMUTATIONS.iter().for_each(|permutation| {
+                    let mut corner = [i, j, k];
+                    let mut element = [node(corner); 4];
+                    permutation.iter().enumerate().for_each(|(vertex, &axis)| {
+                        corner[axis] += 1;
+                        element[vertex + 1] = node(corner)
+                    });
+                    if (0..4).all(|a| (a + 1..4).all(|b| element[a] != element[b])) {
+                        connectivity.push(oriented(nodal_coordinates, element))
+                    }
+                })
+            }
+        }
+    }
+    connectivity
+}
+
+/// Faces that belong to a single element, oriented with outward normals.
+fn boundary_faces(connectivity: &Connectivity<4>) -> Vec<Face> {
+    let mut faces: HashMap<Face, (Face, usize)> = HashMap::new();
+    connectivity.iter().for_each(|&[a, b, c, d]| {
+        [[a, c, b], [a, b, d], [a, d, c], [b, c, d]]
+            .into_iter()
+            .for_each(|face| {
+                let mut key = face;
+                key.sort_unstable();
+                faces.entry(key).or_insert((face, 0)).1 += 1
+            })
+    });
+    let mut boundary: Vec<Face> = faces
+        .into_values()
+        .filter(|&(_, count)| count == 1)
+        .map(|(face, _)| face)
+        .collect();
+    boundary.sort_unstable();
+    boundary
+}
+
+fn structured_mesh(
+    nodal_coordinates: Vec<[Scalar; 3]>,
+    connectivity: Connectivity<4>,
+    node_sets: Vec<(String, Vec<usize>)>,
+) -> Mesh {
+    let boundary = boundary_faces(&connectivity);
+    let side_sets = node_sets
+        .iter()
+        .map(|(name, nodes)| {
+            let mut member = vec![false; nodal_coordinates.len()];
+            nodes.iter().for_each(|&node| member[node] = true);
+            (
+                name.clone(),
+                boundary
+                    .iter()
+                    .filter(|face| face.iter().all(|&node| member[node]))
+                    .copied()
+                    .collect(),
+            )
+        })
+        .collect();
+    Mesh {
+        reference_nodal_coordinates: nodal_coordinates,
+        blocks: vec![("block".into(), connectivity)],
+        node_sets,
+        side_sets,
+    }
+}
+
+pub fn cuboid(lengths: [Scalar; 3], divisions: [usize; 3]) -> Result<Mesh, PyErrGlue> {
+    if lengths
+        .iter()
+        .any(|length| length.is_nan() || *length <= 0.0)
+    {
+        return Err(PyErrGlue::new("The lengths must be positive."));
+    }
+    if divisions.contains(&0) {
+        return Err(PyErrGlue::new("The number of divisions must be positive."));
+    }
+    let [nx, ny, nz] = divisions.map(|division| division + 1);
+    let node = |[i, j, k]: [usize; 3]| i + nx * (j + ny * k);
+    let nodal_coordinates: Vec<[Scalar; 3]> = (0..nz)
+        .flat_map(|k| (0..ny).flat_map(move |j| (0..nx).map(move |i| [i, j, k])))
+        .map(|index| [0, 1, 2].map(|a| lengths[a] * index[a] as Scalar / divisions[a] as Scalar))
+        .collect();
+    let connectivity = structured(divisions, node, &nodal_coordinates);
+    let node_sets = ["x", "y", "z"]
+        .iter()
+        .enumerate()
+        .flat_map(|(axis, name)| {
+            [("min", 0), ("max", divisions[axis])].map(|(bound, index)| {
+                (
+                    format!("{name}{bound}"),
+                    (0..nz)
+                        .flat_map(|k| (0..ny).flat_map(move |j| (0..nx).map(move |i| [i, j, k])))
+                        .filter(|corner| corner[axis] == index)
+                        .map(node)
+                        .collect(),
+                )
+            })
+        })
+        .collect();
+    Ok(structured_mesh(nodal_coordinates, connectivity, node_sets))
+}
+
+pub fn cylinder(radius: Scalar, height: Scalar, divisions: [usize; 3]) -> Result<Mesh, PyErrGlue> {
+    if radius.is_nan() || radius <= 0.0 || height.is_nan() || height <= 0.0 {
+        return Err(PyErrGlue::new("The radius and height must be positive."));
+    }
+    if divisions.contains(&0) {
+        return Err(PyErrGlue::new("The number of divisions must be positive."));
+    }
+    let [radial, circumferential, axial] = divisions;
+    if circumferential < 3 {
+        return Err(PyErrGlue::new(
+            "The number of circumferential divisions must be at least 3.",
+        ));
+    }
+    let layer = 1 + radial * circumferential;
+    let node = |[i, j, k]: [usize; 3]| {
+        k * layer
+            + if i == 0 {
+                0
+            } else {
+                1 + (i - 1) * circumferential + j % circumferential
+            }
+    };
+    let nodal_coordinates: Vec<[Scalar; 3]> = (0..=axial)
+        .flat_map(|k| {
+            let z = height * k as Scalar / axial as Scalar;
+            [[0.0, 0.0, z]]
+                .into_iter()
+                .chain((1..=radial).flat_map(move |i| {
+                    (0..circumferential).map(move |j| {
+                        let r = radius * i as Scalar / radial as Scalar;
+                        let theta = std::f64::consts::TAU * j as Scalar / circumferential as Scalar;
+                        [r * theta.cos(), r * theta.sin(), z]
+                    })
+                }))
+        })
+        .collect();
+    let connectivity = structured(divisions, node, &nodal_coordinates);
+    let node_sets = vec![
+        ("bottom".into(), (0..layer).collect()),
+        ("top".into(), (axial * layer..(axial + 1) * layer).collect()),
+        (
+            "outer".into(),
+            (0..=axial)
+                .flat_map(|k| (0..circumferential).map(move |j| node([radial, j, k])))
+                .collect(),
+        ),
+    ];
+    Ok(structured_mesh(nodal_coordinates, connectivity, node_sets))
+}
+
+/// Midpoint nodes shared by the elements of every block.
+struct Midpoints {
+    nodal_coordinates: Vec<[Scalar; 3]>,
+    nodes: HashMap<(usize, usize), usize>,
+}
+
+impl Midpoints {
+    fn node(&mut self, a: usize, b: usize) -> usize {
+        let nodal_coordinates = &mut self.nodal_coordinates;
+        *self.nodes.entry((a.min(b), a.max(b))).or_insert_with(|| {
+            let midpoint =
+                [0, 1, 2].map(|i| 0.5 * (nodal_coordinates[a][i] + nodal_coordinates[b][i]));
+            nodal_coordinates.push(midpoint);
+            nodal_coordinates.len() - 1
+        })
+    }
+}
+
+pub fn refine(mesh: &Mesh) -> Mesh {
+    let mut midpoints = Midpoints {
+        nodal_coordinates: mesh.reference_nodal_coordinates.clone(),
+        nodes: HashMap::new(),
+    };
+    let blocks = mesh
+        .blocks
+        .iter()
+        .map(|(name, connectivity)| {
+            let refined = connectivity
+                .iter()
+                .flat_map(|&[a, b, c, d]| {
+                    let [ab, ac, ad, bc, bd, cd] = [(a, b), (a, c), (a, d), (b, c), (b, d), (c, d)]
+                        .map(|(u, v)| midpoints.node(u, v));
+                    [
+                        [a, ab, ac, ad],
+                        [ab, b, bc, bd],
+                        [ac, bc, c, cd],
+                        [ad, bd, cd, d],
+                        [ac, bd, ab, bc],
+                        [ac, bd, bc, cd],
+                        [ac, bd, cd, ad],
+                        [ac, bd, ad, ab],
+                    ]
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|element| oriented(&midpoints.nodal_coordinates, element))
+                .collect();
+            (name.clone(), refined)
+        })
+        .collect();
+    let surface: HashSet<(usize, usize)> = boundary_faces(
+        &mesh
+            .blocks
+            .iter()
+            .flat_map(|(_, connectivity)| connectivity.iter().copied())
+            .collect(),
+    )
+    .into_iter()
+    .flat_map(|[a, b, c]| [(a, b), (b, c), (c, a)])
+    .map(|(a, b)| (a.min(b), a.max(b)))
+    .collect();
+    let node_sets = mesh
+        .node_sets
+        .iter()
+        .map(|(name, nodes)| {
+            let mut member = vec![false; mesh.reference_nodal_coordinates.len()];
+            nodes.iter().for_each(|&node| member[node] = true);
+            let mut refined = nodes.clone();
+            let mut edges: Vec<_> = midpoints
+                .nodes
+                .iter()
+                .filter(|(edge, _)| member[edge.0] && member[edge.1] && surface.contains(edge))
+                .map(|(_, &node)| node)
+                .collect();
+            edges.sort_unstable();
+            refined.extend(edges);
+            (name.clone(), refined)
+        })
+        .collect();
+    let side_sets = mesh
+        .side_sets
+        .iter()
+        .map(|(name, faces)| {
+            (
+                name.clone(),
+                faces
+                    .iter()
+                    .flat_map(|&[a, b, c]| {
+                        let [ab, bc, ca] =
+                            [(a, b), (b, c), (c, a)].map(|(u, v)| midpoints.node(u, v));
+                        [[a, ab, ca], [ab, b, bc], [ca, bc, c], [ab, bc, ca]]
+                    })
+                    .collect(),
+            )
+        })
+        .collect();
+    Mesh {
+        reference_nodal_coordinates: midpoints.nodal_coordinates,
+        blocks,
+        node_sets,
+        side_sets,
+    }
+}
+
+pub type QuadraticMesh = (Vec<(String, Vec<[usize; 10]>)>, Vec<[Scalar; 3]>);
+
+pub fn quadratic(mesh: &Mesh) -> QuadraticMesh {
+    let mut midpoints = Midpoints {
+        nodal_coordinates: mesh.reference_nodal_coordinates.clone(),
+        nodes: HashMap::new(),
+    };
+    let blocks = mesh
+        .blocks
+        .iter()
+        .map(|(name, connectivity)| {
+            (
+                name.clone(),
+                connectivity
+                    .iter()
+                    .map(|&[a, b, c, d]| {
+                        let [ab, bc, ca, ad, bd, cd] =
+                            [(a, b), (b, c), (c, a), (a, d), (b, d), (c, d)]
+                                .map(|(u, v)| midpoints.node(u, v));
+                        [a, b, c, d, ab, bc, ca, ad, bd, cd]
+                    })
+                    .collect(),
+            )
+        })
+        .collect();
+    (blocks, midpoints.nodal_coordinates)
+}
@@ -0,0 +1,343 @@
+use super::Mesh;
+use crate::PyErrGlue;
+use conspire::mechanics::Scalar;
+use std::{collections::HashMap, str::SplitWhitespace};
+
+const POINT: usize = 15;
+const LINE: usize = 1;
+const TRIANGLE: usize = 2;
+const TETRAHEDRON: usize = 4;
+
+struct Element {
+    dimension: usize,
+    groups: Vec<(bool, usize)>,
+    nodes: Vec<usize>,
+}
+
+fn element_name(element_type: usize) -> String {
+    match element_type {
+        3 => "4-node quadrangle".into(),
+        5 => "8-node hexahedron".into(),
+        6 => "6-node prism".into(),
+        7 => "5-node pyramid".into(),
+        8 => "3-node line".into(),
+        9 => "6-node triangle".into(),
+        11 => "10-node tetrahedron".into(),
+        12 => "27-node hexahedron".into(),
+        17 => "20-node hexahedron".into(),
+        _ => format!("type {element_type}"),
+    }
+}
+
+fn dimension_of(element_type: usize) -> Result<usize, PyErrGlue> {
+    match element_type {
+        POINT => Ok(0),
+        LINE => Ok(1),
+        TRIANGLE => Ok(2),
+        TETRAHEDRON => Ok(3),
+        _ => Err(PyErrGlue::new(&format!(
+            "Unsupported element type {} in Gmsh mesh.",
+            element_name(element_type)
+        ))),
+    }
+}
+
+fn malformed(section: &str) -> PyErrGlue {
+    PyErrGlue::new(&format!("The Gmsh mesh has a malformed {section} section."))
+}
+
+fn section<'a>(text: &'a str, name: &str) -> Option<&'a str> {
+    let start = text.find(&format!("${name}"))? + name.len() + 1;
+    let end = text[start..].find(&format!("$End{name}"))? + start;
+    Some(&text[start..end])
+}
+
+fn next<T: std::str::FromStr>(tokens: &mut SplitWhitespace, name: &str) -> Result<T, PyErrGlue> {
+    tokens
+        .next()
+        .and_then(|token| token.parse().ok())
+        .ok_or_else(|| malformed(name))
+}
+
+fn physical_names(text: &str) -> HashMap<(usize, usize), String> {
+    section(text, "PhysicalNames")
+        .map(|names| {
+            names
+                .lines()
+                .skip(2)
+                .filter_map(|line| {
+                    let mut tokens = line.splitn(3, char::is_whitespace);
+                    let dimension = tokens.next()?.parse().ok()?;
+                    let tag = tokens.next()?.parse().ok()?;
+                    let name = tokens.next()?.trim().trim_matches('"').to_string();
+                    Some(((dimension, tag), name))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn entities(text: &str) -> Result<HashMap<(usize, usize), Vec<usize>>, PyErrGlue> {
+    let mut physical_tags = HashMap::new();
+    if let Some(entities) = section(text, "Entities") {
+        let mut tokens = entities.split_whitespace();
+        let counts: Vec<usize> = (0..4)
+            .map(|_| next(&mut tokens, "Entities"))
+            .collect::<Result<_, _>>()?;
+        counts
+            .iter()
+            .enumerate()
+            .try_for_each(|(dimension, &count)| {
+                (0..count).try_for_each(|_| {
+                    let tag: usize = next(&mut tokens, "Entities")?;
+                    let bounds = if dimension == 0 { 3 } else { 6 };
+                    (0..bounds)
+                        .try_for_each(|_| next::<Scalar>(&mut tokens, "Entities").map(|_| ()))?;
+                    let number_of_tags: usize = next(&mut tokens, "Entities")?;
+                    let tags = (0..number_of_tags)
+                        .map(|_| {
+                            next::<isize>(&mut tokens, "Entities").map(|tag| tag.unsigned_abs())
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    physical_tags.insert((dimension, tag), tags);
+                    if dimension > 0 {
+                        let number_of_bounding: usize = next(&mut tokens, "Entities")?;
+                        (0..number_of_bounding)
+                            .try_for_each(|_| next::<isize>(&mut tokens, "Entities").map(|_| ()))?;
+                    }
+                    Ok::<(), PyErrGlue>(())
+                })
+            })?;
+    }
+    Ok(physical_tags)
+}
+
+fn nodes_v2(text: &str) -> Result<(Vec<usize>, Vec<[Scalar; 3]>), PyErrGlue> {
+    let mut tokens = section(text, "Nodes")
+        .ok_or_else(|| malformed("Nodes"))?
+        .split_whitespace();
+    let count: usize = next(&mut tokens, "Nodes")?;
+    (0..count)
+        .map(|_| {
+            Ok((
+                next::<usize>(&mut tokens, "Nodes")?,
+                [
+                    next(&mut tokens, "Nodes")?,
+                    next(&mut tokens, "Nodes")?,
+                    next(&mut tokens, "Nodes")?,
+                ],
+            ))
+        })
+        .collect::<Result<_, PyErrGlue>>()
+}
+
+fn nodes_v4(text: &str) -> Result<(Vec<usize>, Vec<[Scalar; 3]>), PyErrGlue> {
+    let mut tokens = section(text, "Nodes")
+        .ok_or_else(|| malformed("Nodes"))?
+        .split_whitespace();
+    let blocks: usize = next(&mut tokens, "Nodes")?;
+    (0..3).try_for_each(|_| next::<usize>(&mut tokens, "Nodes").map(|_| ()))?;
+    let mut tags = vec![];
+    let mut coordinates = vec![];
+    (0..blocks).try_for_each(|_| {
+        (0..2).try_for_each(|_| next::<usize>(&mut tokens, "Nodes").map(|_| ()))?;
+        let parametric: usize = next(&mut tokens, "Nodes")?;
+        if parametric != 0 {
+            return Err(PyErrGlue::new(
+                "Parametric nodes are not supported in Gmsh meshes.",
+            ));
+        }
+        let count: usize = next(&mut tokens, "Nodes")?;
+        (0..count).try_for_each(|_| {
+            tags.push(next(&mut tokens, "Nodes")?);
+            Ok::<(), PyErrGlue>(())
+        })?;
+        (0..count).try_for_each(|_| {
+            coordinates.push([
+                next(&mut tokens, "Nodes")?,
+                next(&mut tokens, "Nodes")?,
+                next(&mut tokens, "Nodes")?,
+            ]);
+            Ok::<(), PyErrGlue>(())
+        })
+    })?;
+    Ok((tags, coordinates))
+}
+
+fn elements_v2(text: &str) -> Result<Vec<Element>, PyErrGlue> {
+    section(text, "Elements")
+        .ok_or_else(|| malformed("Elements"))?
+        .lines()
+        .skip(2)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let mut tokens = line.split_whitespace();
+            next::<usize>(&mut tokens, "Elements")?;
+            let dimension = dimension_of(next(&mut tokens, "Elements")?)?;
+            let number_of_tags: usize = next(&mut tokens, "Elements")?;
+            let tags = (0..number_of_tags)
+                .map(|_| next(&mut tokens, "Elements"))
+                .collect::<Result<Vec<usize>, _>>()?;
+            let groups = match tags.as_slice() {
+                [0, entity, ..] => vec![(false, *entity)],
+                [physical, ..] => vec![(true, *physical)],
+                [] => vec![],
+            };
+            Ok(Element {
+                dimension,
+                groups,
+                nodes: tokens
+                    .map(|token| token.parse().map_err(|_| malformed("Elements")))
+                    .collect::<Result<_, _>>()?,
+            })
+        })
+        .collect()
+}
+
+fn elements_v4(
+    text: &str,
+    physical_tags: &HashMap<(usize, usize), Vec<usize>>,
+) -> Result<Vec<Element>, PyErrGlue> {
+    let body = section(text, "Elements").ok_or_else(|| malformed("Elements"))?;
+    let mut lines = body.lines().filter(|line| !line.trim().is_empty());
+    let mut tokens = lines
+        .next()
+        .ok_or_else(|| malformed("Elements"))?
+        .split_whitespace();
+    let blocks: usize = next(&mut tokens, "Elements")?;
+    let mut elements = vec![];
+    (0..blocks).try_for_each(|_| {
+        let mut tokens = lines
+            .next()
+            .ok_or_else(|| malformed("Elements"))?
+            .split_whitespace();
+        let dimension: usize = next(&mut tokens, "Elements")?;
+        let entity: usize = next(&mut tokens, "Elements")?;
+        let element_type: usize = next(&mut tokens, "Elements")?;
+        let count: usize = next(&mut tokens, "Elements")?;
+        dimension_of(element_type)?;
+        let groups = match physical_tags.get(&(dimension, entity)) {
+            Some(tags) if !tags.is_empty() => tags.iter().map(|&tag| (true, tag)).collect(),
+            _ => vec![(false, entity)],
+        };
+        (0..count).try_for_each(|_| {
+            let mut tokens = lines
+                .next()
+                .ok_or_else(|| malformed("Elements"))?
+                .split_whitespace();
+            next::<usize>(&mut tokens, "Elements")?;
+            elements.push(Element {
+                dimension,
+                groups: groups.clone(),
+                nodes: tokens
+                    .map(|token| token.parse().map_err(|_| malformed("Elements")))
+                    .collect::<Result<_, _>>()?,
+            });
+            Ok::<(), PyErrGlue>(())
+        })
+    })?;
+    Ok(elements)
+}
+
+fn insert<T>(sets: &mut Vec<(usize, usize, Vec<T>)>, dimension: usize, tag: usize, item: T) {
+    match sets
+        .iter_mut()
+        .find(|(set_dimension, set_tag, _)| *set_dimension == dimension && *set_tag == tag)
+    {
+        Some((_, _, items)) => items.push(item),
+        None => sets.push((dimension, tag, vec![item])),
+    }
+}
+
+pub fn read(bytes: &[u8]) -> Result<Mesh, PyErrGlue> {
+    let text = String::from_utf8_lossy(bytes);
+    let mut format = section(&text, "MeshFormat")
+        .ok_or_else(|| PyErrGlue::new("The file is not a Gmsh mesh."))?
+        .split_whitespace();
+    let version: Scalar = next(&mut format, "MeshFormat")?;
+    let file_type: usize = next(&mut format, "MeshFormat")?;
+    if file_type != 0 {
+        return Err(PyErrGlue::new("Binary Gmsh meshes are not supported."));
+    }
+    if (4.0..4.1).contains(&version) {
+        return Err(PyErrGlue::new("Gmsh mesh format 4.0 is not supported."));
+    }
+    let names = physical_names(&text);
+    let ((tags, reference_nodal_coordinates), elements) = if version >= 4.0 {
+        (nodes_v4(&text)?, elements_v4(&text, &entities(&text)?)?)
+    } else {
+        (nodes_v2(&text)?, elements_v2(&text)?)
+    };
+    let indices: HashMap<usize, usize> = tags
+        .into_iter()
+        .enumerate()
+        .map(|(index, tag)| (tag, index))
+        .collect();
+    let mut blocks = vec![];
+    let mut node_sets = vec![];
+    let mut side_sets = vec![];
+    elements.into_iter().try_for_each(|element| {
+        let nodes = element
+            .nodes
+            .iter()
+            .map(|tag| {
+                indices.get(tag).copied().ok_or_else(|| {
+                    PyErrGlue::new(&format!("Gmsh element references undefined node {tag}."))
+                })
+            })
+            .collect::<Result<Vec<usize>, _>>()?;
+        if nodes.len() != element.dimension + 1 {
+            return Err(malformed("Elements"));
+        }
+        element.groups.iter().for_each(|&(physical, tag)| {
+            let key = if physical { tag } else { usize::MAX - tag };
+            match element.dimension {
+                3 => insert(
+                    &mut blocks,
+                    3,
+                    key,
+                    [nodes[0], nodes[1], nodes[2], nodes[3]],
+                ),
+                _ if physical => {
+                    if element.dimension == 2 {
+                        insert(&mut side_sets, 2, tag, [nodes[0], nodes[1], nodes[2]])
+                    }
+                    nodes
+                        .iter()
+                        .for_each(|&node| insert(&mut node_sets, element.dimension, tag, node))
+                }
+                _ => (),
+            }
+        });
+        Ok::<(), PyErrGlue>(())
+    })?;
+    let name = |dimension: usize, key: usize| {
+        if key > usize::MAX / 2 {
+            (usize::MAX - key).to_string()
+        } else {
+            names
+                .get(&(dimension, key))
+                .cloned()
+                .unwrap_or(key.to_string())
+        }
+    };
+    Ok(Mesh {
+        reference_nodal_coordinates,
+        blocks: blocks
+            .into_iter()
+            .map(|(dimension, key, connectivity)| (name(dimension, key), connectivity))
+            .collect(),
+        node_sets: node_sets
+            .into_iter()
+            .map(|(dimension, tag, mut nodes)| {
+                nodes.sort();
+                nodes.dedup();
+                (name(dimension, tag), nodes)
+            })
+            .collect(),
+        side_sets: side_sets
+            .into_iter()
+            .map(|(dimension, tag, faces)| (name(dimension, tag), faces))
+            .collect(),
+    })
+}
@@ -0,0 +1,265 @@
+mod exodus;
+mod generate;
+mod gmsh;
+mod vtk;
+
+use crate::{
+    PyErrGlue,
+    fem::{Block, Model, assembly::Assembly},
+};
+use conspire::{fem::block::Connectivity, mechanics::Scalar};
+use ndarray::Array2;
+use numpy::{PyArray1, PyArray2};
+use pyo3::{prelude::*, types::PyDict};
+use std::{collections::HashMap, fs::read, path::PathBuf};
+
+/// Finite element mesh read from an Exodus II, Gmsh, or VTK file, or generated from simple shapes.
+#[pyclass]
+pub struct Mesh {
+    reference_nodal_coordinates: Vec<[Scalar; 3]>,
+    blocks: Vec<(String, Connectivity<4>)>,
+    node_sets: Vec<(String, Vec<usize>)>,
+    side_sets: Vec<(String, Vec<[usize; 3]>)>,
+}
+
+impl Mesh {
+    fn connectivity(&self, name: Option<String>) -> Result<Connectivity<4>, PyErrGlue> {
+        match name {
+            Some(name) => self
+                .blocks
+                .iter()
+                .find(|(block, _)| block == &name)
+                .map(|(_, connectivity)| connectivity.clone())
+                .ok_or(PyErrGlue::new(&format!(
+                    "There is no element block {name}."
+                ))),
+            None => match self.blocks.as_slice() {
+                [(_, connectivity)] => Ok(connectivity.clone()),
+                _ => Err(PyErrGlue::new(
+                    "An element block name is required for meshes with multiple element blocks.",
+                )),
+            },
+        }
+    }
+    /// Returns the connectivity and reference nodal coordinates of a structured tetrahedral mesh of the unit cube.
+    pub(super) fn unit_cube(
+        divisions: usize,
+    ) -> Result<(Connectivity<4>, Vec<[Scalar; 3]>), PyErrGlue> {
+        let mesh = generate::cuboid([1.0; 3], [divisions; 3])?;
+        Ok((mesh.connectivity(None)?, mesh.reference_nodal_coordinates))
+    }
+    fn validate(mut self) -> Result<Self, PyErrGlue> {
+        let nodes = self.reference_nodal_coordinates.len();
+        if self.blocks.is_empty() {
+            return Err(PyErrGlue::new("The mesh has no tetrahedral elements."));
+        }
+        self.blocks.iter().try_for_each(|(name, connectivity)| {
+            if connectivity.iter().flatten().any(|&node| node >= nodes) {
+                Err(PyErrGlue::new(&format!(
+                    "Element block {name} references a node that does not exist."
+                )))
+            } else {
+                Ok(())
+            }
+        })?;
+        self.node_sets.iter().try_for_each(|(name, set)| {
+            if set.iter().any(|&node| node >= nodes) {
+                Err(PyErrGlue::new(&format!(
+                    "Node set {name} references a node that does not exist."
+                )))
+            } else {
+                Ok(())
+            }
+        })?;
+        self.side_sets.iter().try_for_each(|(name, faces)| {
+            if faces.iter().flatten().any(|&node| node >= nodes) {
+                Err(PyErrGlue::new(&format!(
+                    "Side set {name} references a node that does not exist."
+                )))
+            } else {
+                Ok(())
+            }
+        })?;
+        self.orient_side_sets()?;
+        Ok(self)
+    }
+    /// Orients each side set face so that its normal points away from the element it bounds.
+    fn orient_side_sets(&mut self) -> Result<(), PyErrGlue> {
+        if self.side_sets.is_empty() {
+            return Ok(());
+        }
+        let mut opposite = HashMap::new();
+        self.blocks
+            .iter()
+            .flat_map(|(_, connectivity)| connectivity)
+            .for_each(|&[a, b, c, d]| {
+                [
+                    ([a, b, c], d),
+                    ([a, b, d], c),
+                    ([a, c, d], b),
+                    ([b, c, d], a),
+                ]
+                .into_iter()
+                .for_each(|(mut face, node)| {
+                    face.sort_unstable();
+                    opposite.entry(face).or_insert(node);
+                })
+            });
+        let coordinates = &self.reference_nodal_coordinates;
+        self.side_sets.iter_mut().try_for_each(|(name, faces)| {
+            faces.iter_mut().try_for_each(|face| {
+                let mut key = *face;
+                key.sort_unstable();
+                let node = opposite.get(&key).ok_or_else(|| {
+                    PyErrGlue::new(&format!(
+                        "Side set {name} has a face that is not a face of any element."
+                    ))
+                })?;
+                let [p, q, r, o] = [face[0], face[1], face[2], *node].map(|n| coordinates[n]);
+                let [u, v, w] = [q, r, o].map(|x| [0, 1, 2].map(|i| x[i] - p[i]));
+                if (u[1] * v[2] - u[2] * v[1]) * w[0]
+                    + (u[2] * v[0] - u[0] * v[2]) * w[1]
+                    + (u[0] * v[1] - u[1] * v[0]) * w[2]
+                    > 0.0
+                {
+                    face.swap(1, 2)
+                }
+                Ok(())
+            })
+        })
+    }
+}
+
+#[pymethods]
+impl Mesh {
+    #[new]
+    fn new(path: PathBuf) -> Result<Self, PyErr> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        let bytes = read(&path)?;
+        Ok(match extension.as_str() {
+            "e" | "ex2" | "exo" | "g" | "gen" => exodus::read(&bytes),
+            "msh" => gmsh::read(&bytes),
+            "vtk" => vtk::read_legacy(&bytes),
+            "vtu" => vtk::read_xml(&bytes),
+            _ => Err(PyErrGlue::new(&format!(
+                "Unsupported mesh file extension .{extension}."
+            ))),
+        }?
+        .validate()?)
+    }
+    /// Returns a single-block mesh for the given connectivity and reference nodal coordinates.
+    #[staticmethod]
+    fn from_connectivity(
+        connectivity: Connectivity<4>,
+        reference_nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Self, PyErrGlue> {
+        Self {
+            reference_nodal_coordinates,
+            blocks: vec![("block".into(), connectivity)],
+            node_sets: Vec::new(),
+            side_sets: Vec::new(),
+        }
+        .validate()
+    }
+    /// Returns a structured tetrahedral mesh of the box $[0, L_x]\times[0, L_y]\times[0, L_z]$ with node and side sets on each face.
+    #[staticmethod]
+    #[pyo3(name = "box")]
+    fn cuboid(lengths: [Scalar; 3], divisions: [usize; 3]) -> Result<Self, PyErrGlue> {
+        generate::cuboid(lengths, divisions)
+    }
+    /// Returns a structured tetrahedral mesh of a cylinder along the $z$-axis with radial, circumferential, and axial divisions.
+    #[staticmethod]
+    fn cylinder(radius: Scalar, height: Scalar, divisions: [usize; 3]) -> Result<Self, PyErrGlue> {
+        generate::cylinder(radius, height, divisions)
+    }
+    /// Returns the mesh with each tetrahedron subdivided into eight, adding the midpoints of surface edges to node sets.
+    fn refine(&self) -> Self {
+        generate::refine(self)
+    }
+    /// Returns the quadratic tetrahedral connectivity of each element block and the reference nodal coordinates including edge midpoints.
+    ///
+    /// The 10-node connectivity follows the Exodus II and VTK node ordering and is meant for export only, since blocks and assemblies support linear tetrahedra alone.
+    fn quadratic<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> Result<(Bound<'py, PyDict>, Bound<'py, PyArray2<Scalar>>), PyErr> {
+        let (blocks, reference_nodal_coordinates) = generate::quadratic(self);
+        let connectivities = PyDict::new(py);
+        blocks.into_iter().try_for_each(|(name, connectivity)| {
+            connectivities.set_item(
+                name,
+                PyArray2::from_owned_array(py, Array2::from(connectivity)),
+            )
+        })?;
+        Ok((
+            connectivities,
+            PyArray2::from_owned_array(py, Array2::from(reference_nodal_coordinates)),
+        ))
+    }
+    /// The reference nodal coordinates.
+    #[getter]
+    fn reference_nodal_coordinates<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<Scalar>> {
+        PyArray2::from_owned_array(py, Array2::from(self.reference_nodal_coordinates.clone()))
+    }
+    /// The connectivity of each element block.
+    #[getter]
+    fn blocks<'py>(&self, py: Python<'py>) -> Result<Bound<'py, PyDict>, PyErr> {
+        let blocks = PyDict::new(py);
+        self.blocks.iter().try_for_each(|(name, connectivity)| {
+            blocks.set_item(
+                name,
+                PyArray2::from_owned_array(py, Array2::from(connectivity.clone())),
+            )
+        })?;
+        Ok(blocks)
+    }
+    /// The nodes of each node set.
+    #[getter]
+    fn node_sets<'py>(&self, py: Python<'py>) -> Result<Bound<'py, PyDict>, PyErr> {
+        let node_sets = PyDict::new(py);
+        self.node_sets.iter().try_for_each(|(name, nodes)| {
+            node_sets.set_item(name, PyArray1::from_slice(py, nodes))
+        })?;
+        Ok(node_sets)
+    }
+    /// The faces of each side set, oriented with outward normals.
+    #[getter]
+    fn side_sets<'py>(&self, py: Python<'py>) -> Result<Bound<'py, PyDict>, PyErr> {
+        let side_sets = PyDict::new(py);
+        self.side_sets.iter().try_for_each(|(name, faces)| {
+            side_sets.set_item(
+                name,
+                PyArray2::from_owned_array(py, Array2::from(faces.clone())),
+            )
+        })?;
+        Ok(side_sets)
+    }
+    /// Returns a block for an element block of the mesh.
+    #[pyo3(signature = (model, name = None))]
+    fn block(&self, py: Python, model: Model, name: Option<String>) -> Result<Block, PyErr> {
+        Block::new(
+            py,
+            model,
+            self.connectivity(name)?,
+            self.reference_nodal_coordinates.clone(),
+            Some(&self.node_sets(py)?),
+        )
+    }
+    /// Returns an assembly for a dictionary of element block names and models.
+    fn assembly(&self, py: Python, models: &Bound<'_, PyDict>) -> Result<Assembly, PyErr> {
+        Assembly::new(
+            py,
+            models
+                .iter()
+                .map(|(name, model)| {
+                    Ok((model.extract()?, self.connectivity(Some(name.extract()?))?))
+                })
+                .collect::<Result<_, PyErr>>()?,
+            self.reference_nodal_coordinates.clone(),
+        )
+    }
+}
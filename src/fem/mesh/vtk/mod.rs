@@ -0,0 +1,411 @@
+use super::Mesh;
+use crate::PyErrGlue;
+use conspire::mechanics::Scalar;
+
+const VERTEX: usize = 1;
+const LINE: usize = 3;
+const TRIANGLE: usize = 5;
+const TETRAHEDRON: usize = 10;
+
+const BLOCK_ID_NAMES: [&str; 4] = ["BlockId", "block_id", "CellEntityIds", "gmsh:physical"];
+
+fn cell_name(cell_type: usize) -> String {
+    match cell_type {
+        9 => "quad".into(),
+        12 => "hexahedron".into(),
+        13 => "wedge".into(),
+        14 => "pyramid".into(),
+        22 => "quadratic triangle".into(),
+        24 => "quadratic tetrahedron".into(),
+        25 => "quadratic hexahedron".into(),
+        _ => format!("type {cell_type}"),
+    }
+}
+
+fn malformed() -> PyErrGlue {
+    PyErrGlue::new("The VTK file is malformed.")
+}
+
+fn mesh(
+    reference_nodal_coordinates: Vec<[Scalar; 3]>,
+    cells: Vec<(usize, Vec<usize>)>,
+    block_ids: Option<Vec<i64>>,
+) -> Result<Mesh, PyErrGlue> {
+    let mut blocks: Vec<(String, Vec<[usize; 4]>)> = vec![];
+    let mut node_sets: Vec<(String, Vec<usize>)> = vec![];
+    let mut side_sets: Vec<(String, Vec<[usize; 3]>)> = vec![];
+    cells
+        .into_iter()
+        .enumerate()
+        .try_for_each(|(index, (cell_type, nodes))| {
+            let expected = match cell_type {
+                VERTEX => 1,
+                LINE => 2,
+                TRIANGLE => 3,
+                TETRAHEDRON => 4,
+                _ => {
+                    return Err(PyErrGlue::new(&format!(
+                        "Unsupported cell type {} in VTK file.",
+                        cell_name(cell_type)
+                    )));
+                }
+            };
+            if nodes.len() != expected {
+                return Err(malformed());
+            }
+            let name = match &block_ids {
+                Some(ids) => ids.get(index).ok_or_else(malformed)?.to_string(),
+                None if cell_type == TETRAHEDRON => "1".to_string(),
+                None => return Ok(()),
+            };
+            if cell_type == TETRAHEDRON {
+                let element = [nodes[0], nodes[1], nodes[2], nodes[3]];
+                match blocks.iter_mut().find(|(block, _)| block == &name) {
+                    Some((_, connectivity)) => connectivity.push(element),
+                    None => blocks.push((name, vec![element])),
+                }
+            } else {
+                if cell_type == TRIANGLE {
+                    let face = [nodes[0], nodes[1], nodes[2]];
+                    match side_sets.iter_mut().find(|(set, _)| set == &name) {
+                        Some((_, faces)) => faces.push(face),
+                        None => side_sets.push((name.clone(), vec![face])),
+                    }
+                }
+                match node_sets.iter_mut().find(|(set, _)| set == &name) {
+                    Some((_, set)) => set.extend(nodes),
+                    None => node_sets.push((name, nodes)),
+                }
+            }
+            Ok(())
+        })?;
+    node_sets.iter_mut().for_each(|(_, nodes)| {
+        nodes.sort();
+        nodes.dedup();
+    });
+    Ok(Mesh {
+        reference_nodal_coordinates,
+        blocks,
+        node_sets,
+        side_sets,
+    })
+}
+
+fn coordinates(values: &[Scalar]) -> Vec<[Scalar; 3]> {
+    values
+        .chunks_exact(3)
+        .map(|point| [point[0], point[1], point[2]])
+        .collect()
+}
+
+fn cells(
+    connectivity: &[usize],
+    offsets: &[usize],
+    types: &[usize],
+) -> Result<Vec<(usize, Vec<usize>)>, PyErrGlue> {
+    types
+        .iter()
+        .zip(offsets.windows(2))
+        .map(|(&cell_type, offset)| {
+            Ok((
+                cell_type,
+                connectivity
+                    .get(offset[0]..offset[1])
+                    .ok_or_else(malformed)?
+                    .to_vec(),
+            ))
+        })
+        .collect()
+}
+
+struct Tokens<'a> {
+    tokens: Vec<&'a str>,
+    position: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn next(&mut self) -> Option<&'a str> {
+        let token = self.tokens.get(self.position).copied();
+        self.position += 1;
+        token
+    }
+    fn parse<T: std::str::FromStr>(&mut self) -> Result<T, PyErrGlue> {
+        self.next()
+            .and_then(|token| token.parse().ok())
+            .ok_or_else(malformed)
+    }
+    fn values<T: std::str::FromStr>(&mut self, count: usize) -> Result<Vec<T>, PyErrGlue> {
+        (0..count).map(|_| self.parse()).collect()
+    }
+    fn skip(&mut self, count: usize) -> Result<(), PyErrGlue> {
+        self.position = self
+            .position
+            .checked_add(count)
+            .filter(|&position| position <= self.tokens.len())
+            .ok_or_else(malformed)?;
+        Ok(())
+    }
+    fn skip_tuples(&mut self, components: usize, tuples: usize) -> Result<(), PyErrGlue> {
+        self.skip(components.checked_mul(tuples).ok_or_else(malformed)?)
+    }
+    fn peek_is_number(&self) -> bool {
+        self.tokens
+            .get(self.position)
+            .is_some_and(|token| token.parse::<usize>().is_ok())
+    }
+}
+
+pub fn read_legacy(bytes: &[u8]) -> Result<Mesh, PyErrGlue> {
+    let text = String::from_utf8_lossy(bytes);
+    let mut lines = text.lines();
+    if !lines
+        .next()
+        .is_some_and(|line| line.starts_with("# vtk DataFile"))
+    {
+        return Err(PyErrGlue::new("The file is not a legacy VTK file."));
+    }
+    lines.next();
+    if lines.next().map(|line| line.trim().to_uppercase()) != Some("ASCII".into()) {
+        return Err(PyErrGlue::new("Binary VTK files are not supported."));
+    }
+    let mut metadata = false;
+    let mut tokens = Tokens {
+        tokens: lines
+            .filter(|line| {
+                if line.trim() == "METADATA" {
+                    metadata = true
+                } else if line.trim().is_empty() {
+                    metadata = false
+                }
+                !metadata
+            })
+            .flat_map(|line| line.split_whitespace())
+            .collect(),
+        position: 0,
+    };
+    let mut points = vec![];
+    let mut cell_list = vec![];
+    let mut types = vec![];
+    let mut block_ids = None;
+    let mut count = 0;
+    let mut cell_data = false;
+    while let Some(keyword) = tokens.next() {
+        match keyword.to_uppercase().as_str() {
+            "DATASET" => {
+                if tokens.next() != Some("UNSTRUCTURED_GRID") {
+                    return Err(PyErrGlue::new(
+                        "Only VTK unstructured grid datasets are supported.",
+                    ));
+                }
+            }
+            "POINTS" => {
+                let number_of_points: usize = tokens.parse()?;
+                tokens.next();
+                points = coordinates(&tokens.values(3 * number_of_points)?);
+            }
+            "CELLS" => {
+                let first: usize = tokens.parse()?;
+                let second: usize = tokens.parse()?;
+                if tokens.tokens.get(tokens.position) == Some(&"OFFSETS") {
+                    tokens.skip(2)?;
+                    let offsets: Vec<usize> = tokens.values(first)?;
+                    tokens.skip(2)?;
+                    let connectivity: Vec<usize> = tokens.values(second)?;
+                    cell_list = offsets
+                        .windows(2)
+                        .map(|offset| {
+                            Ok(connectivity
+                                .get(offset[0]..offset[1])
+                                .ok_or_else(malformed)?
+                                .to_vec())
+                        })
+                        .collect::<Result<_, PyErrGlue>>()?;
+                } else {
+                    cell_list = (0..first)
+                        .map(|_| {
+                            let size = tokens.parse()?;
+                            tokens.values(size)
+                        })
+                        .collect::<Result<_, _>>()?;
+                }
+            }
+            "CELL_TYPES" => {
+                let number_of_cells: usize = tokens.parse()?;
+                types = tokens.values(number_of_cells)?;
+            }
+            "CELL_DATA" | "POINT_DATA" => {
+                cell_data = keyword.eq_ignore_ascii_case("CELL_DATA");
+                count = tokens.parse()?;
+            }
+            "SCALARS" => {
+                let name = tokens.next().ok_or_else(malformed)?;
+                tokens.next();
+                let components = if tokens.peek_is_number() {
+                    tokens.parse()?
+                } else {
+                    1
+                };
+                if tokens.next() == Some("LOOKUP_TABLE") {
+                    tokens.next();
+                } else {
+                    tokens.position -= 1;
+                }
+                if cell_data && components == 1 && BLOCK_ID_NAMES.contains(&name) {
+                    block_ids = Some(
+                        tokens
+                            .values::<Scalar>(count)?
+                            .into_iter()
+                            .map(|id| id as i64)
+                            .collect(),
+                    );
+                } else {
+                    tokens.skip_tuples(components, count)?
+                }
+            }
+            "FIELD" => {
+                tokens.next();
+                let arrays: usize = tokens.parse()?;
+                (0..arrays).try_for_each(|_| {
+                    let name = tokens.next().ok_or_else(malformed)?;
+                    let components: usize = tokens.parse()?;
+                    let tuples: usize = tokens.parse()?;
+                    tokens.next();
+                    if cell_data && components == 1 && BLOCK_ID_NAMES.contains(&name) {
+                        block_ids = Some(
+                            tokens
+                                .values::<Scalar>(tuples)?
+                                .into_iter()
+                                .map(|id| id as i64)
+                                .collect(),
+                        );
+                    } else {
+                        tokens.skip_tuples(components, tuples)?
+                    }
+                    Ok::<(), PyErrGlue>(())
+                })?;
+            }
+            "VECTORS" | "NORMALS" => {
+                tokens.skip(2)?;
+                tokens.skip_tuples(3, count)?
+            }
+            "TENSORS" => {
+                tokens.skip(2)?;
+                tokens.skip_tuples(9, count)?
+            }
+            "TEXTURE_COORDINATES" => {
+                tokens.next();
+                let dimension: usize = tokens.parse()?;
+                tokens.skip(1)?;
+                tokens.skip_tuples(dimension, count)?
+            }
+            "COLOR_SCALARS" => {
+                tokens.next();
+                let components: usize = tokens.parse()?;
+                tokens.skip_tuples(components, count)?
+            }
+            "LOOKUP_TABLE" => {
+                tokens.next();
+                let size: usize = tokens.parse()?;
+                tokens.skip_tuples(4, size)?
+            }
+            _ => return Err(malformed()),
+        }
+    }
+    if cell_list.len() != types.len() {
+        return Err(malformed());
+    }
+    mesh(
+        points,
+        types.into_iter().zip(cell_list).collect(),
+        block_ids,
+    )
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!(" {name}=\""))? + name.len() + 3;
+    let end = tag[start..].find('"')? + start;
+    Some(&tag[start..end])
+}
+
+fn element<'a>(text: &'a str, name: &str) -> Option<&'a str> {
+    let start = text.find(&format!("<{name}"))?;
+    let end = text[start..].find(&format!("</{name}>"))? + start;
+    Some(&text[start..end])
+}
+
+fn data_arrays(text: &str) -> Result<Vec<(&str, &str)>, PyErrGlue> {
+    text.match_indices("<DataArray")
+        .map(|(start, _)| {
+            let end = text[start..].find('>').ok_or_else(malformed)? + start;
+            let tag = &text[start..end];
+            if attribute(tag, "format").is_some_and(|format| format != "ascii") {
+                return Err(PyErrGlue::new("Only ASCII VTK XML files are supported."));
+            }
+            let content = if tag.ends_with('/') {
+                ""
+            } else {
+                let close = text[end..].find("</DataArray>").ok_or_else(malformed)? + end;
+                &text[end + 1..close]
+            };
+            Ok((tag, content))
+        })
+        .collect()
+}
+
+fn values<T: std::str::FromStr>(content: &str) -> Result<Vec<T>, PyErrGlue> {
+    content
+        .split_whitespace()
+        .map(|token| token.parse().map_err(|_| malformed()))
+        .collect()
+}
+
+fn named<'a>(arrays: &[(&str, &'a str)], name: &str) -> Result<&'a str, PyErrGlue> {
+    arrays
+        .iter()
+        .find(|(tag, _)| attribute(tag, "Name") == Some(name))
+        .map(|(_, content)| *content)
+        .ok_or_else(malformed)
+}
+
+pub fn read_xml(bytes: &[u8]) -> Result<Mesh, PyErrGlue> {
+    let text = String::from_utf8_lossy(bytes);
+    if !text.contains("type=\"UnstructuredGrid\"") {
+        return Err(PyErrGlue::new(
+            "Only VTK unstructured grid datasets are supported.",
+        ));
+    }
+    if text.contains("<AppendedData") {
+        return Err(PyErrGlue::new("Only ASCII VTK XML files are supported."));
+    }
+    let points = data_arrays(element(&text, "Points").ok_or_else(malformed)?)?;
+    let points = coordinates(&values(points.first().ok_or_else(malformed)?.1)?);
+    let cell_arrays = data_arrays(element(&text, "Cells").ok_or_else(malformed)?)?;
+    let connectivity: Vec<usize> = values(named(&cell_arrays, "connectivity")?)?;
+    let offsets: Vec<usize> = [0]
+        .into_iter()
+        .chain(values(named(&cell_arrays, "offsets")?)?)
+        .collect();
+    let types: Vec<usize> = values(named(&cell_arrays, "types")?)?;
+    let block_ids = match element(&text, "CellData") {
+        Some(cell_data) => {
+            let arrays = data_arrays(cell_data)?;
+            match BLOCK_ID_NAMES
+                .iter()
+                .find_map(|name| named(&arrays, name).ok())
+            {
+                Some(content) => Some(
+                    values::<Scalar>(content)?
+                        .into_iter()
+                        .map(|id| id as i64)
+                        .collect(),
+                ),
+                None => None,
+            }
+        }
+        None => None,
+    };
+    if offsets.len() != types.len() + 1 {
+        return Err(malformed());
+    }
+    mesh(points, cells(&connectivity, &offsets, &types)?, block_ids)
+}
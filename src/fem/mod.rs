@@ -1,4 +1,18 @@
+mod assembly;
+pub mod benchmark;
 mod block;
+mod constraint;
+mod contact;
+mod homogenization;
+mod load;
+mod mesh;
+mod modal;
+mod output;
+mod quality;
+mod reaction;
+mod sensitivity;
+mod solve;
+mod thermomechanical;
 
 use crate::PyErrGlue;
 use crate::constitutive::solid::{
@@ -7,31 +21,232 @@ use crate::constitutive::solid::{
         ArrudaBoyce, Fung, Gent, Hencky, MooneyRivlin, NeoHookean, SaintVenantKirchhoff,
     },
 };
-use block::{elastic::ElasticBlock, hyperelastic::HyperelasticBlock};
+use assembly::Assembly;
+use block::{
+    Fields, Tensor,
+    elastic::{ElasticBlock, G},
+    hyperelastic::HyperelasticBlock,
+    plastic::{PlasticBlock, Plasticity},
+    thermal::HeatConductionBlock,
+    viscoelastic::ViscoelasticBlock,
+};
 use conspire::{fem::block::Connectivity, mechanics::Scalar};
-use numpy::{PyArray2, PyArray4};
-use pyo3::prelude::*;
+use constraint::Constraint;
+use homogenization::RepresentativeVolumeElement;
+use load::Load;
+use mesh::Mesh;
+use ndarray::{Array, Array2, Array4, Axis, IxDyn};
+use numpy::{PyArray1, PyArray2, PyArray3, PyArray4, PyArrayDyn, PyReadonlyArrayDyn};
+use pyo3::{create_exception, exceptions::PyTypeError, prelude::*, types::PyDict};
+use solve::{ArcLength, Conditions, NewtonRaphson};
+use thermomechanical::ThermomechanicalBlock;
+
+create_exception!(
+    conspire.fem,
+    InvertedElementError,
+    PyTypeError,
+    "An element with a non-positive Jacobian, given by the `element` and `jacobian` attributes."
+);
 
 pub fn register_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
-    m.add_class::<Block>()
+    m.add(
+        "InvertedElementError",
+        m.py().get_type::<InvertedElementError>(),
+    )?;
+    m.add_class::<Assembly>()?;
+    m.add_class::<Block>()?;
+    m.add_class::<HeatConductionBlock>()?;
+    m.add_class::<Mesh>()?;
+    m.add_class::<Plasticity>()?;
+    m.add_class::<RepresentativeVolumeElement>()?;
+    m.add_class::<ThermomechanicalBlock>()?;
+    m.add_class::<ViscoelasticBlock>()?;
+    constraint::register_module(m)?;
+    load::register_module(m)?;
+    output::register_module(m)
 }
 
-macro_rules! call_method {
-    ($model: ident, $py: ident, $name: literal, $nodal_coordinates: ident) => {
-        Ok($model
-            .call_method1($py, $name, ($nodal_coordinates,))
-            .unwrap()
-            .extract($py)
-            .unwrap())
-    };
+fn integration_point_tensors<'py>(
+    py: Python<'py>,
+    tensors: Vec<Tensor>,
+) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
+    Ok(PyArray4::from_owned_array(
+        py,
+        Array::from_shape_vec(
+            (tensors.len(), G, 3, 3),
+            tensors.into_iter().flatten().flatten().collect(),
+        )?,
+    ))
 }
-pub(crate) use call_method;
+
+type Modes<'py> = (Bound<'py, PyArray1<Scalar>>, Bound<'py, PyArray3<Scalar>>);
+
+type EquilibriumPath<'py> = (Bound<'py, PyArray3<Scalar>>, Bound<'py, PyArray1<Scalar>>);
 
 /// Finite element block.
+#[allow(clippy::enum_variant_names)]
 #[pyclass]
 enum Block {
     ElasticBlock(Py<ElasticBlock>),
     HyperelasticBlock(Py<HyperelasticBlock>),
+    PlasticBlock(Py<PlasticBlock>),
+}
+
+impl Block {
+    fn with_fields<T>(&self, py: Python, function: impl FnOnce(&dyn Fields) -> T) -> T {
+        match self {
+            Self::ElasticBlock(block) => block.borrow(py).with_fields(py, function),
+            Self::HyperelasticBlock(block) => block.borrow(py).with_fields(py, function),
+            Self::PlasticBlock(block) => function(&*block.borrow(py)),
+        }
+    }
+    fn node_sets_checked(
+        &self,
+        py: Python,
+        nodal_coordinates: &[[Scalar; 3]],
+    ) -> Result<Vec<(String, Vec<usize>)>, PyErrGlue> {
+        self.with_fields(py, |fields| {
+            fields.elements().check_nodes(nodal_coordinates)?;
+            Ok(fields.elements().node_sets().to_vec())
+        })
+    }
+    fn stiffnesses_with_loads(
+        &self,
+        py: Python,
+        nodal_coordinates: &[[Scalar; 3]],
+        loads: &[Load],
+    ) -> Result<Array4<Scalar>, PyErrGlue> {
+        let mut stiffnesses = self.stiffnesses(py, Some(nodal_coordinates))?;
+        loads.iter().try_for_each(|load| {
+            if let Some(load_stiffnesses) = load.stiffnesses(py, nodal_coordinates)? {
+                stiffnesses += &load_stiffnesses
+            }
+            Ok::<_, PyErrGlue>(())
+        })?;
+        Ok(stiffnesses)
+    }
+    fn with_fields_mut<T>(&self, py: Python, function: impl FnOnce(&mut dyn Fields) -> T) -> T {
+        match self {
+            Self::ElasticBlock(block) => block.borrow(py).with_fields_mut(py, function),
+            Self::HyperelasticBlock(block) => block.borrow(py).with_fields_mut(py, function),
+            Self::PlasticBlock(block) => function(&mut *block.borrow_mut(py)),
+        }
+    }
+    fn energy(
+        &self,
+        py: Python,
+        nodal_coordinates: Option<&[[Scalar; 3]]>,
+    ) -> Result<Scalar, PyErrGlue> {
+        self.with_fields(py, |fields| {
+            if let Some(nodal_coordinates) = nodal_coordinates {
+                fields.elements().check_nodes(nodal_coordinates)?
+            }
+            fields
+                .helmholtz_free_energy(nodal_coordinates)
+                .map(|energy| energy.map_err(|error| fields.inverted_or(nodal_coordinates, error)))
+                .unwrap_or_else(|| {
+                    Err(PyErrGlue::new(match self {
+                        Self::PlasticBlock(_) => {
+                            "The Helmholtz free energy is undefined for elastic-plastic constitutive models."
+                        }
+                        _ => {
+                            "The Helmholtz free energy density is undefined for elastic constitutive models."
+                        }
+                    }))
+                })
+        })
+    }
+    fn forces(
+        &self,
+        py: Python,
+        nodal_coordinates: Option<&[[Scalar; 3]]>,
+    ) -> Result<Array2<Scalar>, PyErrGlue> {
+        self.with_fields_mut(py, |fields| {
+            if let Some(nodal_coordinates) = nodal_coordinates {
+                fields.elements().check_nodes(nodal_coordinates)?
+            }
+            fields
+                .nodal_forces(nodal_coordinates)
+                .map_err(|error| fields.inverted_or(nodal_coordinates, error))
+        })
+    }
+    fn stiffnesses(
+        &self,
+        py: Python,
+        nodal_coordinates: Option<&[[Scalar; 3]]>,
+    ) -> Result<Array4<Scalar>, PyErrGlue> {
+        self.with_fields(py, |fields| {
+            if let Some(nodal_coordinates) = nodal_coordinates {
+                fields.elements().check_nodes(nodal_coordinates)?
+            }
+            fields
+                .nodal_stiffnesses(nodal_coordinates)
+                .map_err(|error| fields.inverted_or(nodal_coordinates, error))
+        })
+    }
+}
+
+/// A block or an assembly of blocks.
+#[derive(FromPyObject)]
+enum System {
+    Assembly(Py<Assembly>),
+    Block(Py<Block>),
+}
+
+impl System {
+    fn blocks(&self, py: Python) -> Vec<Py<Block>> {
+        match self {
+            Self::Assembly(assembly) => assembly
+                .borrow(py)
+                .blocks
+                .iter()
+                .map(|block| block.clone_ref(py))
+                .collect(),
+            Self::Block(block) => vec![block.clone_ref(py)],
+        }
+    }
+    fn reference_nodal_coordinates(&self, py: Python) -> Result<Vec<[Scalar; 3]>, PyErrGlue> {
+        self.blocks(py)
+            .first()
+            .map(|block| {
+                block.borrow(py).with_fields(py, |fields| {
+                    fields.elements().reference_nodal_coordinates().to_vec()
+                })
+            })
+            .ok_or_else(|| PyErrGlue::new("The assembly has no blocks."))
+    }
+    fn forces(
+        &self,
+        py: Python,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Array2<Scalar>, PyErrGlue> {
+        match self {
+            Self::Assembly(assembly) => assembly.borrow(py).forces(py, nodal_coordinates),
+            Self::Block(block) => block.borrow(py).forces(py, Some(&nodal_coordinates)),
+        }
+    }
+    fn stiffnesses(
+        &self,
+        py: Python,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Array4<Scalar>, PyErrGlue> {
+        match self {
+            Self::Assembly(assembly) => assembly.borrow(py).stiffnesses(py, nodal_coordinates),
+            Self::Block(block) => block.borrow(py).stiffnesses(py, Some(&nodal_coordinates)),
+        }
+    }
+    /// Moves the current configuration of every block to converged nodal coordinates.
+    fn set_coordinates(
+        &self,
+        py: Python,
+        nodal_coordinates: &[[Scalar; 3]],
+    ) -> Result<(), PyErrGlue> {
+        self.blocks(py).iter().try_for_each(|block| {
+            block
+                .borrow(py)
+                .set_coordinates(py, nodal_coordinates.to_vec())
+        })
+    }
 }
 
 #[derive(FromPyObject)]
@@ -43,12 +258,13 @@ enum Model {
     Fung(Py<Fung>),
     MooneyRivlin(Py<MooneyRivlin>),
     NeoHookean(Py<NeoHookean>),
+    Plasticity(Py<Plasticity>),
     SaintVenantKirchhoff(Py<SaintVenantKirchhoff>),
 }
 
 macro_rules! block_inner {
     ($py: ident, $model: ident, $type: ident, $block: ident, $name: ident, $connectivity: ident, $reference_nodal_coordinates: ident, $($parameter: expr),+ $(,)?) => {
-        Ok(Self::$block(Py::new(
+        Self::$block(Py::new(
             $py,
             $block::$name(Py::new(
                 $py,
@@ -56,22 +272,21 @@ macro_rules! block_inner {
                     $($model.getattr($py, stringify!($parameter))?.extract($py)?),+,
                     $connectivity,
                     $reference_nodal_coordinates,
-                )
+                )?
             )?)
-        )?))
+        )?)
     }
 }
 
-#[pymethods]
 impl Block {
-    #[new]
     fn new(
         py: Python,
         model: Model,
         connectivity: Connectivity<4>,
         reference_nodal_coordinates: Vec<[Scalar; 3]>,
+        node_sets: Option<&Bound<'_, PyDict>>,
     ) -> Result<Self, PyErr> {
-        match model {
+        let block = match model {
             Model::AlmansiHamel(model) => block_inner!(
                 py,
                 model,
@@ -154,6 +369,10 @@ impl Block {
                 bulk_modulus,
                 shear_modulus,
             ),
+            Model::Plasticity(model) => Self::PlasticBlock(Py::new(
+                py,
+                PlasticBlock::new(*model.borrow(py), connectivity, reference_nodal_coordinates)?,
+            )?),
             Model::SaintVenantKirchhoff(model) => block_inner!(
                 py,
                 model,
@@ -165,55 +384,578 @@ impl Block {
                 bulk_modulus,
                 shear_modulus,
             ),
+        };
+        if let Some(node_sets) = node_sets {
+            let node_sets = node_sets
+                .iter()
+                .map(|(name, nodes)| Ok((name.extract()?, nodes.extract()?)))
+                .collect::<Result<_, PyErr>>()?;
+            block.with_fields_mut(py, |fields| fields.elements_mut().set_node_sets(node_sets))?;
         }
+        Ok(block)
+    }
+}
+
+#[pymethods]
+impl Block {
+    #[new]
+    #[pyo3(signature = (model, connectivity, reference_nodal_coordinates, node_sets = None))]
+    fn py_new(
+        py: Python,
+        model: Model,
+        connectivity: &Bound<'_, PyAny>,
+        reference_nodal_coordinates: &Bound<'_, PyAny>,
+        node_sets: Option<&Bound<'_, PyDict>>,
+    ) -> Result<Self, PyErr> {
+        let reference_nodal_coordinates =
+            block::reference_nodal_coordinates(reference_nodal_coordinates)?;
+        let connectivity = block::connectivity(connectivity, reference_nodal_coordinates.len())?;
+        Self::new(
+            py,
+            model,
+            connectivity,
+            reference_nodal_coordinates,
+            node_sets,
+        )
+    }
+    /// The nodes of each node set.
+    #[getter]
+    fn node_sets<'py>(&self, py: Python<'py>) -> Result<Bound<'py, PyDict>, PyErr> {
+        let node_sets = PyDict::new(py);
+        self.with_fields(py, |fields| {
+            fields
+                .elements()
+                .node_sets()
+                .iter()
+                .try_for_each(|(name, nodes)| {
+                    node_sets.set_item(name, PyArray1::from_slice(py, nodes))
+                })
+        })?;
+        Ok(node_sets)
+    }
+    /// The nodal coordinates of the current configuration.
+    #[getter]
+    fn nodal_coordinates<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<Scalar>> {
+        PyArray2::from_owned_array(
+            py,
+            Array2::from(self.with_fields(py, |fields| fields.nodal_coordinates())),
+        )
+    }
+    /// Sets the nodal coordinates of the current configuration.
+    fn set_coordinates(
+        &self,
+        py: Python,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<(), PyErrGlue> {
+        self.with_fields_mut(py, |fields| fields.set_nodal_coordinates(nodal_coordinates))
+    }
+    /// Adds nodal displacements to the nodal coordinates of the current configuration.
+    fn update_displacements(
+        &self,
+        py: Python,
+        nodal_displacements: Vec<[Scalar; 3]>,
+    ) -> Result<(), PyErrGlue> {
+        self.with_fields_mut(py, |fields| {
+            fields.update_displacements(&nodal_displacements)
+        })
     }
     /// $$
     /// A = \int_\Omega a\,dV
     /// $$
+    #[pyo3(signature = (nodal_coordinates = None))]
     fn helmholtz_free_energy(
         &self,
         py: Python,
-        nodal_coordinates: Vec<[Scalar; 3]>,
+        nodal_coordinates: Option<Vec<[Scalar; 3]>>,
     ) -> Result<Scalar, PyErrGlue> {
-        match self {
-            Self::ElasticBlock(_) => Err(PyErrGlue::new(
-                "The Helmholtz free energy density is undefined for elastic constitutive models.",
-            )),
-            Self::HyperelasticBlock(block) => {
-                call_method!(block, py, "helmholtz_free_energy", nodal_coordinates)
-            }
-        }
+        self.energy(py, nodal_coordinates.as_deref())
     }
     /// $$
     /// \mathbf{f}_a = \frac{\partial A}{\partial\mathbf{x}_a}
     /// $$
+    #[pyo3(signature = (nodal_coordinates = None))]
     fn nodal_forces<'py>(
         &self,
         py: Python<'py>,
-        nodal_coordinates: Vec<[Scalar; 3]>,
+        nodal_coordinates: Option<Vec<[Scalar; 3]>>,
     ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
-        match self {
-            Self::ElasticBlock(block) => call_method!(block, py, "nodal_forces", nodal_coordinates),
-            Self::HyperelasticBlock(block) => {
-                call_method!(block, py, "nodal_forces", nodal_coordinates)
-            }
-        }
+        Ok(PyArray2::from_owned_array(
+            py,
+            self.forces(py, nodal_coordinates.as_deref())?,
+        ))
     }
     /// $$
     /// \mathbf{K}_{ab} = \frac{\partial\mathbf{f}_a}{\partial\mathbf{x}_b}
     /// $$
+    #[pyo3(signature = (nodal_coordinates = None))]
     fn nodal_stiffnesses<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Option<Vec<[Scalar; 3]>>,
+    ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
+        Ok(PyArray4::from_owned_array(
+            py,
+            self.stiffnesses(py, nodal_coordinates.as_deref())?,
+        ))
+    }
+    /// Accepts the internal variables from the latest evaluation of the nodal forces.
+    ///
+    /// Elastic and hyperelastic blocks are path-independent and have no internal variables, so this has no effect on them.
+    fn commit_state(&self, py: Python) {
+        if let Self::PlasticBlock(block) = self {
+            block.borrow_mut(py).commit_state()
+        }
+    }
+    /// Discards the internal variables from evaluations of the nodal forces since the last commit.
+    ///
+    /// Elastic and hyperelastic blocks have nothing to discard, so this has no effect on them either.
+    fn revert_state(&self, py: Python) {
+        if let Self::PlasticBlock(block) = self {
+            block.borrow_mut(py).revert_state()
+        }
+    }
+    /// $$
+    /// \bar{\varepsilon}^p = \int\sqrt{\tfrac{2}{3}}\,\|\mathbf{d}^p\|\,dt
+    /// $$
+    #[getter]
+    fn equivalent_plastic_strains<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+        let strains = match self {
+            Self::PlasticBlock(block) => block.borrow(py).equivalent_plastic_strains(),
+            _ => self.with_fields(py, |fields| {
+                vec![0.0; fields.elements().connectivity().len()]
+            }),
+        };
+        Ok(PyArray2::from_owned_array(
+            py,
+            Array::from_shape_vec((strains.len(), G), strains)?,
+        ))
+    }
+    /// $$
+    /// \mathbf{F} = \sum_a\mathbf{x}_a\otimes\nabla_0 N_a
+    /// $$
+    fn deformation_gradients<'py>(
         &self,
         py: Python<'py>,
         nodal_coordinates: Vec<[Scalar; 3]>,
     ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
-        match self {
-            Self::ElasticBlock(block) => {
-                call_method!(block, py, "nodal_stiffnesses", nodal_coordinates)
-            }
-            Self::HyperelasticBlock(block) => {
-                call_method!(block, py, "nodal_stiffnesses", nodal_coordinates)
+        integration_point_tensors(
+            py,
+            self.with_fields(py, |fields| {
+                fields.elements().deformation_gradients(&nodal_coordinates)
+            })?,
+        )
+    }
+    /// $$
+    /// \boldsymbol{\sigma}(\mathbf{F})
+    /// $$
+    fn cauchy_stresses<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
+        integration_point_tensors(
+            py,
+            self.with_fields(py, |fields| fields.cauchy_stresses(&nodal_coordinates))?,
+        )
+    }
+    /// $$
+    /// a(\mathbf{F})
+    /// $$
+    fn helmholtz_free_energy_densities<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+        let densities = self
+            .with_fields(py, |fields| {
+                fields.helmholtz_free_energy_densities(&nodal_coordinates)
+            })?
+            .ok_or_else(|| {
+                PyErrGlue::new(
+                    "The Helmholtz free energy density is undefined for elastic constitutive models.",
+                )
+            })?;
+        Ok(PyArray2::from_owned_array(
+            py,
+            Array::from_shape_vec((densities.len(), G), densities)?,
+        ))
+    }
+    /// $$
+    /// \int_\Omega N_a N_b\,dV\,\mathbf{u}_b = \int_\Omega N_a\,\mathbf{u}\,dV
+    /// $$
+    #[pyo3(signature = (field, lumped = false))]
+    fn project_to_nodes<'py>(
+        &self,
+        py: Python<'py>,
+        field: PyReadonlyArrayDyn<'py, Scalar>,
+        lumped: bool,
+    ) -> Result<Bound<'py, PyArrayDyn<Scalar>>, PyErrGlue> {
+        let field = field.as_array();
+        let shape = field.shape().to_vec();
+        self.with_fields(py, |fields| {
+            let elements = fields.elements();
+            if shape.len() < 2 || shape[0] != elements.connectivity().len() || shape[1] != G {
+                return Err(PyErrGlue::new(&format!(
+                    "Expected a field with shape ({}, {G}, ...), got {shape:?}.",
+                    elements.connectivity().len()
+                )));
             }
+            let components = shape[2..].iter().product();
+            let averages: Vec<Vec<Scalar>> = field
+                .to_shape((shape[0], G, components))?
+                .outer_iter()
+                .map(|element| {
+                    element
+                        .mean_axis(Axis(0))
+                        .map_or_else(|| vec![0.0; components], |mean| mean.to_vec())
+                })
+                .collect();
+            let nodal = elements.project(&averages, lumped)?;
+            Ok(PyArrayDyn::from_owned_array(
+                py,
+                Array::from_shape_vec(
+                    IxDyn(&[&[nodal.len()], &shape[2..]].concat()),
+                    nodal.concat(),
+                )?,
+            ))
+        })
+    }
+    /// $$
+    /// J = \frac{V}{V_0},\quad \mathrm{AR} = \frac{h_\mathrm{max}A}{6\sqrt{6}\,V},\quad \mathrm{skew} = 1 - \frac{V}{V_\mathrm{ideal}(R)}
+    /// $$
+    #[pyo3(signature = (nodal_coordinates = None))]
+    fn element_quality<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Option<Vec<[Scalar; 3]>>,
+    ) -> Result<Bound<'py, PyDict>, PyErr> {
+        self.with_fields(py, |fields| {
+            let elements = fields.elements();
+            quality::element_quality(
+                py,
+                elements,
+                nodal_coordinates
+                    .as_deref()
+                    .unwrap_or(elements.reference_nodal_coordinates()),
+            )
+        })
+    }
+    /// $$
+    /// \mathbf{R}_S = \sum_{a\in S}\mathbf{f}_a
+    /// $$
+    fn reaction_forces<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyDict>, PyErr> {
+        let node_sets = self.node_sets_checked(py, &nodal_coordinates)?;
+        let forces = self.forces(py, Some(&nodal_coordinates))?;
+        reaction::reaction_forces(py, &node_sets, &forces)
+    }
+    /// $$
+    /// \mathbf{M}_S = \sum_{a\in S}(\mathbf{x}_a - \mathbf{x}_0)\times\mathbf{f}_a
+    /// $$
+    #[pyo3(signature = (nodal_coordinates, origin = [0.0; 3]))]
+    fn reaction_moments<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        origin: [Scalar; 3],
+    ) -> Result<Bound<'py, PyDict>, PyErr> {
+        let node_sets = self.node_sets_checked(py, &nodal_coordinates)?;
+        let forces = self.forces(py, Some(&nodal_coordinates))?;
+        reaction::reaction_moments(py, &node_sets, &forces, &nodal_coordinates, origin)
+    }
+    /// $$
+    /// W_n = \sum_{k=1}^n\sum_a\tfrac{1}{2}\left(\mathbf{f}_a^k + \mathbf{f}_a^{k-1}\right)\cdot\left(\mathbf{x}_a^k - \mathbf{x}_a^{k-1}\right) \approx A_n - A_0
+    /// $$
+    fn energy_balance<'py>(
+        &self,
+        py: Python<'py>,
+        steps: Vec<Vec<[Scalar; 3]>>,
+    ) -> Result<Bound<'py, PyDict>, PyErr> {
+        let node_sets = steps
+            .iter()
+            .map(|nodal_coordinates| self.node_sets_checked(py, nodal_coordinates))
+            .collect::<Result<Vec<_>, _>>()?
+            .pop()
+            .unwrap_or_default();
+        reaction::energy_balance(
+            py,
+            &node_sets,
+            &steps,
+            |nodal_coordinates| self.forces(py, Some(&nodal_coordinates)),
+            |nodal_coordinates| self.energy(py, Some(&nodal_coordinates)),
+        )
+    }
+    /// $$
+    /// \mathbf{M}_{ab} = \int_{\Omega_0}\rho_0N_aN_b\,dV
+    /// $$
+    #[pyo3(signature = (density = 1.0))]
+    fn nodal_masses<'py>(&self, py: Python<'py>, density: Scalar) -> Bound<'py, PyArray2<Scalar>> {
+        PyArray2::from_owned_array(
+            py,
+            self.with_fields(py, |fields| modal::nodal_masses(fields.elements(), density)),
+        )
+    }
+    /// $$
+    /// \mathbf{K}\cdot\boldsymbol{\phi} = \omega^2\mathbf{M}\cdot\boldsymbol{\phi}
+    /// $$
+    #[pyo3(signature = (nodal_coordinates, num_modes, fixed_nodes, density = 1.0, shift = 0.0))]
+    fn eigenmodes<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        num_modes: usize,
+        fixed_nodes: Vec<usize>,
+        density: Scalar,
+        shift: Scalar,
+    ) -> Result<Modes<'py>, PyErr> {
+        self.with_fields(py, |fields| {
+            fields.elements().check_nodes(&nodal_coordinates)
+        })?;
+        let stiffnesses = self.stiffnesses(py, Some(&nodal_coordinates))?;
+        let (eigenvalues, modes) = self.with_fields(py, |fields| {
+            modal::eigenmodes(
+                fields.elements(),
+                &stiffnesses,
+                &fixed_nodes,
+                num_modes,
+                density,
+                shift,
+            )
+        })?;
+        Ok((
+            PyArray1::from_iter(
+                py,
+                eigenvalues
+                    .iter()
+                    .map(|eigenvalue| eigenvalue.signum() * eigenvalue.abs().sqrt()),
+            ),
+            PyArray3::from_owned_array(py, modes),
+        ))
+    }
+    /// $$
+    /// \left[\mathbf{K}_0 + \lambda\left(\mathbf{K} - \mathbf{K}_0\right)\right]\cdot\boldsymbol{\phi} = \mathbf{0}
+    /// $$
+    fn buckling_modes<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        num_modes: usize,
+        fixed_nodes: Vec<usize>,
+    ) -> Result<Modes<'py>, PyErr> {
+        let reference_nodal_coordinates = self.with_fields(py, |fields| {
+            fields.elements().check_nodes(&nodal_coordinates)?;
+            Ok::<_, PyErrGlue>(fields.elements().reference_nodal_coordinates().to_vec())
+        })?;
+        let (load_factors, modes) = modal::buckling_modes(
+            &self.stiffnesses(py, Some(&reference_nodal_coordinates))?,
+            &self.stiffnesses(py, Some(&nodal_coordinates))?,
+            &fixed_nodes,
+            num_modes,
+        )?;
+        Ok((
+            PyArray1::from_vec(py, load_factors),
+            PyArray3::from_owned_array(py, modes),
+        ))
+    }
+    /// $$
+    /// \frac{\partial A}{\partial p_k} \approx \frac{A(p_k + h_k) - A(p_k - h_k)}{2h_k},\quad h_k = \epsilon\max(|p_k|, 1)
+    /// $$
+    ///
+    /// The step $\epsilon$ is the `relative_step`.
+    #[pyo3(signature = (nodal_coordinates, relative_step = 1e-6))]
+    fn helmholtz_free_energy_sensitivities<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        relative_step: Scalar,
+    ) -> Result<Bound<'py, PyDict>, PyErr> {
+        let sensitivities = self.with_fields(py, |fields| {
+            fields.elements().check_jacobians(&nodal_coordinates)?;
+            sensitivity::helmholtz_free_energy_sensitivities(
+                fields,
+                &nodal_coordinates,
+                relative_step,
+            )
+        })?;
+        let dict = PyDict::new(py);
+        sensitivities
+            .into_iter()
+            .try_for_each(|(name, sensitivity)| dict.set_item(name, sensitivity))?;
+        Ok(dict)
+    }
+    /// $$
+    /// \frac{\partial\mathbf{f}_a}{\partial p_k} \approx \frac{\mathbf{f}_a(p_k + h_k) - \mathbf{f}_a(p_k - h_k)}{2h_k},\quad h_k = \epsilon\max(|p_k|, 1)
+    /// $$
+    ///
+    /// The step $\epsilon$ is the `relative_step`.
+    #[pyo3(signature = (nodal_coordinates, relative_step = 1e-6))]
+    fn nodal_force_sensitivities<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        relative_step: Scalar,
+    ) -> Result<Bound<'py, PyDict>, PyErr> {
+        let sensitivities = self.with_fields(py, |fields| {
+            fields.elements().check_jacobians(&nodal_coordinates)?;
+            sensitivity::nodal_force_sensitivities(fields, &nodal_coordinates, relative_step)
+        })?;
+        let dict = PyDict::new(py);
+        sensitivities
+            .into_iter()
+            .try_for_each(|(name, sensitivity)| {
+                dict.set_item(name, PyArray2::from_owned_array(py, sensitivity))
+            })?;
+        Ok(dict)
+    }
+    /// $$
+    /// \mathbf{K}^T\cdot\boldsymbol{\lambda} = \frac{\partial J}{\partial\mathbf{x}},\quad \frac{dJ}{dp_k} = -\boldsymbol{\lambda}\cdot\frac{\partial\mathbf{f}}{\partial p_k}
+    /// $$
+    ///
+    /// The force sensitivities are central differences with the `relative_step`, as in `nodal_force_sensitivities`.
+    #[pyo3(signature = (nodal_coordinates, fixed_nodes, objective_gradient, loads = Vec::new(), relative_step = 1e-6))]
+    fn adjoint_sensitivities<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        fixed_nodes: Vec<usize>,
+        objective_gradient: Vec<[Scalar; 3]>,
+        loads: Vec<Load>,
+        relative_step: Scalar,
+    ) -> Result<Bound<'py, PyDict>, PyErr> {
+        let stiffnesses = self.stiffnesses_with_loads(py, &nodal_coordinates, &loads)?;
+        let sensitivities = self.with_fields(py, |fields| {
+            sensitivity::adjoint_sensitivities(
+                fields,
+                &nodal_coordinates,
+                &stiffnesses,
+                &fixed_nodes,
+                &objective_gradient,
+                relative_step,
+            )
+        })?;
+        let dict = PyDict::new(py);
+        sensitivities
+            .into_iter()
+            .try_for_each(|(name, sensitivity)| dict.set_item(name, sensitivity))?;
+        Ok(dict)
+    }
+    /// $$
+    /// \frac{\partial A}{\partial\mathbf{X}_a} = \int_\Omega\left(a\,\mathbf{1} - \mathbf{F}^T\cdot\mathbf{P}\right)\cdot\nabla_0 N_a\,dV
+    /// $$
+    fn helmholtz_free_energy_shape_sensitivities<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+        Ok(PyArray2::from_owned_array(
+            py,
+            self.with_fields(py, |fields| {
+                fields.elements().check_jacobians(&nodal_coordinates)?;
+                sensitivity::helmholtz_free_energy_shape_sensitivities(fields, &nodal_coordinates)
+            })?,
+        ))
+    }
+    /// $$
+    /// \mathbf{K}^T\cdot\boldsymbol{\lambda} = \frac{\partial J}{\partial\mathbf{x}},\quad \frac{dJ}{d\mathbf{X}_a} = -\boldsymbol{\lambda}\cdot\frac{\partial\mathbf{f}}{\partial\mathbf{X}_a}
+    /// $$
+    #[pyo3(signature = (nodal_coordinates, fixed_nodes, objective_gradient, loads = Vec::new()))]
+    fn shape_sensitivities<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        fixed_nodes: Vec<usize>,
+        objective_gradient: Vec<[Scalar; 3]>,
+        loads: Vec<Load>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+        let stiffnesses = self.stiffnesses_with_loads(py, &nodal_coordinates, &loads)?;
+        Ok(PyArray2::from_owned_array(
+            py,
+            self.with_fields(py, |fields| {
+                sensitivity::shape_sensitivities(
+                    fields,
+                    &nodal_coordinates,
+                    &stiffnesses,
+                    &fixed_nodes,
+                    &objective_gradient,
+                )
+            })?,
+        ))
+    }
+    /// $$
+    /// \mathbf{f}_a(\mathbf{x}) + \mathbf{f}_a^\mathrm{load}(\mathbf{x}) = \mathbf{0}\quad\forall a\notin\mathrm{fixed}
+    /// $$
+    #[pyo3(signature = (nodal_coordinates, fixed_nodes, loads = Vec::new(), constraints = Vec::new(), **kwargs))]
+    fn solve<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        fixed_nodes: Vec<usize>,
+        loads: Vec<Load>,
+        constraints: Vec<Constraint>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErr> {
+        let reference_nodal_coordinates = self.with_fields(py, |fields| {
+            fields.elements().reference_nodal_coordinates().to_vec()
+        });
+        let solution = NewtonRaphson::from_kwargs(kwargs)?.solve(
+            py,
+            nodal_coordinates,
+            Conditions {
+                reference_nodal_coordinates: &reference_nodal_coordinates,
+                fixed_nodes: &fixed_nodes,
+                loads: &loads,
+                constraints: &constraints,
+            },
+            |nodal_coordinates| self.forces(py, Some(&nodal_coordinates)),
+            |nodal_coordinates| self.stiffnesses(py, Some(&nodal_coordinates)),
+        )?;
+        self.set_coordinates(py, solution.clone())?;
+        Ok(PyArray2::from_owned_array(py, Array2::from(solution)))
+    }
+    /// $$
+    /// \mathbf{f}_a(\mathbf{x}) + \lambda\,\mathbf{f}_a^\mathrm{load}(\mathbf{x}) = \mathbf{0}\quad\forall a\notin\mathrm{fixed},\quad \|\Delta\mathbf{x}\|^2 + \psi^2\Delta\lambda^2 = \Delta s^2
+    /// $$
+    #[pyo3(signature = (nodal_coordinates, fixed_nodes, loads, arc_length, **kwargs))]
+    fn arc_length<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        fixed_nodes: Vec<usize>,
+        loads: Vec<Load>,
+        arc_length: Scalar,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> Result<EquilibriumPath<'py>, PyErr> {
+        self.with_fields(py, |fields| {
+            fields.elements().check_nodes(&nodal_coordinates)
+        })?;
+        let nodes = nodal_coordinates.len();
+        let (path, load_factors) = ArcLength::new(arc_length, kwargs)?.trace(
+            py,
+            nodal_coordinates,
+            &fixed_nodes,
+            &loads,
+            |nodal_coordinates| self.forces(py, Some(&nodal_coordinates)),
+            |nodal_coordinates| self.stiffnesses(py, Some(&nodal_coordinates)),
+        )?;
+        if let Some(nodal_coordinates) = path.last() {
+            self.set_coordinates(py, nodal_coordinates.clone())?
         }
+        Ok((
+            PyArray3::from_owned_array(
+                py,
+                Array::from_shape_vec(
+                    (path.len(), nodes, 3),
+                    path.into_iter().flatten().flatten().collect(),
+                )
+                .map_err(PyErrGlue::from)?,
+            ),
+            PyArray1::from_vec(py, load_factors),
+        ))
     }
 }
@@ -0,0 +1,165 @@
+use crate::{
+    PyErrGlue,
+    fem::block::Elements,
+    math::linalg::{
+        lanczos,
+        sparse::{CholeskyDecomposition, CompressedSparseRows, SparseLuDecomposition},
+    },
+};
+use conspire::mechanics::Scalar;
+use ndarray::{Array2, Array3, Array4};
+
+type Modes = (Vec<Scalar>, Array3<Scalar>);
+
+struct Dofs {
+    free: Vec<(usize, usize)>,
+    index: Vec<[Option<usize>; 3]>,
+}
+
+impl Dofs {
+    fn new(nodes: usize, fixed_nodes: &[usize], count: usize) -> Result<Self, PyErrGlue> {
+        if let Some(node) = fixed_nodes.iter().find(|&&node| node >= nodes) {
+            return Err(PyErrGlue::new(&format!(
+                "Fixed node {node} is out of range for {nodes} nodes."
+            )));
+        }
+        let mut fixed = vec![false; nodes];
+        fixed_nodes.iter().for_each(|&node| fixed[node] = true);
+        let free: Vec<(usize, usize)> = (0..nodes)
+            .filter(|&node| !fixed[node])
+            .flat_map(|node| (0..3).map(move |i| (node, i)))
+            .collect();
+        if count > free.len() {
+            return Err(PyErrGlue::new(&format!(
+                "Requested {count} modes, but there are only {} free degrees of freedom.",
+                free.len()
+            )));
+        }
+        let mut index = vec![[None; 3]; nodes];
+        free.iter()
+            .enumerate()
+            .for_each(|(k, &(a, i))| index[a][i] = Some(k));
+        Ok(Self { free, index })
+    }
+    /// The nonzero stiffnesses between free coordinates, as triplets of free indices and stiffness.
+    fn restrict(&self, stiffnesses: &Array4<Scalar>) -> Vec<(usize, usize, Scalar)> {
+        stiffnesses
+            .indexed_iter()
+            .filter(|&(_, &stiffness)| stiffness != 0.0)
+            .filter_map(|((a, b, i, j), &stiffness)| {
+                Some((self.index[a][i]?, self.index[b][j]?, stiffness))
+            })
+            .collect()
+    }
+    fn matrix(&self, entries: &[(usize, usize, Scalar)]) -> CompressedSparseRows {
+        CompressedSparseRows::from_triplets((self.free.len(), self.free.len()), entries)
+    }
+    fn modes(&self, vectors: &[Vec<Scalar>], unit: bool) -> Array3<Scalar> {
+        let mut modes = Array3::zeros((vectors.len(), self.index.len(), 3));
+        vectors.iter().enumerate().for_each(|(mode, vector)| {
+            let largest = vector
+                .iter()
+                .fold(0.0, |a: Scalar, &b| if b.abs() > a.abs() { b } else { a });
+            let scale = if unit {
+                1.0 / largest
+            } else {
+                largest.signum()
+            };
+            self.free
+                .iter()
+                .zip(vector.iter())
+                .for_each(|(&(a, i), value)| modes[[mode, a, i]] = scale * value)
+        });
+        modes
+    }
+}
+
+fn element_mass(a: usize, b: usize, volume: Scalar) -> Scalar {
+    volume / 20.0 * if a == b { 2.0 } else { 1.0 }
+}
+
+/// The consistent masses of each pair of nodes within each element, as triplets of nodes and mass.
+pub fn nodal_mass_entries(elements: &Elements, density: Scalar) -> Vec<(usize, usize, Scalar)> {
+    elements
+        .connectivity()
+        .iter()
+        .zip(elements.volumes().iter())
+        .flat_map(|(element, &volume)| {
+            (0..4).flat_map(move |a| {
+                (0..4).map(move |b| (element[a], element[b], density * element_mass(a, b, volume)))
+            })
+        })
+        .collect()
+}
+
+pub fn nodal_masses(elements: &Elements, density: Scalar) -> Array2<Scalar> {
+    let nodes = elements.reference_nodal_coordinates().len();
+    let mut masses = Array2::zeros((nodes, nodes));
+    nodal_mass_entries(elements, density)
+        .into_iter()
+        .for_each(|(a, b, mass)| masses[[a, b]] += mass);
+    masses
+}
+
+pub fn eigenmodes(
+    elements: &Elements,
+    stiffnesses: &Array4<Scalar>,
+    fixed_nodes: &[usize],
+    count: usize,
+    density: Scalar,
+    shift: Scalar,
+) -> Result<Modes, PyErrGlue> {
+    let dofs = Dofs::new(stiffnesses.shape()[0], fixed_nodes, count)?;
+    let couplings: Vec<(usize, usize, Scalar)> = nodal_mass_entries(elements, density)
+        .into_iter()
+        .flat_map(|(a, b, mass)| {
+            let index = &dofs.index;
+            (0..3).filter_map(move |i| Some((index[a][i]?, index[b][i]?, mass)))
+        })
+        .collect();
+    let mass = dofs.matrix(&couplings);
+    let mut entries = dofs.restrict(stiffnesses);
+    entries.extend(couplings.iter().map(|&(k, l, mass)| (k, l, -shift * mass)));
+    let factorization = SparseLuDecomposition::new(&dofs.matrix(&entries))?;
+    let (values, vectors) = lanczos(
+        |vector| factorization.solve(&mass.product(vector)),
+        |vector| mass.product(vector),
+        dofs.free.len(),
+        count,
+    )?;
+    let eigenvalues: Vec<Scalar> = values.iter().map(|value| shift + 1.0 / value).collect();
+    let mut order: Vec<usize> = (0..eigenvalues.len()).collect();
+    order.sort_by(|&a, &b| eigenvalues[a].total_cmp(&eigenvalues[b]));
+    Ok((
+        order.iter().map(|&k| eigenvalues[k]).collect(),
+        dofs.modes(
+            &order
+                .iter()
+                .map(|&k| vectors[k].clone())
+                .collect::<Vec<_>>(),
+            false,
+        ),
+    ))
+}
+
+pub fn buckling_modes(
+    reference_stiffnesses: &Array4<Scalar>,
+    stiffnesses: &Array4<Scalar>,
+    fixed_nodes: &[usize],
+    count: usize,
+) -> Result<Modes, PyErrGlue> {
+    let dofs = Dofs::new(stiffnesses.shape()[0], fixed_nodes, count)?;
+    let reference = dofs.matrix(&dofs.restrict(reference_stiffnesses));
+    let difference = dofs.matrix(&dofs.restrict(&(reference_stiffnesses - stiffnesses)));
+    let factorization = CholeskyDecomposition::new(&reference)?;
+    let (values, vectors) = lanczos(
+        |vector| factorization.solve(&difference.product(vector)),
+        |vector| reference.product(vector),
+        dofs.free.len(),
+        count,
+    )?;
+    Ok((
+        values.iter().map(|value| 1.0 / value).collect(),
+        dofs.modes(&vectors, true),
+    ))
+}
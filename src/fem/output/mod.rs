@@ -0,0 +1,289 @@
+use crate::{
+    PyErrGlue,
+    fem::{
+        Block, System,
+        block::{Tensor, von_mises_stress},
+    },
+};
+use conspire::{fem::block::Connectivity, mechanics::Scalar};
+use pyo3::prelude::*;
+use std::{fmt::Write, fs::write, path::PathBuf};
+
+pub fn register_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<TimeSeries>()?;
+    m.add_function(wrap_pyfunction!(write_vtu, m)?)
+}
+
+struct Piece {
+    connectivity: Connectivity<4>,
+    deformation_gradients: Vec<Tensor>,
+    cauchy_stresses: Vec<Tensor>,
+    helmholtz_free_energy_densities: Option<Vec<Scalar>>,
+}
+
+impl Piece {
+    fn new(
+        py: Python,
+        block: &Block,
+        nodal_coordinates: &[[Scalar; 3]],
+    ) -> Result<(Vec<[Scalar; 3]>, Self), PyErrGlue> {
+        block.with_fields(py, |fields| {
+            let elements = fields.elements();
+            Ok((
+                elements.reference_nodal_coordinates().to_vec(),
+                Self {
+                    connectivity: elements.connectivity().clone(),
+                    deformation_gradients: elements.deformation_gradients(nodal_coordinates)?,
+                    cauchy_stresses: fields.cauchy_stresses(nodal_coordinates)?,
+                    helmholtz_free_energy_densities: fields
+                        .helmholtz_free_energy_densities(nodal_coordinates)?,
+                },
+            ))
+        })
+    }
+}
+
+fn data_array<T: std::fmt::Display>(
+    xml: &mut String,
+    kind: &str,
+    name: &str,
+    components: usize,
+    values: impl Iterator<Item = T>,
+) {
+    write!(xml, "        <DataArray type=\"{kind}\"").unwrap();
+    if !name.is_empty() {
+        write!(xml, " Name=\"{name}\"").unwrap();
+    }
+    if components > 1 {
+        write!(xml, " NumberOfComponents=\"{components}\"").unwrap();
+    }
+    xml.push_str(" format=\"ascii\">\n");
+    values
+        .collect::<Vec<_>>()
+        .chunks(components)
+        .for_each(|chunk| {
+            xml.push_str("          ");
+            chunk.iter().enumerate().for_each(|(index, value)| {
+                if index > 0 {
+                    xml.push(' ');
+                }
+                write!(xml, "{value}").unwrap();
+            });
+            xml.push('\n');
+        });
+    xml.push_str("        </DataArray>\n");
+}
+
+fn flatten(tensors: &[Tensor]) -> impl Iterator<Item = Scalar> + '_ {
+    tensors.iter().flatten().flatten().copied()
+}
+
+fn vtu(
+    py: Python,
+    system: &System,
+    nodal_coordinates: &[[Scalar; 3]],
+) -> Result<String, PyErrGlue> {
+    let blocks = system.blocks(py);
+    let mut reference_nodal_coordinates = nodal_coordinates.to_vec();
+    let pieces = blocks
+        .iter()
+        .map(|block| {
+            let (reference, piece) = Piece::new(py, &block.borrow(py), nodal_coordinates)?;
+            reference_nodal_coordinates = reference;
+            Ok(piece)
+        })
+        .collect::<Result<Vec<_>, PyErrGlue>>()?;
+    let cells = pieces
+        .iter()
+        .map(|piece| piece.connectivity.len())
+        .sum::<usize>();
+    let mut xml = String::from(
+        "<?xml version=\"1.0\"?>\n\
+         <VTKFile type=\"UnstructuredGrid\" version=\"1.0\" byte_order=\"LittleEndian\">\n  \
+         <UnstructuredGrid>\n",
+    );
+    writeln!(
+        xml,
+        "    <Piece NumberOfPoints=\"{}\" NumberOfCells=\"{cells}\">",
+        nodal_coordinates.len()
+    )
+    .unwrap();
+    xml.push_str("      <PointData Vectors=\"displacement\">\n");
+    data_array(
+        &mut xml,
+        "Float64",
+        "displacement",
+        3,
+        nodal_coordinates
+            .iter()
+            .zip(reference_nodal_coordinates.iter())
+            .flat_map(|(current, reference)| [0, 1, 2].map(|i| current[i] - reference[i])),
+    );
+    xml.push_str("      </PointData>\n      <CellData Scalars=\"von_mises_stress\">\n");
+    data_array(
+        &mut xml,
+        "Int32",
+        "BlockId",
+        1,
+        pieces
+            .iter()
+            .enumerate()
+            .flat_map(|(index, piece)| vec![index + 1; piece.connectivity.len()]),
+    );
+    data_array(
+        &mut xml,
+        "Float64",
+        "cauchy_stress",
+        9,
+        pieces
+            .iter()
+            .flat_map(|piece| flatten(&piece.cauchy_stresses)),
+    );
+    data_array(
+        &mut xml,
+        "Float64",
+        "deformation_gradient",
+        9,
+        pieces
+            .iter()
+            .flat_map(|piece| flatten(&piece.deformation_gradients)),
+    );
+    if pieces
+        .iter()
+        .any(|piece| piece.helmholtz_free_energy_densities.is_some())
+    {
+        data_array(
+            &mut xml,
+            "Float64",
+            "helmholtz_free_energy_density",
+            1,
+            pieces.iter().flat_map(|piece| {
+                piece
+                    .helmholtz_free_energy_densities
+                    .clone()
+                    .unwrap_or_else(|| vec![Scalar::NAN; piece.connectivity.len()])
+            }),
+        );
+    }
+    data_array(
+        &mut xml,
+        "Float64",
+        "von_mises_stress",
+        1,
+        pieces
+            .iter()
+            .flat_map(|piece| piece.cauchy_stresses.iter().map(von_mises_stress)),
+    );
+    xml.push_str("      </CellData>\n      <Points>\n");
+    data_array(
+        &mut xml,
+        "Float64",
+        "",
+        3,
+        nodal_coordinates.iter().flatten(),
+    );
+    xml.push_str("      </Points>\n      <Cells>\n");
+    data_array(
+        &mut xml,
+        "Int64",
+        "connectivity",
+        4,
+        pieces
+            .iter()
+            .flat_map(|piece| piece.connectivity.iter().flatten()),
+    );
+    data_array(
+        &mut xml,
+        "Int64",
+        "offsets",
+        1,
+        (1..=cells).map(|cell| 4 * cell),
+    );
+    data_array(&mut xml, "UInt8", "types", 1, (0..cells).map(|_| 10));
+    xml.push_str("      </Cells>\n    </Piece>\n  </UnstructuredGrid>\n</VTKFile>\n");
+    Ok(xml)
+}
+
+/// Writes the displacements, deformation gradients, Cauchy stresses, von Mises stresses, and Helmholtz free energy densities of a block or an assembly to a VTK unstructured grid file.
+///
+/// $$
+/// \sigma_\mathrm{vM} = \sqrt{\tfrac{3}{2}\,\mathrm{dev}(\boldsymbol{\sigma}):\mathrm{dev}(\boldsymbol{\sigma})}
+/// $$
+#[pyfunction]
+fn write_vtu(
+    py: Python,
+    path: PathBuf,
+    system: System,
+    nodal_coordinates: Vec<[Scalar; 3]>,
+) -> Result<(), PyErr> {
+    Ok(write(path, vtu(py, &system, &nodal_coordinates)?)?)
+}
+
+/// Time series of VTK unstructured grid files collected in a ParaView data file.
+#[pyclass]
+pub struct TimeSeries {
+    path: PathBuf,
+    steps: Vec<(Scalar, String)>,
+}
+
+#[pymethods]
+impl TimeSeries {
+    #[new]
+    fn new(path: PathBuf) -> Result<Self, PyErrGlue> {
+        if path.extension().is_some_and(|extension| extension == "pvd") {
+            Ok(Self {
+                path,
+                steps: Vec::new(),
+            })
+        } else {
+            Err(PyErrGlue::new(&format!(
+                "Expected a path with the extension .pvd, got {}.",
+                path.display()
+            )))
+        }
+    }
+    /// @private
+    #[getter]
+    fn path(&self) -> PathBuf {
+        self.path.clone()
+    }
+    /// The times written so far.
+    #[getter]
+    fn times(&self) -> Vec<Scalar> {
+        self.steps.iter().map(|(time, _)| *time).collect()
+    }
+    /// Writes a step to its own unstructured grid file and updates the collection.
+    fn write(
+        &mut self,
+        py: Python,
+        system: System,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        time: Scalar,
+    ) -> Result<(), PyErr> {
+        let stem = self
+            .path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let file = format!("{stem}_{}.vtu", self.steps.len());
+        write(
+            self.path.with_file_name(&file),
+            vtu(py, &system, &nodal_coordinates)?,
+        )?;
+        self.steps.push((time, file));
+        let mut xml = String::from(
+            "<?xml version=\"1.0\"?>\n\
+             <VTKFile type=\"Collection\" version=\"1.0\">\n  \
+             <Collection>\n",
+        );
+        self.steps.iter().for_each(|(time, file)| {
+            writeln!(
+                xml,
+                "    <DataSet timestep=\"{time}\" part=\"0\" file=\"{file}\"/>"
+            )
+            .unwrap()
+        });
+        xml.push_str("  </Collection>\n</VTKFile>\n");
+        Ok(write(&self.path, xml)?)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::fem::block::{Elements, determinant, inverse, jacobian};
+use conspire::mechanics::Scalar;
+use numpy::PyArray1;
+use pyo3::{prelude::*, types::PyDict};
+
+type Vector = [Scalar; 3];
+
+fn norm(v: Vector) -> Scalar {
+    (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt()
+}
+
+fn area(u: Vector, v: Vector) -> Scalar {
+    0.5 * norm([
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ])
+}
+
+fn aspect_ratio(points: &[Vector; 4], volume: Scalar) -> Scalar {
+    let edge = |a: usize, b: usize| [0, 1, 2].map(|i| points[b][i] - points[a][i]);
+    let longest = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]
+        .iter()
+        .map(|&(a, b)| norm(edge(a, b)))
+        .fold(0.0, Scalar::max);
+    let surface: Scalar = [(0, 1, 2), (0, 1, 3), (0, 2, 3), (1, 2, 3)]
+        .iter()
+        .map(|&(a, b, c)| area(edge(a, b), edge(a, c)))
+        .sum();
+    longest * surface / (6.0 * (6.0 as Scalar).sqrt() * volume.abs())
+}
+
+fn skew(edges: &[[Scalar; 3]; 3], volume: Scalar) -> Scalar {
+    if volume == 0.0 {
+        return 1.0;
+    }
+    let rows = [0, 1, 2].map(|a| [0, 1, 2].map(|i| edges[i][a]));
+    let inverse = inverse(&rows);
+    let lengths = rows.map(|row| 0.5 * (row[0] * row[0] + row[1] * row[1] + row[2] * row[2]));
+    let center = [0, 1, 2].map(|i| (0..3).map(|a| inverse[i][a] * lengths[a]).sum());
+    let ideal = 8.0 * (3.0 as Scalar).sqrt() / 27.0 * norm(center).powi(3);
+    1.0 - volume.abs() / ideal
+}
+
+pub fn element_quality<'py>(
+    py: Python<'py>,
+    elements: &Elements,
+    nodal_coordinates: &[Vector],
+) -> Result<Bound<'py, PyDict>, PyErr> {
+    elements.check_nodes(nodal_coordinates)?;
+    let count = elements.volumes().len();
+    let mut volumes = Vec::with_capacity(count);
+    let mut aspect_ratios = Vec::with_capacity(count);
+    let mut skews = Vec::with_capacity(count);
+    elements.connectivity().iter().for_each(|element| {
+        let edges = jacobian(nodal_coordinates, element);
+        let volume = determinant(&edges) / 6.0;
+        let points = element.map(|node| nodal_coordinates[node]);
+        volumes.push(volume);
+        aspect_ratios.push(aspect_ratio(&points, volume));
+        skews.push(skew(&edges, volume));
+    });
+    let jacobians: Vec<Scalar> = volumes
+        .iter()
+        .zip(elements.volumes().iter())
+        .map(|(volume, reference)| volume / reference)
+        .collect();
+    let quality = PyDict::new(py);
+    quality.set_item("volume", PyArray1::from_vec(py, volumes))?;
+    quality.set_item("jacobian", PyArray1::from_vec(py, jacobians))?;
+    quality.set_item("aspect_ratio", PyArray1::from_vec(py, aspect_ratios))?;
+    quality.set_item("skew", PyArray1::from_vec(py, skews))?;
+    Ok(quality)
+}
@@ -0,0 +1,111 @@
+use crate::{PyErrGlue, fem::load::cross};
+use conspire::mechanics::Scalar;
+use ndarray::{Array1, Array2};
+use numpy::PyArray1;
+use pyo3::{prelude::*, types::PyDict};
+
+type NodeSets = [(String, Vec<usize>)];
+
+fn force(forces: &Array2<Scalar>, node: usize) -> [Scalar; 3] {
+    [0, 1, 2].map(|i| forces[[node, i]])
+}
+
+fn add(u: [Scalar; 3], v: [Scalar; 3]) -> [Scalar; 3] {
+    [u[0] + v[0], u[1] + v[1], u[2] + v[2]]
+}
+
+pub fn reaction_forces<'py>(
+    py: Python<'py>,
+    node_sets: &NodeSets,
+    forces: &Array2<Scalar>,
+) -> Result<Bound<'py, PyDict>, PyErr> {
+    let reactions = PyDict::new(py);
+    node_sets.iter().try_for_each(|(name, nodes)| {
+        let total = nodes
+            .iter()
+            .fold([0.0; 3], |total, &node| add(total, force(forces, node)));
+        reactions.set_item(name, PyArray1::from_slice(py, &total))
+    })?;
+    Ok(reactions)
+}
+
+pub fn reaction_moments<'py>(
+    py: Python<'py>,
+    node_sets: &NodeSets,
+    forces: &Array2<Scalar>,
+    nodal_coordinates: &[[Scalar; 3]],
+    origin: [Scalar; 3],
+) -> Result<Bound<'py, PyDict>, PyErr> {
+    let moments = PyDict::new(py);
+    node_sets.iter().try_for_each(|(name, nodes)| {
+        let total = nodes.iter().fold([0.0; 3], |total, &node| {
+            let arm = [0, 1, 2].map(|i| nodal_coordinates[node][i] - origin[i]);
+            add(total, cross(arm, force(forces, node)))
+        });
+        moments.set_item(name, PyArray1::from_slice(py, &total))
+    })?;
+    Ok(moments)
+}
+
+fn cumulative_work(
+    steps: &[Vec<[Scalar; 3]>],
+    forces: &[Array2<Scalar>],
+    nodes: &[usize],
+) -> Array1<Scalar> {
+    let mut total = 0.0;
+    Array1::from_iter((0..steps.len()).map(|step| {
+        if step > 0 {
+            total += nodes
+                .iter()
+                .flat_map(|&node| (0..3).map(move |i| (node, i)))
+                .map(|(node, i)| {
+                    0.5 * (forces[step][[node, i]] + forces[step - 1][[node, i]])
+                        * (steps[step][node][i] - steps[step - 1][node][i])
+                })
+                .sum::<Scalar>()
+        }
+        total
+    }))
+}
+
+pub fn energy_balance<'py>(
+    py: Python<'py>,
+    node_sets: &NodeSets,
+    steps: &[Vec<[Scalar; 3]>],
+    forces: impl Fn(Vec<[Scalar; 3]>) -> Result<Array2<Scalar>, PyErrGlue>,
+    helmholtz_free_energy: impl Fn(Vec<[Scalar; 3]>) -> Result<Scalar, PyErrGlue>,
+) -> Result<Bound<'py, PyDict>, PyErr> {
+    if steps.is_empty() {
+        return Err(PyErrGlue::new("At least one load step is required.").into());
+    }
+    let nodal_forces = steps
+        .iter()
+        .map(|nodal_coordinates| forces(nodal_coordinates.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let energy = steps
+        .iter()
+        .map(|nodal_coordinates| helmholtz_free_energy(nodal_coordinates.clone()))
+        .collect::<Result<Array1<_>, _>>()?;
+    let nodes: Vec<usize> = (0..steps[0].len()).collect();
+    let external_work = cumulative_work(steps, &nodal_forces, &nodes);
+    let error = &external_work - &(&energy - energy[0]);
+    let node_set_work = PyDict::new(py);
+    node_sets.iter().try_for_each(|(name, nodes)| {
+        node_set_work.set_item(
+            name,
+            PyArray1::from_owned_array(py, cumulative_work(steps, &nodal_forces, nodes)),
+        )
+    })?;
+    let balance = PyDict::new(py);
+    balance.set_item(
+        "external_work",
+        PyArray1::from_owned_array(py, external_work),
+    )?;
+    balance.set_item(
+        "helmholtz_free_energy",
+        PyArray1::from_owned_array(py, energy),
+    )?;
+    balance.set_item("error", PyArray1::from_owned_array(py, error))?;
+    balance.set_item("node_set_work", node_set_work)?;
+    Ok(balance)
+}
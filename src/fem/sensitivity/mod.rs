@@ -0,0 +1,262 @@
+use crate::{
+    PyErrGlue,
+    fem::block::{Fields, Tensor},
+    math::linalg::solve_dense,
+};
+use conspire::mechanics::Scalar;
+use ndarray::{Array2, Array4};
+
+type Sensitivities<T> = Vec<(&'static str, T)>;
+
+/// Differentiates with respect to each constitutive model parameter using central differences,
+/// with a step of the relative step times the magnitude of the parameter (at least one).
+fn central_difference<T>(
+    fields: &dyn Fields,
+    relative_step: Scalar,
+    function: impl Fn(&[Scalar]) -> Result<T, PyErrGlue>,
+    difference: impl Fn(T, T, Scalar) -> T,
+) -> Result<Sensitivities<T>, PyErrGlue> {
+    if relative_step.is_nan() || relative_step <= 0.0 {
+        return Err(PyErrGlue::new("The relative step must be positive."));
+    }
+    let parameters = fields.parameters();
+    let values: Vec<Scalar> = parameters.iter().map(|&(_, value)| value).collect();
+    parameters
+        .iter()
+        .enumerate()
+        .map(|(k, &(name, value))| {
+            let step = relative_step * value.abs().max(1.0);
+            let [upper, lower] = [step, -step].map(|perturbation| {
+                let mut perturbed = values.clone();
+                perturbed[k] += perturbation;
+                perturbed
+            });
+            Ok((
+                name,
+                difference(function(&upper)?, function(&lower)?, 2.0 * step),
+            ))
+        })
+        .collect()
+}
+
+pub fn helmholtz_free_energy_sensitivities(
+    fields: &dyn Fields,
+    nodal_coordinates: &[[Scalar; 3]],
+    relative_step: Scalar,
+) -> Result<Sensitivities<Scalar>, PyErrGlue> {
+    fields.elements().check_nodes(nodal_coordinates)?;
+    central_difference(
+        fields,
+        relative_step,
+        |parameters| {
+            fields
+                .helmholtz_free_energy_with(parameters, nodal_coordinates)
+                .unwrap_or_else(|| {
+                    Err(PyErrGlue::new(
+                        "The Helmholtz free energy density is undefined for elastic constitutive models.",
+                    ))
+                })
+        },
+        |upper, lower, step| (upper - lower) / step,
+    )
+}
+
+pub fn nodal_force_sensitivities(
+    fields: &dyn Fields,
+    nodal_coordinates: &[[Scalar; 3]],
+    relative_step: Scalar,
+) -> Result<Sensitivities<Array2<Scalar>>, PyErrGlue> {
+    fields.elements().check_nodes(nodal_coordinates)?;
+    central_difference(
+        fields,
+        relative_step,
+        |parameters| {
+            let forces = fields.nodal_forces_with(parameters, nodal_coordinates)?;
+            Ok(Array2::from_shape_fn((forces.len(), 3), |(a, i)| {
+                forces[a][i]
+            }))
+        },
+        |upper, lower, step| (upper - lower) / step,
+    )
+}
+
+fn adjoint(
+    stiffnesses: &Array4<Scalar>,
+    fixed_nodes: &[usize],
+    objective_gradient: &[[Scalar; 3]],
+) -> Result<Array2<Scalar>, PyErrGlue> {
+    let nodes = stiffnesses.shape()[0];
+    if objective_gradient.len() != nodes {
+        return Err(PyErrGlue::new(&format!(
+            "Expected {nodes} objective gradient components, got {}.",
+            objective_gradient.len()
+        )));
+    }
+    if let Some(node) = fixed_nodes.iter().find(|&&node| node >= nodes) {
+        return Err(PyErrGlue::new(&format!(
+            "Fixed node {node} is out of range for {nodes} nodes."
+        )));
+    }
+    let free_dofs: Vec<(usize, usize)> = (0..nodes)
+        .filter(|node| !fixed_nodes.contains(node))
+        .flat_map(|node| (0..3).map(move |i| (node, i)))
+        .collect();
+    let transpose = free_dofs
+        .iter()
+        .map(|&(a, i)| {
+            free_dofs
+                .iter()
+                .map(|&(b, j)| stiffnesses[[b, a, j, i]])
+                .collect()
+        })
+        .collect();
+    let solution = solve_dense(
+        transpose,
+        free_dofs
+            .iter()
+            .map(|&(a, i)| objective_gradient[a][i])
+            .collect(),
+    )?;
+    let mut multipliers = Array2::zeros((nodes, 3));
+    free_dofs
+        .iter()
+        .zip(solution)
+        .for_each(|(&(a, i), multiplier)| multipliers[[a, i]] = multiplier);
+    Ok(multipliers)
+}
+
+pub fn adjoint_sensitivities(
+    fields: &dyn Fields,
+    nodal_coordinates: &[[Scalar; 3]],
+    stiffnesses: &Array4<Scalar>,
+    fixed_nodes: &[usize],
+    objective_gradient: &[[Scalar; 3]],
+    relative_step: Scalar,
+) -> Result<Sensitivities<Scalar>, PyErrGlue> {
+    let multipliers = adjoint(stiffnesses, fixed_nodes, objective_gradient)?;
+    Ok(
+        nodal_force_sensitivities(fields, nodal_coordinates, relative_step)?
+            .into_iter()
+            .map(|(name, forces)| (name, -(&multipliers * &forces).sum()))
+            .collect(),
+    )
+}
+
+pub fn helmholtz_free_energy_shape_sensitivities(
+    fields: &dyn Fields,
+    nodal_coordinates: &[[Scalar; 3]],
+) -> Result<Array2<Scalar>, PyErrGlue> {
+    let elements = fields.elements();
+    let mut sensitivities = Array2::zeros((nodal_coordinates.len(), 3));
+    elements
+        .connectivity()
+        .iter()
+        .zip(elements.gradient_vectors().iter())
+        .zip(elements.volumes().iter())
+        .zip(elements.deformation_gradients(nodal_coordinates)?.iter())
+        .try_for_each(|(((element, gradient_vectors), volume), deformation_gradient)| {
+            let density = fields
+                .helmholtz_free_energy_density(deformation_gradient)
+                .unwrap_or_else(|| {
+                    Err(PyErrGlue::new(
+                        "The Helmholtz free energy density is undefined for elastic constitutive models.",
+                    ))
+                })?;
+            let stress = fields.first_piola_kirchhoff_stress(deformation_gradient)?;
+            let eshelby_stress: Tensor = [0, 1, 2].map(|i| {
+                [0, 1, 2].map(|j| {
+                    let identity = if i == j { density } else { 0.0 };
+                    identity
+                        - (0..3)
+                            .map(|k| deformation_gradient[k][i] * stress[k][j])
+                            .sum::<Scalar>()
+                })
+            });
+            element
+                .iter()
+                .zip(gradient_vectors.iter())
+                .for_each(|(&node, gradient_vector)| {
+                    (0..3).for_each(|i| {
+                        sensitivities[[node, i]] += volume
+                            * (0..3)
+                                .map(|j| eshelby_stress[i][j] * gradient_vector[j])
+                                .sum::<Scalar>()
+                    })
+                });
+            Ok::<_, PyErrGlue>(())
+        })?;
+    Ok(sensitivities)
+}
+
+pub fn shape_sensitivities(
+    fields: &dyn Fields,
+    nodal_coordinates: &[[Scalar; 3]],
+    stiffnesses: &Array4<Scalar>,
+    fixed_nodes: &[usize],
+    objective_gradient: &[[Scalar; 3]],
+) -> Result<Array2<Scalar>, PyErrGlue> {
+    let multipliers = adjoint(stiffnesses, fixed_nodes, objective_gradient)?;
+    let elements = fields.elements();
+    let mut sensitivities = Array2::zeros((nodal_coordinates.len(), 3));
+    elements
+        .connectivity()
+        .iter()
+        .zip(elements.gradient_vectors().iter())
+        .zip(elements.volumes().iter())
+        .zip(elements.deformation_gradients(nodal_coordinates)?.iter())
+        .try_for_each(
+            |(((element, gradient_vectors), volume), deformation_gradient)| {
+                let gradient: Tensor = [0, 1, 2].map(|i| {
+                    [0, 1, 2].map(|j| {
+                        element
+                            .iter()
+                            .zip(gradient_vectors.iter())
+                            .map(|(&node, gradient_vector)| {
+                                multipliers[[node, i]] * gradient_vector[j]
+                            })
+                            .sum()
+                    })
+                });
+                let contract = |tensor: &Tensor| {
+                    (0..3)
+                        .flat_map(|i| (0..3).map(move |j| (i, j)))
+                        .map(|(i, j)| tensor[i][j] * gradient[i][j])
+                        .sum::<Scalar>()
+                };
+                let stress = fields.first_piola_kirchhoff_stress(deformation_gradient)?;
+                let tangent =
+                    fields.first_piola_kirchhoff_tangent_stiffness(deformation_gradient)?;
+                let directional: Tensor = [0, 1, 2].map(|i| {
+                    [0, 1, 2].map(|j| {
+                        (0..3)
+                            .map(|k| {
+                                contract(&tangent.map(|row| row.map(|column| column[k][j])))
+                                    * deformation_gradient[k][i]
+                            })
+                            .sum()
+                    })
+                });
+                let work = contract(&stress);
+                element
+                    .iter()
+                    .zip(gradient_vectors.iter())
+                    .for_each(|(&node, gradient_vector)| {
+                        (0..3).for_each(|i| {
+                            sensitivities[[node, i]] -= volume
+                                * (gradient_vector[i] * work
+                                    - (0..3)
+                                        .map(|j| {
+                                            (directional[i][j]
+                                                + (0..3)
+                                                    .map(|k| stress[k][j] * gradient[k][i])
+                                                    .sum::<Scalar>())
+                                                * gradient_vector[j]
+                                        })
+                                        .sum::<Scalar>())
+                        })
+                    });
+                Ok::<_, PyErrGlue>(())
+            },
+        )?;
+    Ok(sensitivities)
+}
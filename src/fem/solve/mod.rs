@@ -0,0 +1,556 @@
+use crate::{
+    PyErrGlue,
+    fem::{
+        constraint::{Constraint, Term},
+        load::Load,
+    },
+    math::linalg::sparse::{CompressedSparseRows, SparseLuDecomposition},
+};
+use conspire::{
+    fem::{
+        NodalCoordinates,
+        solid::{NodalForcesSolid, NodalStiffnessesSolid},
+    },
+    math::{
+        TensorRank2,
+        optimize::{EqualityConstraint, FirstOrderRootFinding, OptimizationError},
+    },
+    mechanics::Scalar,
+};
+use ndarray::{Array2, Array4};
+use pyo3::{prelude::*, types::PyDict};
+use std::cell::RefCell;
+
+/// Newton-Raphson solver for nodal equilibrium with fixed nodes and applied loads.
+pub struct NewtonRaphson {
+    pub abs_tol: Scalar,
+    pub max_augmentations: usize,
+    pub max_steps: usize,
+}
+
+/// Fixed nodes, loads, and constraints applied during a solve, with the reference nodal coordinates that rigid bodies are tied in.
+pub struct Conditions<'a> {
+    pub reference_nodal_coordinates: &'a [[Scalar; 3]],
+    pub fixed_nodes: &'a [usize],
+    pub loads: &'a [Load],
+    pub constraints: &'a [Constraint],
+}
+
+struct Dofs {
+    free: Vec<(usize, usize)>,
+    fixed: Vec<usize>,
+    index: Vec<[Option<usize>; 3]>,
+    equations: Vec<(Vec<Term>, Scalar)>,
+}
+
+/// Keeps the first error for Python while passing its message to an upstream solver.
+fn keep<T>(error: &RefCell<Option<PyErrGlue>>, result: Result<T, PyErrGlue>) -> Result<T, String> {
+    result.map_err(|glue| {
+        let message = glue.message.clone();
+        error.borrow_mut().get_or_insert(glue);
+        message
+    })
+}
+
+impl Default for NewtonRaphson {
+    fn default() -> Self {
+        Self {
+            abs_tol: 1e-10,
+            max_augmentations: 25,
+            max_steps: 25,
+        }
+    }
+}
+
+impl NewtonRaphson {
+    pub fn from_kwargs(kwargs: Option<&Bound<'_, PyDict>>) -> Result<Self, PyErr> {
+        let mut solver = Self::default();
+        if let Some(args) = kwargs {
+            args.into_iter().try_for_each(|(name, value)| {
+                match name.extract()? {
+                    "abs_tol" => solver.abs_tol = value.extract()?,
+                    "max_augmentations" => solver.max_augmentations = value.extract()?,
+                    "max_steps" => solver.max_steps = value.extract()?,
+                    name => {
+                        return Err(PyErrGlue::new(&format!(
+                            "Unexpected keyword argument {name}."
+                        ))
+                        .into());
+                    }
+                };
+                Ok::<(), PyErr>(())
+            })?
+        }
+        Ok(solver)
+    }
+    pub fn solve(
+        &self,
+        py: Python,
+        mut nodal_coordinates: Vec<[Scalar; 3]>,
+        conditions: Conditions,
+        residual: impl Fn(Vec<[Scalar; 3]>) -> Result<Array2<Scalar>, PyErrGlue>,
+        tangent: impl Fn(Vec<[Scalar; 3]>) -> Result<Array4<Scalar>, PyErrGlue>,
+    ) -> Result<Vec<[Scalar; 3]>, PyErrGlue> {
+        let Conditions {
+            reference_nodal_coordinates,
+            fixed_nodes,
+            loads,
+            constraints,
+        } = conditions;
+        let nodes = nodal_coordinates.len();
+        if let Some(node) = fixed_nodes.iter().find(|&&node| node >= nodes) {
+            return Err(PyErrGlue::new(&format!(
+                "Fixed node {node} is out of range for {nodes} nodes."
+            )));
+        }
+        let mut fixed_nodes = fixed_nodes.to_vec();
+        constraints.iter().try_for_each(|constraint| {
+            fixed_nodes.extend(constraint.prescribe(
+                py,
+                reference_nodal_coordinates,
+                &mut nodal_coordinates,
+            )?);
+            Ok::<(), PyErrGlue>(())
+        })?;
+        let mut fixed = vec![false; nodes];
+        fixed_nodes.iter().for_each(|&node| fixed[node] = true);
+        let equations = constraints
+            .iter()
+            .enumerate()
+            .filter_map(|(index, constraint)| {
+                constraint.equation(py).map(|equation| (index, equation))
+            })
+            .map(|(index, (terms, value))| {
+                if terms
+                    .iter()
+                    .any(|&(node, _, coefficient)| !fixed[node] && coefficient != 0.0)
+                {
+                    Ok((terms, value))
+                } else {
+                    Err(PyErrGlue::new(&format!(
+                        "Constraint {index} only involves fixed coordinates."
+                    )))
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        let free: Vec<(usize, usize)> = (0..nodes)
+            .filter(|&node| !fixed[node])
+            .flat_map(|node| (0..3).map(move |i| (node, i)))
+            .collect();
+        let mut index = vec![[None; 3]; nodes];
+        free.iter()
+            .enumerate()
+            .for_each(|(k, &(a, i))| index[a][i] = Some(k));
+        let dofs = Dofs {
+            free,
+            fixed: (0..nodes)
+                .filter(|&node| fixed[node])
+                .flat_map(|node| (0..3).map(move |i| 3 * node + i))
+                .collect(),
+            index,
+            equations,
+        };
+        loads
+            .iter()
+            .try_for_each(|load| load.begin(py, &nodal_coordinates))?;
+        for _ in 0..self.max_augmentations {
+            let multipliers;
+            (nodal_coordinates, multipliers) =
+                self.equilibrium(py, nodal_coordinates, &dofs, loads, &residual, &tangent)?;
+            let satisfied: Vec<bool> = loads
+                .iter()
+                .map(|load| load.augment(py, &nodal_coordinates))
+                .collect::<Result<_, _>>()?;
+            if satisfied.into_iter().all(|satisfied| satisfied) {
+                loads
+                    .iter()
+                    .try_for_each(|load| load.commit(py, &nodal_coordinates))?;
+                if !constraints.is_empty() {
+                    let forces = self.forces(py, &nodal_coordinates, loads, &residual)?;
+                    let mut multipliers = multipliers.into_iter();
+                    constraints.iter().for_each(|constraint| {
+                        constraint.commit(py, &nodal_coordinates, &forces, &mut multipliers)
+                    });
+                }
+                return Ok(nodal_coordinates);
+            }
+        }
+        Err(PyErrGlue::new(
+            "The maximum number of augmentations was reached.",
+        ))
+    }
+    fn forces(
+        &self,
+        py: Python,
+        nodal_coordinates: &[[Scalar; 3]],
+        loads: &[Load],
+        residual: impl Fn(Vec<[Scalar; 3]>) -> Result<Array2<Scalar>, PyErrGlue>,
+    ) -> Result<Array2<Scalar>, PyErrGlue> {
+        let mut forces = residual(nodal_coordinates.to_vec())?;
+        loads.iter().try_for_each(|load| {
+            forces += &load.forces(py, nodal_coordinates)?;
+            Ok::<_, PyErrGlue>(())
+        })?;
+        Ok(forces)
+    }
+    fn stiffnesses(
+        &self,
+        py: Python,
+        nodal_coordinates: &[[Scalar; 3]],
+        loads: &[Load],
+        tangent: impl Fn(Vec<[Scalar; 3]>) -> Result<Array4<Scalar>, PyErrGlue>,
+    ) -> Result<Array4<Scalar>, PyErrGlue> {
+        let mut stiffnesses = tangent(nodal_coordinates.to_vec())?;
+        loads.iter().try_for_each(|load| {
+            if let Some(load_stiffnesses) = load.stiffnesses(py, nodal_coordinates)? {
+                stiffnesses += &load_stiffnesses
+            }
+            Ok::<_, PyErrGlue>(())
+        })?;
+        Ok(stiffnesses)
+    }
+    /// Solves for equilibrium with the fixed coordinates eliminated using the upstream Newton-Raphson method,
+    /// keeping the first error from evaluating the forces or stiffnesses so that it reaches Python intact.
+    fn root(
+        &self,
+        py: Python,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        dofs: &Dofs,
+        loads: &[Load],
+        residual: impl Fn(Vec<[Scalar; 3]>) -> Result<Array2<Scalar>, PyErrGlue>,
+        tangent: impl Fn(Vec<[Scalar; 3]>) -> Result<Array4<Scalar>, PyErrGlue>,
+    ) -> Result<Vec<[Scalar; 3]>, PyErrGlue> {
+        let error = RefCell::new(None);
+        let solver = conspire::math::optimize::NewtonRaphson {
+            abs_tol: self.abs_tol,
+            max_steps: self.max_steps,
+            ..Default::default()
+        };
+        match solver.root(
+            |nodal_coordinates: &NodalCoordinates| {
+                keep(
+                    &error,
+                    self.forces(py, &Vec::from(nodal_coordinates.clone()), loads, &residual)
+                        .map(|forces| {
+                            forces
+                                .outer_iter()
+                                .map(|force| [force[0], force[1], force[2]])
+                                .collect::<Vec<_>>()
+                        }),
+                )
+                .map(NodalForcesSolid::from)
+            },
+            |nodal_coordinates: &NodalCoordinates| {
+                keep(
+                    &error,
+                    self.stiffnesses(py, &Vec::from(nodal_coordinates.clone()), loads, &tangent),
+                )
+                .map(|stiffnesses| {
+                    stiffnesses
+                        .outer_iter()
+                        .map(|row| {
+                            row.outer_iter()
+                                .map(|stiffness| {
+                                    TensorRank2::from(
+                                        [0, 1, 2].map(|i| [0, 1, 2].map(|j| stiffness[[i, j]])),
+                                    )
+                                })
+                                .collect()
+                        })
+                        .collect::<NodalStiffnessesSolid>()
+                })
+            },
+            NodalCoordinates::from(nodal_coordinates),
+            EqualityConstraint::Fixed(dofs.fixed.clone()),
+        ) {
+            Ok(nodal_coordinates) => Ok(nodal_coordinates.into()),
+            Err(OptimizationError::MaximumStepsReached(..)) => {
+                Err(PyErrGlue::new("The maximum number of steps was reached."))
+            }
+            Err(upstream) => Err(error.into_inner().unwrap_or_else(|| upstream.into())),
+        }
+    }
+    fn equilibrium(
+        &self,
+        py: Python,
+        mut nodal_coordinates: Vec<[Scalar; 3]>,
+        dofs: &Dofs,
+        loads: &[Load],
+        residual: impl Fn(Vec<[Scalar; 3]>) -> Result<Array2<Scalar>, PyErrGlue>,
+        tangent: impl Fn(Vec<[Scalar; 3]>) -> Result<Array4<Scalar>, PyErrGlue>,
+    ) -> Result<(Vec<[Scalar; 3]>, Vec<Scalar>), PyErrGlue> {
+        if dofs.equations.is_empty() {
+            return Ok((
+                self.root(py, nodal_coordinates, dofs, loads, residual, tangent)?,
+                Vec::new(),
+            ));
+        }
+        let free = dofs.free.len();
+        let mut multipliers = vec![0.0; dofs.equations.len()];
+        let columns: Vec<Vec<(usize, Scalar)>> = dofs
+            .equations
+            .iter()
+            .map(|(terms, _)| {
+                terms
+                    .iter()
+                    .filter_map(|&(a, i, coefficient)| {
+                        dofs.index[a][i].map(|column| (column, coefficient))
+                    })
+                    .collect()
+            })
+            .collect();
+        for _ in 0..self.max_steps {
+            let forces = self.forces(py, &nodal_coordinates, loads, &residual)?;
+            let mut rhs: Vec<Scalar> = dofs.free.iter().map(|&(a, i)| -forces[[a, i]]).collect();
+            columns
+                .iter()
+                .zip(multipliers.iter())
+                .for_each(|(column, multiplier)| {
+                    column
+                        .iter()
+                        .for_each(|&(k, coefficient)| rhs[k] -= multiplier * coefficient)
+                });
+            rhs.extend(dofs.equations.iter().map(|(terms, value)| {
+                value
+                    - terms
+                        .iter()
+                        .map(|&(a, i, coefficient)| coefficient * nodal_coordinates[a][i])
+                        .sum::<Scalar>()
+            }));
+            if rhs.iter().map(|f| f * f).sum::<Scalar>().sqrt() < self.abs_tol {
+                return Ok((nodal_coordinates, multipliers));
+            }
+            let stiffnesses = self.stiffnesses(py, &nodal_coordinates, loads, &tangent)?;
+            let mut triplets: Vec<(usize, usize, Scalar)> = stiffnesses
+                .indexed_iter()
+                .filter(|&(_, &stiffness)| stiffness != 0.0)
+                .filter_map(|((a, b, i, j), &stiffness)| {
+                    Some((dofs.index[a][i]?, dofs.index[b][j]?, stiffness))
+                })
+                .collect();
+            columns.iter().enumerate().for_each(|(m, column)| {
+                column.iter().for_each(|&(k, coefficient)| {
+                    triplets.push((k, free + m, coefficient));
+                    triplets.push((free + m, k, coefficient))
+                })
+            });
+            let size = rhs.len();
+            let increments = SparseLuDecomposition::new(&CompressedSparseRows::from_triplets(
+                (size, size),
+                &triplets,
+            ))?
+            .solve(&rhs)?;
+            increments
+                .iter()
+                .zip(dofs.free.iter())
+                .for_each(|(increment, &(a, i))| nodal_coordinates[a][i] += increment);
+            multipliers
+                .iter_mut()
+                .zip(increments[free..].iter())
+                .for_each(|(multiplier, increment)| *multiplier += increment);
+        }
+        Err(PyErrGlue::new("The maximum number of steps was reached."))
+    }
+}
+
+/// Nodal coordinates and load factors along an equilibrium path.
+pub type Path = (Vec<Vec<[Scalar; 3]>>, Vec<Scalar>);
+
+/// Arc-length continuation of nodal equilibrium under proportional loads.
+///
+/// Increments that fail to converge are retried at half the arc length, up to `max_cutbacks` times before the last failure is raised.
+pub struct ArcLength {
+    pub abs_tol: Scalar,
+    pub arc_length: Scalar,
+    pub load_factor_weight: Scalar,
+    pub max_cutbacks: usize,
+    pub max_steps: usize,
+    pub num_increments: usize,
+}
+
+impl ArcLength {
+    pub fn new(arc_length: Scalar, kwargs: Option<&Bound<'_, PyDict>>) -> Result<Self, PyErr> {
+        if arc_length.is_nan() || arc_length <= 0.0 {
+            return Err(PyErrGlue::new("The arc length must be positive.").into());
+        }
+        let mut solver = Self {
+            abs_tol: 1e-10,
+            arc_length,
+            load_factor_weight: 1.0,
+            max_cutbacks: 5,
+            max_steps: 25,
+            num_increments: 10,
+        };
+        if let Some(args) = kwargs {
+            args.into_iter().try_for_each(|(name, value)| {
+                match name.extract()? {
+                    "abs_tol" => solver.abs_tol = value.extract()?,
+                    "load_factor_weight" => solver.load_factor_weight = value.extract()?,
+                    "max_cutbacks" => solver.max_cutbacks = value.extract()?,
+                    "max_steps" => solver.max_steps = value.extract()?,
+                    "num_increments" => solver.num_increments = value.extract()?,
+                    name => {
+                        return Err(PyErrGlue::new(&format!(
+                            "Unexpected keyword argument {name}."
+                        ))
+                        .into());
+                    }
+                };
+                Ok::<(), PyErr>(())
+            })?
+        }
+        Ok(solver)
+    }
+    /// Each iteration factors the stiffness of the free coordinates once and solves it for the residual and the loads,
+    /// so that the load factor correction follows from the linearized arc-length constraint.
+    pub fn trace(
+        &self,
+        py: Python,
+        mut nodal_coordinates: Vec<[Scalar; 3]>,
+        fixed_nodes: &[usize],
+        loads: &[Load],
+        residual: impl Fn(Vec<[Scalar; 3]>) -> Result<Array2<Scalar>, PyErrGlue>,
+        tangent: impl Fn(Vec<[Scalar; 3]>) -> Result<Array4<Scalar>, PyErrGlue>,
+    ) -> Result<Path, PyErrGlue> {
+        let nodes = nodal_coordinates.len();
+        if let Some(node) = fixed_nodes.iter().find(|&&node| node >= nodes) {
+            return Err(PyErrGlue::new(&format!(
+                "Fixed node {node} is out of range for {nodes} nodes."
+            )));
+        }
+        if loads
+            .iter()
+            .any(|load| matches!(load, Load::RigidCylinder(_) | Load::RigidPlane(_)))
+        {
+            return Err(PyErrGlue::new(
+                "Contact loads are not supported by arc-length continuation.",
+            ));
+        }
+        let mut fixed = vec![false; nodes];
+        fixed_nodes.iter().for_each(|&node| fixed[node] = true);
+        let free: Vec<(usize, usize)> = (0..nodes)
+            .filter(|&node| !fixed[node])
+            .flat_map(|node| (0..3).map(move |i| (node, i)))
+            .collect();
+        let mut index = vec![[None; 3]; nodes];
+        free.iter()
+            .enumerate()
+            .for_each(|(k, &(a, i))| index[a][i] = Some(k));
+        let weight = self.load_factor_weight * self.load_factor_weight;
+        let squared_norm = |increments: &[Scalar]| {
+            increments
+                .iter()
+                .enumerate()
+                .map(|(k, d)| {
+                    if k < free.len() {
+                        d * d
+                    } else {
+                        weight * d * d
+                    }
+                })
+                .sum::<Scalar>()
+        };
+        let linearize = |nodal_coordinates: &[[Scalar; 3]], load_factor: Scalar| {
+            let mut stiffnesses = tangent(nodal_coordinates.to_vec())?;
+            loads.iter().try_for_each(|load| {
+                if let Some(load_stiffnesses) = load.stiffnesses(py, nodal_coordinates)? {
+                    stiffnesses.scaled_add(load_factor, &load_stiffnesses)
+                }
+                Ok::<_, PyErrGlue>(())
+            })?;
+            let load_forces = loads
+                .iter()
+                .try_fold(Array2::zeros((nodes, 3)), |forces, load| {
+                    Ok::<_, PyErrGlue>(forces + load.forces(py, nodal_coordinates)?)
+                })?;
+            let forces = residual(nodal_coordinates.to_vec())? + load_factor * &load_forces;
+            let triplets: Vec<(usize, usize, Scalar)> = stiffnesses
+                .indexed_iter()
+                .filter(|&(_, &stiffness)| stiffness != 0.0)
+                .filter_map(|((a, b, i, j), &stiffness)| {
+                    Some((index[a][i]?, index[b][j]?, stiffness))
+                })
+                .collect();
+            let factorization = SparseLuDecomposition::new(&CompressedSparseRows::from_triplets(
+                (free.len(), free.len()),
+                &triplets,
+            ))?;
+            let loading: Vec<Scalar> = free.iter().map(|&(a, i)| -load_forces[[a, i]]).collect();
+            let rhs: Vec<Scalar> = free.iter().map(|&(a, i)| -forces[[a, i]]).collect();
+            Ok::<_, PyErrGlue>((factorization, loading, rhs))
+        };
+        let increment =
+            |previous: &(Vec<[Scalar; 3]>, Scalar), direction: &[Scalar], arc_length: Scalar| {
+                let norm = squared_norm(direction).sqrt();
+                let mut nodal_coordinates = previous.0.clone();
+                free.iter()
+                    .zip(direction.iter())
+                    .for_each(|(&(a, i), d)| nodal_coordinates[a][i] += arc_length * d / norm);
+                let mut load_factor = previous.1 + arc_length * direction[free.len()] / norm;
+                for _ in 0..self.max_steps {
+                    let increments: Vec<Scalar> = free
+                        .iter()
+                        .map(|&(a, i)| nodal_coordinates[a][i] - previous.0[a][i])
+                        .chain([load_factor - previous.1])
+                        .collect();
+                    let constraint = squared_norm(&increments) - arc_length * arc_length;
+                    let (factorization, loading, rhs) = linearize(&nodal_coordinates, load_factor)?;
+                    if rhs.iter().map(|f| f * f).sum::<Scalar>().sqrt() < self.abs_tol
+                        && constraint.abs() < self.abs_tol * arc_length
+                    {
+                        return Ok((nodal_coordinates, load_factor));
+                    }
+                    let residual_corrections = factorization.solve(&rhs)?;
+                    let load_corrections = factorization.solve(&loading)?;
+                    let dot = |corrections: &[Scalar]| {
+                        increments
+                            .iter()
+                            .zip(corrections.iter())
+                            .map(|(d, c)| d * c)
+                            .sum::<Scalar>()
+                    };
+                    let load_factor_correction = -(constraint + 2.0 * dot(&residual_corrections))
+                        / (2.0 * (dot(&load_corrections) + weight * increments[free.len()]));
+                    if !load_factor_correction.is_finite() {
+                        return Err(PyErrGlue::new("The arc-length constraint is singular."));
+                    }
+                    free.iter()
+                        .zip(residual_corrections.iter().zip(load_corrections.iter()))
+                        .for_each(|(&(a, i), (residual, load))| {
+                            nodal_coordinates[a][i] += residual + load_factor_correction * load
+                        });
+                    load_factor += load_factor_correction;
+                }
+                Err(PyErrGlue::new("The maximum number of steps was reached."))
+            };
+        let (factorization, loading, _) = linearize(&nodal_coordinates, 0.0)?;
+        let mut direction = factorization.solve(&loading)?;
+        direction.push(1.0);
+        let mut load_factor = 0.0;
+        let mut path = vec![nodal_coordinates.clone()];
+        let mut load_factors = vec![load_factor];
+        for _ in 0..self.num_increments {
+            let previous = (nodal_coordinates, load_factor);
+            let mut arc_length = self.arc_length;
+            let mut cutbacks = 0;
+            (nodal_coordinates, load_factor) = loop {
+                match increment(&previous, &direction, arc_length) {
+                    Ok(solution) => break solution,
+                    Err(error) if cutbacks == self.max_cutbacks => return Err(error),
+                    Err(_) => {
+                        arc_length *= 0.5;
+                        cutbacks += 1
+                    }
+                }
+            };
+            direction = free
+                .iter()
+                .map(|&(a, i)| nodal_coordinates[a][i] - previous.0[a][i])
+                .chain([load_factor - previous.1])
+                .collect();
+            path.push(nodal_coordinates.clone());
+            load_factors.push(load_factor);
+        }
+        Ok((path, load_factors))
+    }
+}
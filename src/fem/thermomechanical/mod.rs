@@ -0,0 +1,521 @@
+use crate::{
+    PyErrGlue,
+    fem::{
+        Block, Model,
+        block::{
+            self, Fields, Tangent, Tensor,
+            thermal::{Conduction, NODAL_WEIGHT, check_temperatures, element_temperatures},
+        },
+        solve::NewtonRaphson,
+    },
+    math::linalg::sparse::{CompressedSparseRows, SparseLuDecomposition},
+};
+use conspire::mechanics::Scalar;
+use ndarray::{Array2, Array3, Array4};
+use numpy::{PyArray1, PyArray2, PyArray3, PyArray4};
+use pyo3::{prelude::*, types::PyDict};
+
+type State<'py> = (Bound<'py, PyArray2<Scalar>>, Bound<'py, PyArray1<Scalar>>);
+type Couplings<'py> = (Bound<'py, PyArray3<Scalar>>, Bound<'py, PyArray3<Scalar>>);
+
+#[derive(Clone, Copy)]
+enum Dof {
+    Coordinate(usize, usize),
+    Temperature(usize),
+}
+
+struct Linearization {
+    stiffnesses: Array4<Scalar>,
+    thermal_stiffnesses: Array3<Scalar>,
+    couplings: Array3<Scalar>,
+    conductances: Array2<Scalar>,
+}
+
+/// Thermo-mechanical finite element block carrying a nodal temperature field.
+#[pyclass]
+pub struct ThermomechanicalBlock {
+    block: Py<Block>,
+    conduction: Conduction,
+    thermal_expansion_coefficient: Scalar,
+    reference_temperature: Scalar,
+    nodal_temperatures: Vec<Scalar>,
+}
+
+impl ThermomechanicalBlock {
+    fn expansion(&self, temperature: Scalar) -> Scalar {
+        1.0 + self.thermal_expansion_coefficient * (temperature - self.reference_temperature)
+    }
+    fn stress(
+        &self,
+        fields: &dyn Fields,
+        deformation_gradient: &Tensor,
+        temperature: Scalar,
+    ) -> Result<Tensor, PyErrGlue> {
+        let expansion = self.expansion(temperature);
+        let stress = fields.first_piola_kirchhoff_stress(
+            &deformation_gradient.map(|row| row.map(|component| component / expansion)),
+        )?;
+        Ok(stress.map(|row| row.map(|component| expansion * expansion * component)))
+    }
+    /// Returns the tangents of the stress with respect to the deformation gradient and the temperature.
+    ///
+    /// With $\mathbf{P}=\vartheta^2\mathbf{P}_0(\mathbf{F}/\vartheta)$ and $\vartheta=1+\alpha(T-T_0)$, these are $\vartheta\,\mathcal{A}_0$ and $\alpha\vartheta(2\mathbf{P}_0-\mathcal{A}_0:\mathbf{F}/\vartheta)$.
+    fn tangents(
+        &self,
+        fields: &dyn Fields,
+        deformation_gradient: &Tensor,
+        temperature: Scalar,
+    ) -> Result<(Tangent, Tensor), PyErrGlue> {
+        let expansion = self.expansion(temperature);
+        let mechanical = deformation_gradient.map(|row| row.map(|component| component / expansion));
+        let stress = fields.first_piola_kirchhoff_stress(&mechanical)?;
+        let tangent = fields.first_piola_kirchhoff_tangent_stiffness(&mechanical)?;
+        Ok((
+            tangent.map(|i| i.map(|j| j.map(|k| k.map(|component| expansion * component)))),
+            [0, 1, 2].map(|i| {
+                [0, 1, 2].map(|j| {
+                    self.thermal_expansion_coefficient
+                        * expansion
+                        * (2.0 * stress[i][j]
+                            - (0..3)
+                                .flat_map(|k| (0..3).map(move |l| (k, l)))
+                                .map(|(k, l)| tangent[i][j][k][l] * mechanical[k][l])
+                                .sum::<Scalar>())
+                })
+            }),
+        ))
+    }
+    fn stresses(
+        &self,
+        fields: &dyn Fields,
+        deformation_gradients: &[Tensor],
+        nodal_temperatures: &[Scalar],
+    ) -> Result<Vec<Tensor>, PyErrGlue> {
+        deformation_gradients
+            .iter()
+            .zip(element_temperatures(fields.elements(), nodal_temperatures))
+            .map(|(deformation_gradient, temperature)| {
+                self.stress(fields, deformation_gradient, temperature)
+            })
+            .collect()
+    }
+    fn forces(
+        &self,
+        fields: &dyn Fields,
+        nodal_coordinates: &[[Scalar; 3]],
+        nodal_temperatures: &[Scalar],
+    ) -> Result<Array2<Scalar>, PyErrGlue> {
+        let elements = fields.elements();
+        check_temperatures(elements, nodal_temperatures)?;
+        Ok(elements.assemble_forces(&self.stresses(
+            fields,
+            &elements.deformation_gradients(nodal_coordinates)?,
+            nodal_temperatures,
+        )?))
+    }
+    fn fluxes(
+        &self,
+        fields: &dyn Fields,
+        nodal_coordinates: &[[Scalar; 3]],
+        nodal_temperatures: &[Scalar],
+    ) -> Result<Vec<Scalar>, PyErrGlue> {
+        let elements = fields.elements();
+        check_temperatures(elements, nodal_temperatures)?;
+        Ok(self.conduction.nodal_fluxes(
+            elements,
+            &elements.deformation_gradients(nodal_coordinates)?,
+            nodal_temperatures,
+        ))
+    }
+    fn linearize(
+        &self,
+        fields: &dyn Fields,
+        nodal_coordinates: &[[Scalar; 3]],
+        nodal_temperatures: &[Scalar],
+    ) -> Result<Linearization, PyErrGlue> {
+        let elements = fields.elements();
+        check_temperatures(elements, nodal_temperatures)?;
+        let nodes = nodal_coordinates.len();
+        let deformation_gradients = elements.deformation_gradients(nodal_coordinates)?;
+        let temperatures = element_temperatures(elements, nodal_temperatures);
+        let (tangents, thermal_tangents): (Vec<Tangent>, Vec<Tensor>) = deformation_gradients
+            .iter()
+            .zip(temperatures)
+            .map(|(deformation_gradient, temperature)| {
+                self.tangents(fields, deformation_gradient, temperature)
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+        let mut thermal_stiffnesses = Array3::zeros((nodes, nodes, 3));
+        elements
+            .connectivity()
+            .iter()
+            .zip(elements.gradient_vectors().iter())
+            .zip(elements.volumes().iter())
+            .zip(thermal_tangents.iter())
+            .for_each(|(((element, gradient_vectors), volume), thermal_tangent)| {
+                element
+                    .iter()
+                    .zip(gradient_vectors.iter())
+                    .for_each(|(&a, gradient_a)| {
+                        element.iter().for_each(|&b| {
+                            (0..3).for_each(|i| {
+                                thermal_stiffnesses[[a, b, i]] += NODAL_WEIGHT
+                                    * volume
+                                    * (0..3)
+                                        .map(|j| thermal_tangent[i][j] * gradient_a[j])
+                                        .sum::<Scalar>()
+                            })
+                        })
+                    })
+            });
+        Ok(Linearization {
+            stiffnesses: elements.assemble_stiffnesses(&tangents),
+            thermal_stiffnesses,
+            couplings: self.conduction.nodal_couplings(
+                elements,
+                &deformation_gradients,
+                nodal_temperatures,
+            ),
+            conductances: self
+                .conduction
+                .nodal_conductances(elements, &deformation_gradients),
+        })
+    }
+    fn residual(
+        &self,
+        fields: &dyn Fields,
+        nodal_coordinates: &[[Scalar; 3]],
+        nodal_temperatures: &[Scalar],
+        dofs: &[Dof],
+        heat_sources: &[Scalar],
+    ) -> Result<Vec<Scalar>, PyErrGlue> {
+        let forces = self.forces(fields, nodal_coordinates, nodal_temperatures)?;
+        let fluxes = self.fluxes(fields, nodal_coordinates, nodal_temperatures)?;
+        Ok(dofs
+            .iter()
+            .map(|&dof| match dof {
+                Dof::Coordinate(a, i) => -forces[[a, i]],
+                Dof::Temperature(a) => heat_sources[a] - fluxes[a],
+            })
+            .collect())
+    }
+    fn newton(
+        &self,
+        fields: &dyn Fields,
+        solver: &NewtonRaphson,
+        state: (&mut [[Scalar; 3]], &mut [Scalar]),
+        dofs: &[Dof],
+        heat_sources: &[Scalar],
+    ) -> Result<(), PyErrGlue> {
+        let (nodal_coordinates, nodal_temperatures) = state;
+        let mut coordinates = vec![[None; 3]; nodal_coordinates.len()];
+        let mut temperatures = vec![None; nodal_temperatures.len()];
+        dofs.iter().enumerate().for_each(|(k, &dof)| match dof {
+            Dof::Coordinate(a, i) => coordinates[a][i] = Some(k),
+            Dof::Temperature(a) => temperatures[a] = Some(k),
+        });
+        for _ in 0..solver.max_steps {
+            let rhs = self.residual(
+                fields,
+                nodal_coordinates,
+                nodal_temperatures,
+                dofs,
+                heat_sources,
+            )?;
+            if rhs.iter().map(|r| r * r).sum::<Scalar>().sqrt() < solver.abs_tol {
+                return Ok(());
+            }
+            let linearization = self.linearize(fields, nodal_coordinates, nodal_temperatures)?;
+            let entries =
+                linearization
+                    .stiffnesses
+                    .indexed_iter()
+                    .filter_map(|((a, b, i, j), &value)| {
+                        Some((coordinates[a][i]?, coordinates[b][j]?, value))
+                    })
+                    .chain(linearization.thermal_stiffnesses.indexed_iter().filter_map(
+                        |((a, b, i), &value)| Some((coordinates[a][i]?, temperatures[b]?, value)),
+                    ))
+                    .chain(linearization.couplings.indexed_iter().filter_map(
+                        |((a, b, j), &value)| Some((temperatures[a]?, coordinates[b][j]?, value)),
+                    ))
+                    .chain(linearization.conductances.indexed_iter().filter_map(
+                        |((a, b), &value)| Some((temperatures[a]?, temperatures[b]?, value)),
+                    ))
+                    .filter(|&(_, _, value)| value != 0.0)
+                    .collect::<Vec<_>>();
+            SparseLuDecomposition::new(&CompressedSparseRows::from_triplets(
+                (dofs.len(), dofs.len()),
+                &entries,
+            ))?
+            .solve(&rhs)?
+            .into_iter()
+            .zip(dofs.iter())
+            .for_each(|(increment, &dof)| match dof {
+                Dof::Coordinate(a, i) => nodal_coordinates[a][i] += increment,
+                Dof::Temperature(a) => nodal_temperatures[a] += increment,
+            });
+        }
+        Err(PyErrGlue::new("The maximum number of steps was reached."))
+    }
+    fn temperatures_or_current(&self, nodal_temperatures: Option<Vec<Scalar>>) -> Vec<Scalar> {
+        nodal_temperatures.unwrap_or_else(|| self.nodal_temperatures.clone())
+    }
+}
+
+#[pymethods]
+impl ThermomechanicalBlock {
+    #[new]
+    #[pyo3(signature = (model, connectivity, reference_nodal_coordinates, thermal_conductivity, thermal_expansion_coefficient, reference_temperature = 0.0))]
+    fn new(
+        py: Python,
+        model: Model,
+        connectivity: &Bound<'_, PyAny>,
+        reference_nodal_coordinates: &Bound<'_, PyAny>,
+        thermal_conductivity: Scalar,
+        thermal_expansion_coefficient: Scalar,
+        reference_temperature: Scalar,
+    ) -> Result<Self, PyErr> {
+        if let Model::Plasticity(_) = model {
+            return Err(PyErrGlue::new(
+                "Thermomechanical blocks do not support elastic-plastic constitutive models.",
+            )
+            .into());
+        }
+        let reference_nodal_coordinates =
+            block::reference_nodal_coordinates(reference_nodal_coordinates)?;
+        let nodes = reference_nodal_coordinates.len();
+        let connectivity = block::connectivity(connectivity, nodes)?;
+        Ok(Self {
+            block: Py::new(
+                py,
+                Block::new(py, model, connectivity, reference_nodal_coordinates, None)?,
+            )?,
+            conduction: Conduction::new(thermal_conductivity),
+            thermal_expansion_coefficient,
+            reference_temperature,
+            nodal_temperatures: vec![reference_temperature; nodes],
+        })
+    }
+    /// The isothermal mechanical block.
+    #[getter]
+    fn block(&self, py: Python) -> Py<Block> {
+        self.block.clone_ref(py)
+    }
+    /// @private
+    #[getter]
+    fn thermal_conductivity(&self) -> Scalar {
+        self.conduction.thermal_conductivity()
+    }
+    /// @private
+    #[getter]
+    fn thermal_expansion_coefficient(&self) -> Scalar {
+        self.thermal_expansion_coefficient
+    }
+    /// @private
+    #[getter]
+    fn reference_temperature(&self) -> Scalar {
+        self.reference_temperature
+    }
+    /// The nodal temperatures, updated by each solve.
+    #[getter]
+    fn get_nodal_temperatures<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<Scalar>> {
+        PyArray1::from_slice(py, &self.nodal_temperatures)
+    }
+    #[setter]
+    fn set_nodal_temperatures(
+        &mut self,
+        py: Python,
+        nodal_temperatures: Vec<Scalar>,
+    ) -> Result<(), PyErrGlue> {
+        self.block.borrow(py).with_fields(py, |fields| {
+            check_temperatures(fields.elements(), &nodal_temperatures)
+        })?;
+        self.nodal_temperatures = nodal_temperatures;
+        Ok(())
+    }
+    /// $$
+    /// \mathbf{f}_a = \int_\Omega\mathbf{P}\cdot\nabla_0 N_a\,dV,\quad \mathbf{P} = \vartheta^2\,\mathbf{P}_\mathrm{m}\left(\frac{\mathbf{F}}{\vartheta}\right),\quad \vartheta = 1 + \alpha\left(T - T_0\right)
+    /// $$
+    #[pyo3(signature = (nodal_coordinates, nodal_temperatures = None))]
+    fn nodal_forces<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        nodal_temperatures: Option<Vec<Scalar>>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+        let nodal_temperatures = self.temperatures_or_current(nodal_temperatures);
+        Ok(PyArray2::from_owned_array(
+            py,
+            self.block.borrow(py).with_fields(py, |fields| {
+                self.forces(fields, &nodal_coordinates, &nodal_temperatures)
+            })?,
+        ))
+    }
+    /// $$
+    /// r_a = \int_\Omega k\,J\,\nabla_0 N_a\cdot\mathbf{C}^{-1}\cdot\nabla_0 T\,dV
+    /// $$
+    #[pyo3(signature = (nodal_coordinates, nodal_temperatures = None))]
+    fn nodal_fluxes<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        nodal_temperatures: Option<Vec<Scalar>>,
+    ) -> Result<Bound<'py, PyArray1<Scalar>>, PyErrGlue> {
+        let nodal_temperatures = self.temperatures_or_current(nodal_temperatures);
+        Ok(PyArray1::from_vec(
+            py,
+            self.block.borrow(py).with_fields(py, |fields| {
+                self.fluxes(fields, &nodal_coordinates, &nodal_temperatures)
+            })?,
+        ))
+    }
+    /// $$
+    /// \mathbf{K}_{ab} = \frac{\partial\mathbf{f}_a}{\partial\mathbf{x}_b}
+    /// $$
+    #[pyo3(signature = (nodal_coordinates, nodal_temperatures = None))]
+    fn nodal_stiffnesses<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        nodal_temperatures: Option<Vec<Scalar>>,
+    ) -> Result<Bound<'py, PyArray4<Scalar>>, PyErrGlue> {
+        let nodal_temperatures = self.temperatures_or_current(nodal_temperatures);
+        Ok(PyArray4::from_owned_array(
+            py,
+            self.block
+                .borrow(py)
+                .with_fields(py, |fields| {
+                    self.linearize(fields, &nodal_coordinates, &nodal_temperatures)
+                })?
+                .stiffnesses,
+        ))
+    }
+    /// $$
+    /// K_{ab} = \frac{\partial r_a}{\partial T_b}
+    /// $$
+    #[pyo3(signature = (nodal_coordinates, nodal_temperatures = None))]
+    fn nodal_conductances<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        nodal_temperatures: Option<Vec<Scalar>>,
+    ) -> Result<Bound<'py, PyArray2<Scalar>>, PyErrGlue> {
+        let nodal_temperatures = self.temperatures_or_current(nodal_temperatures);
+        Ok(PyArray2::from_owned_array(
+            py,
+            self.block
+                .borrow(py)
+                .with_fields(py, |fields| {
+                    self.linearize(fields, &nodal_coordinates, &nodal_temperatures)
+                })?
+                .conductances,
+        ))
+    }
+    /// $$
+    /// \frac{\partial\mathbf{f}_a}{\partial T_b},\quad \frac{\partial r_a}{\partial\mathbf{x}_b}
+    /// $$
+    #[pyo3(signature = (nodal_coordinates, nodal_temperatures = None))]
+    fn nodal_couplings<'py>(
+        &self,
+        py: Python<'py>,
+        nodal_coordinates: Vec<[Scalar; 3]>,
+        nodal_temperatures: Option<Vec<Scalar>>,
+    ) -> Result<Couplings<'py>, PyErrGlue> {
+        let nodal_temperatures = self.temperatures_or_current(nodal_temperatures);
+        let linearization = self.block.borrow(py).with_fields(py, |fields| {
+            self.linearize(fields, &nodal_coordinates, &nodal_temperatures)
+        })?;
+        Ok((
+            PyArray3::from_owned_array(py, linearization.thermal_stiffnesses),
+            PyArray3::from_owned_array(py, linearization.couplings),
+        ))
+    }
+    /// $$
+    /// \mathbf{f}_a(\mathbf{x}, T) = \mathbf{0}\quad\forall a\notin\mathrm{fixed},\quad r_a(\mathbf{x}, T) = Q_a\quad\forall a\notin\mathrm{fixed}_T
+    /// $$
+    #[pyo3(signature = (nodal_coordinates, fixed_nodes, fixed_temperature_nodes, heat_sources = None, staggered = false, **kwargs))]
+    #[allow(clippy::too_many_arguments)]
+    fn solve<'py>(
+        &mut self,
+        py: Python<'py>,
+        mut nodal_coordinates: Vec<[Scalar; 3]>,
+        fixed_nodes: Vec<usize>,
+        fixed_temperature_nodes: Vec<usize>,
+        heat_sources: Option<Vec<Scalar>>,
+        staggered: bool,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> Result<State<'py>, PyErr> {
+        let solver = NewtonRaphson::from_kwargs(kwargs)?;
+        let nodes = nodal_coordinates.len();
+        let heat_sources = heat_sources.unwrap_or_else(|| vec![0.0; nodes]);
+        let mut nodal_temperatures = self.nodal_temperatures.clone();
+        let block = self.block.borrow(py);
+        block.with_fields(py, |fields| {
+            fields.elements().check_nodes(&nodal_coordinates)?;
+            check_temperatures(fields.elements(), &heat_sources)?;
+            if let Some(node) = fixed_nodes
+                .iter()
+                .chain(fixed_temperature_nodes.iter())
+                .find(|&&node| node >= nodes)
+            {
+                return Err(PyErrGlue::new(&format!(
+                    "Fixed node {node} is out of range for {nodes} nodes."
+                )));
+            }
+            let mechanical: Vec<Dof> = (0..nodes)
+                .filter(|node| !fixed_nodes.contains(node))
+                .flat_map(|node| (0..3).map(move |i| Dof::Coordinate(node, i)))
+                .collect();
+            let thermal: Vec<Dof> = (0..nodes)
+                .filter(|node| !fixed_temperature_nodes.contains(node))
+                .map(Dof::Temperature)
+                .collect();
+            let dofs: Vec<Dof> = mechanical.iter().chain(thermal.iter()).copied().collect();
+            if !staggered {
+                return self.newton(
+                    fields,
+                    &solver,
+                    (&mut nodal_coordinates, &mut nodal_temperatures),
+                    &dofs,
+                    &heat_sources,
+                );
+            }
+            for _ in 0..solver.max_steps {
+                self.newton(
+                    fields,
+                    &solver,
+                    (&mut nodal_coordinates, &mut nodal_temperatures),
+                    &thermal,
+                    &heat_sources,
+                )?;
+                self.newton(
+                    fields,
+                    &solver,
+                    (&mut nodal_coordinates, &mut nodal_temperatures),
+                    &mechanical,
+                    &heat_sources,
+                )?;
+                let residual = self.residual(
+                    fields,
+                    &nodal_coordinates,
+                    &nodal_temperatures,
+                    &dofs,
+                    &heat_sources,
+                )?;
+                if residual.iter().map(|r| r * r).sum::<Scalar>().sqrt() < solver.abs_tol {
+                    return Ok(());
+                }
+            }
+            Err(PyErrGlue::new("The maximum number of steps was reached."))
+        })?;
+        drop(block);
+        self.nodal_temperatures = nodal_temperatures.clone();
+        Ok((
+            PyArray2::from_owned_array(py, Array2::from(nodal_coordinates)),
+            PyArray1::from_vec(py, nodal_temperatures),
+        ))
+    }
+}
@@ -4,8 +4,9 @@ mod math;
 mod physics;
 
 use ::conspire::{
-    constitutive::ConstitutiveError, fem::block::FiniteElementBlockError,
-    math::integrate::IntegrationError,
+    constitutive::ConstitutiveError,
+    fem::block::FiniteElementBlockError,
+    math::{Scalar, integrate::IntegrationError, optimize::OptimizationError},
 };
 use ndarray::ShapeError;
 use numpy::FromVecError;
@@ -30,7 +31,7 @@ fn conspire(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     let submodule_fem = PyModule::new(py, "fem")?;
     submodule_math.setattr(
         "__doc__",
-        "Mathematics library.\n\n - [integrate](math/integrate.html) - Integration and ODEs.\n - [special](math/special.html) - Special functions.",
+        "Mathematics library.\n\n - [integrate](math/integrate.html) - Integration and ODEs.\n - [linalg](math/linalg.html) - Linear algebra.\n - [optimize](math/optimize.html) - Optimization and root finding.\n - [special](math/special.html) - Special functions.",
     )?;
     submodule_physics.setattr(
         "__doc__",
@@ -49,6 +50,7 @@ fn conspire(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     physics::register_module(py, &submodule_physics)?;
     constitutive::register_module(py, &submodule_constitutive)?;
     fem::register_module(&submodule_fem)?;
+    fem::benchmark::register_module(m)?;
     py.import("sys")?
         .getattr("modules")?
         .set_item("conspire.math", submodule_math)?;
@@ -65,19 +67,46 @@ fn conspire(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
 
 struct PyErrGlue {
     message: String,
+    element: Option<(usize, Scalar)>,
 }
 
 impl PyErrGlue {
     fn new(message: &str) -> Self {
         Self {
             message: message.into(),
+            element: None,
+        }
+    }
+    fn inverted_element(element: usize, jacobian: Scalar) -> Self {
+        Self {
+            message: format!("Element {element} is inverted with a Jacobian of {jacobian}."),
+            element: Some((element, jacobian)),
+        }
+    }
+    fn offset(self, elements: usize) -> Self {
+        match self.element {
+            Some((element, jacobian)) => Self::inverted_element(element + elements, jacobian),
+            None => self,
         }
     }
 }
 
 impl From<PyErrGlue> for PyErr {
     fn from(error: PyErrGlue) -> Self {
-        PyTypeError::new_err(error.message)
+        match error.element {
+            Some((element, jacobian)) => Python::attach(|py| {
+                let error = fem::InvertedElementError::new_err(error.message);
+                let value = error.value(py);
+                match value
+                    .setattr("element", element)
+                    .and_then(|_| value.setattr("jacobian", jacobian))
+                {
+                    Ok(()) => error,
+                    Err(error) => error,
+                }
+            }),
+            None => PyTypeError::new_err(error.message),
+        }
     }
 }
 
@@ -85,6 +114,7 @@ impl From<ConstitutiveError> for PyErrGlue {
     fn from(error: ConstitutiveError) -> Self {
         PyErrGlue {
             message: format!("{error:?}\x1B[A"),
+            element: None,
         }
     }
 }
@@ -93,6 +123,16 @@ impl From<IntegrationError> for PyErrGlue {
     fn from(error: IntegrationError) -> Self {
         PyErrGlue {
             message: format!("{error:?}\x1B[A"),
+            element: None,
+        }
+    }
+}
+
+impl From<OptimizationError> for PyErrGlue {
+    fn from(error: OptimizationError) -> Self {
+        PyErrGlue {
+            message: format!("{error:?}\x1B[A"),
+            element: None,
         }
     }
 }
@@ -101,6 +141,7 @@ impl From<FiniteElementBlockError> for PyErrGlue {
     fn from(error: FiniteElementBlockError) -> Self {
         PyErrGlue {
             message: format!("{error:?}\x1B[A"),
+            element: None,
         }
     }
 }
@@ -109,6 +150,7 @@ impl From<ShapeError> for PyErrGlue {
     fn from(error: ShapeError) -> Self {
         PyErrGlue {
             message: error.to_string(),
+            element: None,
         }
     }
 }
@@ -117,6 +159,7 @@ impl From<FromVecError> for PyErrGlue {
     fn from(error: FromVecError) -> Self {
         PyErrGlue {
             message: error.to_string(),
+            element: None,
         }
     }
 }
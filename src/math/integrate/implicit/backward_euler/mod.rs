@@ -0,0 +1,132 @@
+use crate::{PyErrGlue, math::linalg::check_length};
+use conspire::math::{
+    Matrix, Scalar, SquareMatrix, Tensor, Vector,
+    integrate::{self, ImplicitZerothOrder, IntegrationError},
+    optimize::{EqualityConstraint, NewtonRaphson, OptimizationError, ZerothOrderRootFinding},
+};
+use pyo3::{
+    prelude::*,
+    types::{PyDict, PyFunction},
+};
+
+/// Newton-Raphson iterations with a forward difference Jacobian, which solve the implicit steps for vectors of any length.
+struct FiniteDifferenceNewton<'a>(&'a NewtonRaphson);
+
+impl ZerothOrderRootFinding<Vector> for FiniteDifferenceNewton<'_> {
+    fn root(
+        &self,
+        mut function: impl FnMut(&Vector) -> Result<Vector, String>,
+        initial_guess: Vector,
+        _equality_constraint: EqualityConstraint,
+    ) -> Result<Vector, OptimizationError> {
+        let mut solution = initial_guess;
+        let mut perturbed = solution.clone();
+        for _ in 0..=self.0.max_steps {
+            let residual = function(&solution)?;
+            if residual.norm_inf() < self.0.abs_tol {
+                return Ok(solution);
+            }
+            let mut columns = Vec::with_capacity(solution.len());
+            for j in 0..solution.len() {
+                let increment = Scalar::EPSILON.sqrt() * solution[j].abs().max(1.0);
+                perturbed[j] = solution[j] + increment;
+                columns.push((function(&perturbed)? - &residual) / increment);
+                perturbed[j] = solution[j];
+            }
+            let jacobian: SquareMatrix = (0..solution.len())
+                .map(|i| columns.iter().map(|column| column[i]).collect())
+                .collect();
+            solution -= jacobian.solve_lu(&residual)?;
+            perturbed = solution.clone();
+        }
+        Err(OptimizationError::MaximumStepsReached(
+            self.0.max_steps,
+            format!("{:?}", self.0),
+        ))
+    }
+}
+
+#[doc = include_str!("doc.md")]
+#[pyclass]
+pub struct BackwardEuler {
+    dt: Scalar,
+    solver: NewtonRaphson,
+}
+
+#[pymethods]
+impl BackwardEuler {
+    #[new]
+    #[pyo3(signature = (**kwargs))]
+    fn new(kwargs: Option<&Bound<'_, PyDict>>) -> Result<Self, PyErr> {
+        let mut integrator = Self {
+            dt: 0.0,
+            solver: NewtonRaphson::default(),
+        };
+        if let Some(args) = kwargs {
+            args.into_iter().try_for_each(|(name, value)| {
+                match name.extract()? {
+                    "dt" => integrator.dt = value.extract()?,
+                    "abs_tol" => integrator.solver.abs_tol = value.extract()?,
+                    "max_steps" => integrator.solver.max_steps = value.extract()?,
+                    _ => (),
+                };
+                Ok::<(), PyErr>(())
+            })?
+        }
+        Ok(integrator)
+    }
+    /// @private
+    #[getter]
+    pub fn dt(&self) -> Scalar {
+        self.dt
+    }
+    /// @private
+    #[getter]
+    pub fn abs_tol(&self) -> Scalar {
+        self.solver.abs_tol
+    }
+    /// @private
+    #[getter]
+    pub fn max_steps(&self) -> usize {
+        self.solver.max_steps
+    }
+    /// Integrates $dy/dt = f(t, y)$ from the initial condition, returning the times, solutions, and derivatives.
+    ///
+    /// If only the initial and final times are given, steps of size `dt` are taken between them, and otherwise a step is taken to each of the given times.
+    /// The implicit steps are solved using Newton-Raphson iterations to within `abs_tol`, with the Jacobian approximated by finite differences.
+    fn integrate(
+        &self,
+        py: Python,
+        function: Py<PyFunction>,
+        time: Vec<Scalar>,
+        initial_condition: Vec<Scalar>,
+    ) -> Result<super::Solution, PyErrGlue> {
+        let time = match time.as_slice() {
+            [] => return Err(IntegrationError::LengthTimeLessThanTwo.into()),
+            &[t_0, t_f] if self.dt > 0.0 && t_0 < t_f => {
+                let steps = ((t_f - t_0) / self.dt).ceil() as usize;
+                (0..steps)
+                    .map(|step| t_0 + step as Scalar * self.dt)
+                    .chain([t_f])
+                    .collect()
+            }
+            _ => time,
+        };
+        let size = initial_condition.len();
+        let (t, y, dydt): (Vector, Matrix, Matrix) = ImplicitZerothOrder::integrate(
+            &integrate::BackwardEuler::default(),
+            |t: Scalar, y: &Vector| {
+                let dydt: Vec<Scalar> = function
+                    .call1(py, (t, Vec::from(y.clone())))
+                    .and_then(|val| val.extract(py))
+                    .map_err(|e| e.to_string())?;
+                check_length(size, &dydt).map_err(|error| error.message)?;
+                Ok(Vector::from(dydt))
+            },
+            &time,
+            Vector::from(initial_condition),
+            FiniteDifferenceNewton(&self.solver),
+        )?;
+        Ok((t.into(), y.into(), dydt.into()))
+    }
+}
@@ -0,0 +1,172 @@
+use super::{Stepper, Trial};
+use std::collections::VecDeque;
+
+const MAX_ORDER: usize = 5;
+const MAX_RATIO: Scalar = 2.0;
+
+/// Weights of the values at the nodes for the derivative at the first node of their interpolating polynomial.
+fn derivative_weights(nodes: &[Scalar]) -> Vec<Scalar> {
+    let first = nodes[0];
+    (0..nodes.len())
+        .map(|j| {
+            if j == 0 {
+                nodes[1..].iter().map(|node| 1.0 / (first - node)).sum()
+            } else {
+                (1..nodes.len())
+                    .filter(|&m| m != j)
+                    .map(|m| first - nodes[m])
+                    .product::<Scalar>()
+                    / (0..nodes.len())
+                        .filter(|&m| m != j)
+                        .map(|m| nodes[j] - nodes[m])
+                        .product::<Scalar>()
+            }
+        })
+        .collect()
+}
+
+/// Weights of the values at the nodes for their interpolating polynomial at the target.
+fn extrapolation_weights(target: Scalar, nodes: &[Scalar]) -> Vec<Scalar> {
+    (0..nodes.len())
+        .map(|j| {
+            (0..nodes.len())
+                .filter(|&m| m != j)
+                .map(|m| (target - nodes[m]) / (nodes[j] - nodes[m]))
+                .product()
+        })
+        .collect()
+}
+
+/// Variable-order, variable-coefficient BDF step using the most recent accepted solutions.
+pub struct BdfStep {
+    history: VecDeque<(Scalar, Vec<Scalar>)>,
+    order: usize,
+    next_order: usize,
+    steps: usize,
+}
+
+impl Default for BdfStep {
+    fn default() -> Self {
+        Self {
+            history: VecDeque::with_capacity(MAX_ORDER + 2),
+            order: 1,
+            next_order: 1,
+            steps: 0,
+        }
+    }
+}
+
+impl BdfStep {
+    /// Extrapolates the most recent solutions to the given time using a polynomial of the given degree.
+    fn predict(&self, t: Scalar, degree: usize) -> Vec<Scalar> {
+        let points = self.history.range(..=degree);
+        let nodes: Vec<Scalar> = points.clone().map(|(t, _)| *t).collect();
+        let mut prediction = vec![0.0; self.history[0].1.len()];
+        points
+            .zip(extrapolation_weights(t, &nodes))
+            .for_each(|((_, y), weight)| {
+                prediction
+                    .iter_mut()
+                    .zip(y)
+                    .for_each(|(p, y)| *p += weight * y)
+            });
+        prediction
+    }
+    /// Scaled local error for the given order from the difference between the solution and its prediction.
+    fn error(&self, settings: &Settings, t: Scalar, solution: &[Scalar], order: usize) -> Scalar {
+        let scale = (t - self.history[0].0) / (t - self.history[order].0);
+        let difference: Vec<Scalar> = solution
+            .iter()
+            .zip(self.predict(t, order))
+            .map(|(y, p)| scale * (y - p))
+            .collect();
+        settings.norm(&difference, solution)
+    }
+}
+
+impl Stepper for BdfStep {
+    fn attempt(
+        &mut self,
+        system: &System,
+        settings: &Settings,
+        t: Scalar,
+        y: &[Scalar],
+        dydt: &[Scalar],
+        dt: Scalar,
+    ) -> PyResult<Option<Trial>> {
+        let order = self.order;
+        let time = t + dt;
+        let nodes: Vec<Scalar> = [time]
+            .into_iter()
+            .chain(self.history.range(..order).map(|(t, _)| *t))
+            .collect();
+        let weights = derivative_weights(&nodes);
+        let coefficient = 1.0 / weights[0];
+        let mut rhs = vec![0.0; y.len()];
+        self.history
+            .range(..order)
+            .zip(&weights[1..])
+            .for_each(|((_, y), weight)| {
+                rhs.iter_mut()
+                    .zip(y)
+                    .for_each(|(r, y)| *r -= coefficient * weight * y)
+            });
+        let euler: Vec<Scalar> = y.iter().zip(dydt).map(|(y, f)| y + dt * f).collect();
+        let starting = self.history.len() <= order;
+        let guess = if starting {
+            euler.clone()
+        } else {
+            self.predict(time, order)
+        };
+        let matrix = system.iteration_matrix(coefficient, t, y, dydt)?;
+        let Some(solution) = system.newton(settings, &matrix, coefficient, time, &rhs, guess)?
+        else {
+            return Ok(None);
+        };
+        let error = if starting {
+            let difference: Vec<Scalar> = solution
+                .iter()
+                .zip(&euler)
+                .map(|(y, p)| 0.5 * (y - p))
+                .collect();
+            settings.norm(&difference, &solution)
+        } else {
+            self.error(settings, time, &solution, order)
+        };
+        let mut factor = settings.factor(error, order);
+        self.next_order = order;
+        if error <= 1.0 && self.steps > order {
+            if order > 1 {
+                let lower =
+                    settings.factor(self.error(settings, time, &solution, order - 1), order - 1);
+                if lower > factor {
+                    (factor, self.next_order) = (lower, order - 1);
+                }
+            }
+            if order < MAX_ORDER && self.history.len() > order + 1 {
+                let higher =
+                    settings.factor(self.error(settings, time, &solution, order + 1), order + 1);
+                if higher > factor {
+                    (factor, self.next_order) = (higher, order + 1);
+                }
+            }
+        }
+        Ok(Some(Trial {
+            solution,
+            error,
+            factor: factor.min(MAX_RATIO),
+        }))
+    }
+    fn accept(&mut self, t: Scalar, y: &[Scalar]) {
+        self.history.push_front((t, y.to_vec()));
+        self.history.truncate(MAX_ORDER + 2);
+        if self.next_order == self.order {
+            self.steps += 1;
+        } else {
+            self.order = self.next_order;
+            self.steps = 1;
+        }
+    }
+}
+
+super::implicit!(Bdf, "BDF", BdfStep::default());
@@ -0,0 +1,323 @@
+pub mod backward_euler;
+pub mod bdf;
+pub mod sdirk_4;
+
+use crate::{
+    PyErrGlue,
+    math::linalg::{check_length, check_matrix},
+};
+use conspire::math::{Scalar, SquareMatrix, Vector};
+use pyo3::{
+    prelude::*,
+    types::{PyDict, PyFunction},
+};
+
+const MAX_FACTOR: Scalar = 5.0;
+const MIN_FACTOR: Scalar = 0.1;
+const NEWTON_STEPS: usize = 10;
+const NEWTON_TOL: Scalar = 1e-2;
+
+/// Tolerances and step size controls shared by the implicit integrators.
+pub struct Settings {
+    pub abs_tol: Scalar,
+    pub rel_tol: Scalar,
+    pub dt_beta: Scalar,
+    pub dt_cut: Scalar,
+    pub dt_min: Scalar,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            abs_tol: 1e-6,
+            rel_tol: 1e-6,
+            dt_beta: 0.9,
+            dt_cut: 0.5,
+            dt_min: 1e-12,
+        }
+    }
+}
+
+impl Settings {
+    pub fn from_kwargs(kwargs: Option<&Bound<'_, PyDict>>) -> Result<Self, PyErr> {
+        let mut settings = Self::default();
+        if let Some(args) = kwargs {
+            args.into_iter().try_for_each(|(name, value)| {
+                match name.extract()? {
+                    "abs_tol" => settings.abs_tol = value.extract()?,
+                    "rel_tol" => settings.rel_tol = value.extract()?,
+                    "dt_beta" => settings.dt_beta = value.extract()?,
+                    "dt_cut" => settings.dt_cut = value.extract()?,
+                    "dt_min" => settings.dt_min = value.extract()?,
+                    _ => (),
+                };
+                Ok::<(), PyErr>(())
+            })?
+        }
+        Ok(settings)
+    }
+    /// Maximum norm of the vector with each component scaled by the tolerances at the given solution.
+    fn norm(&self, vector: &[Scalar], solution: &[Scalar]) -> Scalar {
+        vector
+            .iter()
+            .zip(solution)
+            .map(|(v, y)| v.abs() / (self.abs_tol + self.rel_tol * y.abs()))
+            .fold(0.0, Scalar::max)
+    }
+    /// Factor for the next step size given the scaled error and the order of the error estimate.
+    fn factor(&self, error: Scalar, order: usize) -> Scalar {
+        self.dt_beta * error.powf(-1.0 / (order + 1) as Scalar)
+    }
+}
+
+/// Right-hand side of the system of ODEs and optionally its Jacobian, evaluated by calling Python.
+pub struct System<'py> {
+    py: Python<'py>,
+    function: Py<PyFunction>,
+    jacobian: Option<Py<PyFunction>>,
+}
+
+impl<'py> System<'py> {
+    pub fn new(
+        py: Python<'py>,
+        function: Py<PyFunction>,
+        jacobian: Option<Py<PyFunction>>,
+    ) -> Self {
+        Self {
+            py,
+            function,
+            jacobian,
+        }
+    }
+    fn evaluate(&self, t: Scalar, y: &[Scalar]) -> PyResult<Vec<Scalar>> {
+        let dydt: Vec<Scalar> = self
+            .function
+            .call1(self.py, (t, y.to_vec()))?
+            .extract(self.py)?;
+        check_length(y.len(), &dydt)?;
+        Ok(dydt)
+    }
+    /// Jacobian from the given callable, or otherwise approximated by forward finite differences.
+    fn jacobian(&self, t: Scalar, y: &[Scalar], dydt: &[Scalar]) -> PyResult<Vec<Vec<Scalar>>> {
+        Ok(match &self.jacobian {
+            Some(jacobian) => {
+                let jacobian: Vec<Vec<Scalar>> =
+                    jacobian.call1(self.py, (t, y.to_vec()))?.extract(self.py)?;
+                check_matrix(y.len(), &jacobian)?;
+                jacobian
+            }
+            None => {
+                let mut jacobian = vec![vec![0.0; y.len()]; y.len()];
+                let mut perturbed = y.to_vec();
+                for j in 0..y.len() {
+                    let increment = Scalar::EPSILON.sqrt() * y[j].abs().max(1.0);
+                    perturbed[j] = y[j] + increment;
+                    self.evaluate(t, &perturbed)?
+                        .iter()
+                        .zip(dydt)
+                        .zip(jacobian.iter_mut())
+                        .for_each(|((f, f_0), row)| row[j] = (f - f_0) / increment);
+                    perturbed[j] = y[j];
+                }
+                jacobian
+            }
+        })
+    }
+    /// Forms $\mathbf{1} - c\,\mathbf{J}$ with the Jacobian at the given state.
+    fn iteration_matrix(
+        &self,
+        coefficient: Scalar,
+        t: Scalar,
+        y: &[Scalar],
+        dydt: &[Scalar],
+    ) -> PyResult<SquareMatrix> {
+        let mut matrix = self.jacobian(t, y, dydt)?;
+        matrix.iter_mut().enumerate().for_each(|(i, row)| {
+            row.iter_mut().for_each(|entry| *entry *= -coefficient);
+            row[i] += 1.0;
+        });
+        Ok(matrix.into_iter().map(Vector::from).collect())
+    }
+    /// Solves $\mathbf{y} - c\,\mathbf{f}(t, \mathbf{y}) = \mathbf{r}$ using simplified Newton iterations, or returns nothing if they do not converge or the iteration matrix is singular.
+    fn newton(
+        &self,
+        settings: &Settings,
+        matrix: &SquareMatrix,
+        coefficient: Scalar,
+        t: Scalar,
+        rhs: &[Scalar],
+        mut y: Vec<Scalar>,
+    ) -> PyResult<Option<Vec<Scalar>>> {
+        for _ in 0..NEWTON_STEPS {
+            let residual: Vec<Scalar> = self
+                .evaluate(t, &y)?
+                .iter()
+                .zip(rhs.iter().zip(&y))
+                .map(|(f, (r, y))| r + coefficient * f - y)
+                .collect();
+            let Ok(increment) = matrix.solve_lu(&Vector::from(residual)) else {
+                return Ok(None);
+            };
+            let increment = Vec::from(increment);
+            y.iter_mut().zip(&increment).for_each(|(y, dy)| *y += dy);
+            let norm = settings.norm(&increment, &y);
+            if !norm.is_finite() {
+                return Ok(None);
+            } else if norm <= NEWTON_TOL {
+                return Ok(Some(y));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Attempted step with its scaled error and the factor for the next step size.
+pub struct Trial {
+    solution: Vec<Scalar>,
+    error: Scalar,
+    factor: Scalar,
+}
+
+pub trait Stepper {
+    /// Attempts a step from the given state, or returns nothing if the nonlinear solve failed.
+    fn attempt(
+        &mut self,
+        system: &System,
+        settings: &Settings,
+        t: Scalar,
+        y: &[Scalar],
+        dydt: &[Scalar],
+        dt: Scalar,
+    ) -> PyResult<Option<Trial>>;
+    /// Records an accepted state.
+    fn accept(&mut self, _t: Scalar, _y: &[Scalar]) {}
+}
+
+pub type Solution = (Vec<Scalar>, Vec<Vec<Scalar>>, Vec<Vec<Scalar>>);
+
+/// Integrates adaptively over the time interval, returning every accepted step if only the initial and final times are given, or otherwise stepping exactly to each of the given times.
+pub fn integrate(
+    system: System,
+    settings: &Settings,
+    mut stepper: impl Stepper,
+    time: Vec<Scalar>,
+    initial_condition: Vec<Scalar>,
+) -> PyResult<Solution> {
+    if time.len() < 2 {
+        return Err(PyErrGlue::new("The time must contain at least two entries.").into());
+    } else if time.windows(2).any(|times| times[0] >= times[1]) {
+        return Err(PyErrGlue::new("The times must be strictly increasing.").into());
+    }
+    let dense = time.len() == 2;
+    let mut t = time[0];
+    let mut y = initial_condition;
+    let mut dydt = system.evaluate(t, &y)?;
+    let mut output = (vec![t], vec![y.clone()], vec![dydt.clone()]);
+    stepper.accept(t, &y);
+    let (size, rate) = (settings.norm(&y, &y), settings.norm(&dydt, &y));
+    let mut dt = if size < 1e-5 || rate < 1e-5 {
+        1e-6
+    } else {
+        0.01 * size / rate
+    };
+    for &target in &time[1..] {
+        while t < target {
+            let last = dt >= target - t;
+            let step = if last { target - t } else { dt };
+            match stepper.attempt(&system, settings, t, &y, &dydt, step)? {
+                Some(trial) if trial.error <= 1.0 => {
+                    t = if last { target } else { t + step };
+                    y = trial.solution;
+                    dydt = system.evaluate(t, &y)?;
+                    stepper.accept(t, &y);
+                    dt = step * trial.factor.clamp(MIN_FACTOR, MAX_FACTOR);
+                    if dense {
+                        output.0.push(t);
+                        output.1.push(y.clone());
+                        output.2.push(dydt.clone());
+                    }
+                }
+                Some(trial) => dt = step * trial.factor.clamp(MIN_FACTOR, 1.0),
+                None => dt = step * settings.dt_cut,
+            }
+            if dt < settings.dt_min {
+                return Err(PyErrGlue::new("The minimum step size was reached.").into());
+            }
+        }
+        if !dense {
+            output.0.push(t);
+            output.1.push(y.clone());
+            output.2.push(dydt.clone());
+        }
+    }
+    Ok(output)
+}
+
+macro_rules! implicit {
+    ($method: ident, $name: literal, $stepper: expr) => {
+        use super::{Settings, Solution, System};
+        use conspire::math::Scalar;
+        use pyo3::{
+            prelude::*,
+            types::{PyDict, PyFunction},
+        };
+        #[doc = include_str!("doc.md")]
+        #[pyclass(name = $name)]
+        pub struct $method(Settings);
+        #[pymethods]
+        impl $method {
+            #[new]
+            #[pyo3(signature = (**kwargs))]
+            fn new(kwargs: Option<&Bound<'_, PyDict>>) -> Result<Self, PyErr> {
+                Ok(Self(Settings::from_kwargs(kwargs)?))
+            }
+            /// @private
+            #[getter]
+            pub fn abs_tol(&self) -> Scalar {
+                self.0.abs_tol
+            }
+            /// @private
+            #[getter]
+            pub fn rel_tol(&self) -> Scalar {
+                self.0.rel_tol
+            }
+            /// @private
+            #[getter]
+            pub fn dt_beta(&self) -> Scalar {
+                self.0.dt_beta
+            }
+            /// @private
+            #[getter]
+            pub fn dt_cut(&self) -> Scalar {
+                self.0.dt_cut
+            }
+            /// @private
+            #[getter]
+            pub fn dt_min(&self) -> Scalar {
+                self.0.dt_min
+            }
+            #[doc = include_str!("../doc.md")]
+            ///
+            /// If only the initial and final times are given, every accepted step is returned, and otherwise the solution is returned at each of the given times.
+            /// The Jacobian is approximated by finite differences unless it is given.
+            #[pyo3(signature = (function, time, initial_condition, jacobian = None))]
+            fn integrate(
+                &self,
+                py: Python,
+                function: Py<PyFunction>,
+                time: Vec<Scalar>,
+                initial_condition: Vec<Scalar>,
+                jacobian: Option<Py<PyFunction>>,
+            ) -> PyResult<Solution> {
+                super::integrate(
+                    System::new(py, function, jacobian),
+                    &self.0,
+                    $stepper,
+                    time,
+                    initial_condition,
+                )
+            }
+        }
+    };
+}
+pub(crate) use implicit;
@@ -0,0 +1,85 @@
+use super::{Stepper, Trial, Vector};
+
+const GAMMA: Scalar = 0.25;
+const C: [Scalar; 5] = [0.25, 0.75, 11.0 / 20.0, 0.5, 1.0];
+const A: [[Scalar; 4]; 5] = [
+    [0.0, 0.0, 0.0, 0.0],
+    [0.5, 0.0, 0.0, 0.0],
+    [17.0 / 50.0, -1.0 / 25.0, 0.0, 0.0],
+    [371.0 / 1360.0, -137.0 / 2720.0, 15.0 / 544.0, 0.0],
+    [25.0 / 24.0, -49.0 / 48.0, 125.0 / 16.0, -85.0 / 12.0],
+];
+const B: [Scalar; 5] = [25.0 / 24.0, -49.0 / 48.0, 125.0 / 16.0, -85.0 / 12.0, 0.25];
+const B_HAT: [Scalar; 5] = [59.0 / 48.0, -17.0 / 96.0, 225.0 / 32.0, -85.0 / 12.0, 0.0];
+
+/// Stiffly accurate five-stage SDIRK step with an embedded third-order solution for the local error.
+pub struct Sdirk4Step;
+
+impl Stepper for Sdirk4Step {
+    fn attempt(
+        &mut self,
+        system: &System,
+        settings: &Settings,
+        t: Scalar,
+        y: &[Scalar],
+        dydt: &[Scalar],
+        dt: Scalar,
+    ) -> PyResult<Option<Trial>> {
+        let coefficient = GAMMA * dt;
+        let matrix = system.iteration_matrix(coefficient, t, y, dydt)?;
+        let mut slopes: Vec<Vec<Scalar>> = Vec::with_capacity(C.len());
+        let mut solution = y.to_vec();
+        for (c, a) in C.iter().zip(A.iter()) {
+            let rhs: Vec<Scalar> = y
+                .iter()
+                .enumerate()
+                .map(|(i, y)| {
+                    y + dt
+                        * slopes
+                            .iter()
+                            .zip(a)
+                            .map(|(slope, a)| a * slope[i])
+                            .sum::<Scalar>()
+                })
+                .collect();
+            let guess = rhs
+                .iter()
+                .zip(slopes.last().map_or(dydt, Vec::as_slice))
+                .map(|(r, k)| r + coefficient * k)
+                .collect();
+            let Some(stage) =
+                system.newton(settings, &matrix, coefficient, t + c * dt, &rhs, guess)?
+            else {
+                return Ok(None);
+            };
+            slopes.push(
+                stage
+                    .iter()
+                    .zip(&rhs)
+                    .map(|(y, r)| (y - r) / coefficient)
+                    .collect(),
+            );
+            solution = stage;
+        }
+        let estimate: Vec<Scalar> = (0..y.len())
+            .map(|i| {
+                dt * slopes
+                    .iter()
+                    .zip(B.iter().zip(&B_HAT))
+                    .map(|(slope, (b, b_hat))| (b - b_hat) * slope[i])
+                    .sum::<Scalar>()
+            })
+            .collect();
+        let Ok(filtered) = matrix.solve_lu(&Vector::from(estimate)) else {
+            return Ok(None);
+        };
+        let error = settings.norm(&Vec::from(filtered), &solution);
+        Ok(Some(Trial {
+            solution,
+            error,
+            factor: settings.factor(error, 3),
+        }))
+    }
+}
+
+super::implicit!(Sdirk4, "SDIRK4", Sdirk4Step);
@@ -1,4 +1,5 @@
 pub mod explicit;
+pub mod implicit;
 
 use pyo3::prelude::*;
 
@@ -6,5 +7,8 @@ pub fn register_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<explicit::bogacki_shampine::BogackiShampine>()?;
     m.add_class::<explicit::dormand_prince::DormandPrince>()?;
     m.add_class::<explicit::verner_8::Verner8>()?;
-    m.add_class::<explicit::verner_9::Verner9>()
+    m.add_class::<explicit::verner_9::Verner9>()?;
+    m.add_class::<implicit::backward_euler::BackwardEuler>()?;
+    m.add_class::<implicit::bdf::Bdf>()?;
+    m.add_class::<implicit::sdirk_4::Sdirk4>()
 }
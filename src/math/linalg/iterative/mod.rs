@@ -0,0 +1,172 @@
+use super::{dot, sparse::CompressedSparseRows};
+use crate::PyErrGlue;
+use conspire::math::Scalar;
+
+/// Diagonal preconditioner applied as its inverse.
+pub enum Preconditioner {
+    Identity,
+    Jacobi(Vec<Scalar>),
+}
+
+impl Preconditioner {
+    pub fn new(matrix: &CompressedSparseRows, name: Option<&str>) -> Result<Self, PyErrGlue> {
+        match name {
+            None => Ok(Self::Identity),
+            Some("jacobi") => Ok(Self::Jacobi(
+                matrix
+                    .diagonal()
+                    .into_iter()
+                    .map(|entry| if entry != 0.0 { 1.0 / entry } else { 1.0 })
+                    .collect(),
+            )),
+            Some(name) => Err(PyErrGlue::new(&format!("Unknown preconditioner {name}."))),
+        }
+    }
+    fn apply(&self, vector: &[Scalar]) -> Vec<Scalar> {
+        match self {
+            Self::Identity => vector.to_vec(),
+            Self::Jacobi(inverse) => vector.iter().zip(inverse).map(|(v, d)| v * d).collect(),
+        }
+    }
+}
+
+fn residual(matrix: &CompressedSparseRows, rhs: &[Scalar], solution: &[Scalar]) -> Vec<Scalar> {
+    rhs.iter()
+        .zip(matrix.product(solution))
+        .map(|(b, a_x)| b - a_x)
+        .collect()
+}
+
+/// Solves a symmetric positive definite system using the preconditioned conjugate gradient method, until the residual is within the tolerance relative to the right-hand side.
+pub fn conjugate_gradient(
+    matrix: &CompressedSparseRows,
+    rhs: &[Scalar],
+    mut solution: Vec<Scalar>,
+    preconditioner: &Preconditioner,
+    tolerance: Scalar,
+    max_iterations: usize,
+) -> Result<Vec<Scalar>, PyErrGlue> {
+    let target = tolerance * dot(rhs, rhs).sqrt();
+    let mut residual = residual(matrix, rhs, &solution);
+    let mut preconditioned = preconditioner.apply(&residual);
+    let mut direction = preconditioned.clone();
+    let mut product = dot(&residual, &preconditioned);
+    for _ in 0..=max_iterations {
+        if dot(&residual, &residual).sqrt() <= target {
+            return Ok(solution);
+        }
+        let image = matrix.product(&direction);
+        let curvature = dot(&direction, &image);
+        if curvature.is_nan() || curvature <= 0.0 {
+            return Err(PyErrGlue::new("The matrix is not positive definite."));
+        }
+        let step = product / curvature;
+        solution
+            .iter_mut()
+            .zip(&direction)
+            .for_each(|(x, p)| *x += step * p);
+        residual
+            .iter_mut()
+            .zip(&image)
+            .for_each(|(r, q)| *r -= step * q);
+        preconditioned = preconditioner.apply(&residual);
+        let next = dot(&residual, &preconditioned);
+        direction
+            .iter_mut()
+            .zip(&preconditioned)
+            .for_each(|(p, z)| *p = z + next / product * *p);
+        product = next;
+    }
+    Err(PyErrGlue::new(
+        "The conjugate gradient method did not converge.",
+    ))
+}
+
+/// Solves a general system using the restarted generalized minimal residual method with right preconditioning, until the residual is within the tolerance relative to the right-hand side.
+pub fn gmres(
+    matrix: &CompressedSparseRows,
+    rhs: &[Scalar],
+    mut solution: Vec<Scalar>,
+    preconditioner: &Preconditioner,
+    tolerance: Scalar,
+    max_iterations: usize,
+    restart: usize,
+) -> Result<Vec<Scalar>, PyErrGlue> {
+    let target = tolerance * dot(rhs, rhs).sqrt();
+    let mut iterations = 0;
+    loop {
+        let residual = residual(matrix, rhs, &solution);
+        let norm = dot(&residual, &residual).sqrt();
+        if norm <= target {
+            return Ok(solution);
+        }
+        if iterations >= max_iterations {
+            return Err(PyErrGlue::new("GMRES did not converge."));
+        }
+        let mut basis = vec![residual.iter().map(|r| r / norm).collect::<Vec<_>>()];
+        let mut hessenberg: Vec<Vec<Scalar>> = Vec::with_capacity(restart);
+        let mut rotations: Vec<(Scalar, Scalar)> = Vec::with_capacity(restart);
+        let mut projection = vec![norm];
+        while hessenberg.len() < restart && iterations < max_iterations {
+            let k = hessenberg.len();
+            let mut vector = matrix.product(&preconditioner.apply(&basis[k]));
+            let mut column: Vec<Scalar> = basis
+                .iter()
+                .map(|basis_vector| {
+                    let coefficient = dot(&vector, basis_vector);
+                    vector
+                        .iter_mut()
+                        .zip(basis_vector)
+                        .for_each(|(w, v)| *w -= coefficient * v);
+                    coefficient
+                })
+                .collect();
+            let subdiagonal = dot(&vector, &vector).sqrt();
+            rotations.iter().enumerate().for_each(|(i, &(c, s))| {
+                let (upper, lower) = (column[i], column[i + 1]);
+                column[i] = c * upper + s * lower;
+                column[i + 1] = c * lower - s * upper;
+            });
+            let radius = column[k].hypot(subdiagonal);
+            let (c, s) = if radius > 0.0 {
+                (column[k] / radius, subdiagonal / radius)
+            } else {
+                (1.0, 0.0)
+            };
+            column[k] = radius;
+            rotations.push((c, s));
+            projection.push(-s * projection[k]);
+            projection[k] *= c;
+            hessenberg.push(column);
+            iterations += 1;
+            if projection[k + 1].abs() <= target || subdiagonal == 0.0 {
+                break;
+            }
+            basis.push(vector.iter().map(|w| w / subdiagonal).collect());
+        }
+        let size = hessenberg.len();
+        let mut coefficients = projection[..size].to_vec();
+        for i in (0..size).rev() {
+            if hessenberg[i][i] == 0.0 {
+                return Err(PyErrGlue::new("The matrix is singular."));
+            }
+            coefficients[i] /= hessenberg[i][i];
+            let value = coefficients[i];
+            (0..i).for_each(|j| coefficients[j] -= hessenberg[i][j] * value);
+        }
+        let mut correction = vec![0.0; solution.len()];
+        basis
+            .iter()
+            .zip(coefficients.iter())
+            .for_each(|(basis_vector, coefficient)| {
+                correction
+                    .iter_mut()
+                    .zip(basis_vector)
+                    .for_each(|(c, v)| *c += coefficient * v)
+            });
+        solution
+            .iter_mut()
+            .zip(preconditioner.apply(&correction))
+            .for_each(|(x, c)| *x += c);
+    }
+}
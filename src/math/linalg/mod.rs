@@ -0,0 +1,414 @@
+pub mod iterative;
+pub mod sparse;
+
+use crate::PyErrGlue;
+use conspire::math::{Scalar, SquareMatrix, Vector};
+use iterative::Preconditioner;
+use numpy::{PyArray1, PyReadonlyArrayDyn};
+use pyo3::prelude::*;
+use sparse::{CholeskyDecomposition, CompressedSparseRows, SparseLuDecomposition};
+
+pub fn register_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Cholesky>()?;
+    m.add_class::<Lu>()?;
+    m.add_function(wrap_pyfunction!(conjugate_gradient, m)?)?;
+    m.add_function(wrap_pyfunction!(gmres, m)?)?;
+    m.add_function(wrap_pyfunction!(to_csr, m)?)
+}
+
+type CsrArrays<'py> = (
+    Bound<'py, PyArray1<Scalar>>,
+    Bound<'py, PyArray1<usize>>,
+    Bound<'py, PyArray1<usize>>,
+);
+
+/// Reads a square matrix given as a `(data, indices, indptr)` tuple, or a sparse matrix with these attributes and a shape, in compressed sparse row format.
+fn csr(matrix: &Bound<'_, PyAny>) -> PyResult<CompressedSparseRows> {
+    let matrix = if matrix.hasattr("tocsr")? {
+        matrix.call_method0("tocsr")?
+    } else {
+        matrix.clone()
+    };
+    Ok(
+        match matrix.extract::<(Vec<Scalar>, Vec<usize>, Vec<usize>)>() {
+            Ok((values, column_indices, row_pointers)) => {
+                let rows = row_pointers.len().saturating_sub(1);
+                CompressedSparseRows::new((rows, rows), row_pointers, column_indices, values)
+            }
+            Err(_) => CompressedSparseRows::new(
+                matrix.getattr("shape")?.extract()?,
+                matrix.getattr("indptr")?.extract()?,
+                matrix.getattr("indices")?.extract()?,
+                matrix.getattr("data")?.extract()?,
+            ),
+        }?,
+    )
+}
+
+fn guess_or_zero(
+    matrix: &CompressedSparseRows,
+    vector: &[Scalar],
+    guess: Option<Vec<Scalar>>,
+) -> Result<Vec<Scalar>, PyErrGlue> {
+    matrix.check_square()?;
+    matrix.check_vector(vector)?;
+    let guess = guess.unwrap_or_else(|| vec![0.0; vector.len()]);
+    matrix.check_vector(&guess)?;
+    Ok(guess)
+}
+
+/// Returns the nonzero entries of a dense matrix, or of nodal stiffnesses with shape $(N, N, 3, 3)$, as a `(data, indices, indptr)` tuple in compressed sparse row format.
+#[pyfunction]
+fn to_csr<'py>(py: Python<'py>, matrix: &Bound<'py, PyAny>) -> PyResult<CsrArrays<'py>> {
+    let array: PyReadonlyArrayDyn<Scalar> = py
+        .import("numpy")?
+        .call_method1("asarray", (matrix, "float64"))?
+        .extract()?;
+    let array = array.as_array();
+    let (rows, columns, (a, b)) = match *array.shape() {
+        [rows, columns] => (rows, columns, (1, 1)),
+        [rows, columns, a, b] => (rows * a, columns * b, (a, b)),
+        _ => {
+            return Err(PyErrGlue::new(&format!(
+                "Expected a matrix with 2 or 4 dimensions, got {}.",
+                array.ndim()
+            ))
+            .into());
+        }
+    };
+    let entry = |row: usize, column: usize| {
+        if array.ndim() == 2 {
+            array[[row, column]]
+        } else {
+            array[[row / a, column / b, row % a, column % b]]
+        }
+    };
+    let mut values = Vec::new();
+    let mut column_indices = Vec::new();
+    let mut row_pointers = vec![0];
+    (0..rows).for_each(|row| {
+        (0..columns).for_each(|column| {
+            let value = entry(row, column);
+            if value != 0.0 {
+                values.push(value);
+                column_indices.push(column);
+            }
+        });
+        row_pointers.push(values.len());
+    });
+    Ok((
+        PyArray1::from_vec(py, values),
+        PyArray1::from_vec(py, column_indices),
+        PyArray1::from_vec(py, row_pointers),
+    ))
+}
+
+/// Cholesky decomposition of a sparse symmetric positive definite matrix in compressed sparse row format, using the entries of its lower triangle.
+#[pyclass]
+struct Cholesky(CholeskyDecomposition);
+
+#[pymethods]
+impl Cholesky {
+    #[new]
+    fn new(matrix: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(CholeskyDecomposition::new(&csr(matrix)?)?))
+    }
+    /// Solves the linear system for the given right-hand side.
+    fn solve<'py>(
+        &self,
+        py: Python<'py>,
+        vector: Vec<Scalar>,
+    ) -> Result<Bound<'py, PyArray1<Scalar>>, PyErrGlue> {
+        Ok(PyArray1::from_vec(py, self.0.solve(&vector)?))
+    }
+}
+
+/// LU decomposition of a sparse matrix in compressed sparse row format with threshold partial pivoting.
+#[pyclass(name = "LU")]
+struct Lu(SparseLuDecomposition);
+
+#[pymethods]
+impl Lu {
+    #[new]
+    fn new(matrix: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(SparseLuDecomposition::new(&csr(matrix)?)?))
+    }
+    /// Solves the linear system for the given right-hand side.
+    fn solve<'py>(
+        &self,
+        py: Python<'py>,
+        vector: Vec<Scalar>,
+    ) -> Result<Bound<'py, PyArray1<Scalar>>, PyErrGlue> {
+        Ok(PyArray1::from_vec(py, self.0.solve(&vector)?))
+    }
+}
+
+/// Solves a sparse symmetric positive definite linear system in compressed sparse row format using the preconditioned conjugate gradient method.
+///
+/// The preconditioner is either `"jacobi"` or `None`, and the iterations stop once $\|\mathbf{b} - \mathbf{A}\cdot\mathbf{x}\| \leq \mathrm{tol}\,\|\mathbf{b}\|$.
+#[pyfunction]
+#[pyo3(signature = (matrix, vector, guess = None, tolerance = 1e-10, max_iterations = None, preconditioner = Some("jacobi".to_string())))]
+fn conjugate_gradient<'py>(
+    matrix: &Bound<'py, PyAny>,
+    vector: Vec<Scalar>,
+    guess: Option<Vec<Scalar>>,
+    tolerance: Scalar,
+    max_iterations: Option<usize>,
+    preconditioner: Option<String>,
+) -> PyResult<Bound<'py, PyArray1<Scalar>>> {
+    let py = matrix.py();
+    let matrix = csr(matrix)?;
+    let guess = guess_or_zero(&matrix, &vector, guess)?;
+    let preconditioner = Preconditioner::new(&matrix, preconditioner.as_deref())?;
+    Ok(PyArray1::from_vec(
+        py,
+        iterative::conjugate_gradient(
+            &matrix,
+            &vector,
+            guess,
+            &preconditioner,
+            tolerance,
+            max_iterations.unwrap_or(10 * vector.len()),
+        )?,
+    ))
+}
+
+/// Solves a sparse linear system in compressed sparse row format using the restarted generalized minimal residual method with right preconditioning.
+///
+/// The preconditioner is either `"jacobi"` or `None`, and the iterations stop once $\|\mathbf{b} - \mathbf{A}\cdot\mathbf{x}\| \leq \mathrm{tol}\,\|\mathbf{b}\|$.
+#[pyfunction]
+#[pyo3(signature = (matrix, vector, guess = None, tolerance = 1e-10, max_iterations = None, restart = 30, preconditioner = Some("jacobi".to_string())))]
+fn gmres<'py>(
+    matrix: &Bound<'py, PyAny>,
+    vector: Vec<Scalar>,
+    guess: Option<Vec<Scalar>>,
+    tolerance: Scalar,
+    max_iterations: Option<usize>,
+    restart: usize,
+    preconditioner: Option<String>,
+) -> PyResult<Bound<'py, PyArray1<Scalar>>> {
+    if restart == 0 {
+        return Err(PyErrGlue::new("The restart length must be positive.").into());
+    }
+    let py = matrix.py();
+    let matrix = csr(matrix)?;
+    let guess = guess_or_zero(&matrix, &vector, guess)?;
+    let preconditioner = Preconditioner::new(&matrix, preconditioner.as_deref())?;
+    Ok(PyArray1::from_vec(
+        py,
+        iterative::gmres(
+            &matrix,
+            &vector,
+            guess,
+            &preconditioner,
+            tolerance,
+            max_iterations.unwrap_or(10 * vector.len()),
+            restart,
+        )?,
+    ))
+}
+
+/// Checks that a vector has the given length, shared by the sparse solvers, optimizers, and integrators.
+pub fn check_length(size: usize, vector: &[Scalar]) -> Result<(), PyErrGlue> {
+    if vector.len() == size {
+        Ok(())
+    } else {
+        Err(PyErrGlue::new(&format!(
+            "Expected a vector of length {size}, got {}.",
+            vector.len()
+        )))
+    }
+}
+
+/// Checks that a dense matrix is square with the given size.
+pub fn check_matrix(size: usize, matrix: &[Vec<Scalar>]) -> Result<(), PyErrGlue> {
+    if matrix.len() == size && matrix.iter().all(|row| row.len() == size) {
+        Ok(())
+    } else {
+        Err(PyErrGlue::new(&format!(
+            "Expected a matrix of shape ({size}, {size})."
+        )))
+    }
+}
+
+fn dot(u: &[Scalar], v: &[Scalar]) -> Scalar {
+    u.iter().zip(v).map(|(u, v)| u * v).sum()
+}
+
+/// Solves a dense linear system using the upstream LU decomposition with partial pivoting.
+pub fn solve_dense(
+    matrix: Vec<Vec<Scalar>>,
+    vector: Vec<Scalar>,
+) -> Result<Vec<Scalar>, PyErrGlue> {
+    matrix
+        .into_iter()
+        .map(Vector::from)
+        .collect::<SquareMatrix>()
+        .solve_lu(&Vector::from(vector))
+        .map(Vec::from)
+        .map_err(|_| PyErrGlue::new("The matrix is singular."))
+}
+
+/// Computes the eigenvalues and eigenvectors of a dense symmetric matrix using cyclic Jacobi rotations.
+pub fn symmetric_eigen(mut matrix: Vec<Vec<Scalar>>) -> (Vec<Scalar>, Vec<Vec<Scalar>>) {
+    let n = matrix.len();
+    let mut vectors: Vec<Vec<Scalar>> = (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+    let norm: Scalar = matrix.iter().map(|row| dot(row, row)).sum();
+    for _ in 0..100 {
+        let off_diagonal: Scalar = (0..n)
+            .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+            .map(|(i, j)| matrix[i][j] * matrix[i][j])
+            .sum();
+        if off_diagonal <= 1e-30 * norm {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                if matrix[p][q] == 0.0 {
+                    continue;
+                }
+                let theta = (matrix[q][q] - matrix[p][p]) / (2.0 * matrix[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                matrix.iter_mut().chain(vectors.iter_mut()).for_each(|row| {
+                    let (a_kp, a_kq) = (row[p], row[q]);
+                    row[p] = c * a_kp - s * a_kq;
+                    row[q] = s * a_kp + c * a_kq;
+                });
+                (0..n).for_each(|k| {
+                    let (a_pk, a_qk) = (matrix[p][k], matrix[q][k]);
+                    matrix[p][k] = c * a_pk - s * a_qk;
+                    matrix[q][k] = s * a_pk + c * a_qk;
+                });
+            }
+        }
+    }
+    (
+        (0..n).map(|i| matrix[i][i]).collect(),
+        (0..n)
+            .map(|i| vectors.iter().map(|row| row[i]).collect())
+            .collect(),
+    )
+}
+
+/// Computes the eigenpairs of largest magnitude of an operator that is self-adjoint with respect to the inner product of a symmetric positive definite matrix, using the Lanczos method with full reorthogonalization.
+pub fn lanczos(
+    operator: impl Fn(&[Scalar]) -> Result<Vec<Scalar>, PyErrGlue>,
+    inner_product: impl Fn(&[Scalar]) -> Vec<Scalar>,
+    size: usize,
+    count: usize,
+) -> Result<(Vec<Scalar>, Vec<Vec<Scalar>>), PyErrGlue> {
+    if count == 0 || size == 0 {
+        return Ok((Vec::new(), Vec::new()));
+    }
+    let mut seed: u64 = 0x2545f4914f6cdd1d;
+    let mut random = || {
+        (0..size)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                seed as Scalar / u64::MAX as Scalar - 0.5
+            })
+            .collect::<Vec<Scalar>>()
+    };
+    let mut basis: Vec<Vec<Scalar>> = Vec::new();
+    let mut products: Vec<Vec<Scalar>> = Vec::new();
+    let mut diagonal: Vec<Scalar> = Vec::new();
+    let mut off_diagonal: Vec<Scalar> = Vec::new();
+    let mut vector = random();
+    let mut coupling = 0.0;
+    while basis.len() < size {
+        for _ in 0..2 {
+            basis.iter().zip(products.iter()).for_each(|(q, b_q)| {
+                let projection = dot(b_q, &vector);
+                vector
+                    .iter_mut()
+                    .zip(q)
+                    .for_each(|(v, q)| *v -= projection * q)
+            });
+        }
+        let product = inner_product(&vector);
+        let norm = dot(&vector, &product);
+        if norm.is_nan() || norm <= 0.0 {
+            return Err(PyErrGlue::new("The matrix is not positive definite."));
+        }
+        let norm = norm.sqrt();
+        if !basis.is_empty() {
+            off_diagonal.push(if coupling > 0.0 { norm } else { 0.0 });
+        }
+        basis.push(vector.iter().map(|v| v / norm).collect());
+        products.push(product.iter().map(|v| v / norm).collect());
+        let j = basis.len() - 1;
+        vector = operator(&basis[j])?;
+        let alpha = dot(&products[j], &vector);
+        diagonal.push(alpha);
+        for _ in 0..2 {
+            basis.iter().zip(products.iter()).for_each(|(q, b_q)| {
+                let projection = dot(b_q, &vector);
+                vector
+                    .iter_mut()
+                    .zip(q)
+                    .for_each(|(v, q)| *v -= projection * q)
+            });
+        }
+        coupling = dot(&vector, &inner_product(&vector)).max(0.0).sqrt();
+        let scale = diagonal.iter().fold(0.0, |a: Scalar, b| a.max(b.abs()));
+        let breakdown = coupling <= 1e-12 * scale;
+        if basis.len() >= count {
+            let m = basis.len();
+            let tridiagonal = (0..m)
+                .map(|i| {
+                    (0..m)
+                        .map(|k| {
+                            if i == k {
+                                diagonal[i]
+                            } else if k == i + 1 {
+                                off_diagonal[i]
+                            } else if i == k + 1 {
+                                off_diagonal[k]
+                            } else {
+                                0.0
+                            }
+                        })
+                        .collect()
+                })
+                .collect();
+            let (values, vectors) = symmetric_eigen(tridiagonal);
+            let mut order: Vec<usize> = (0..m).collect();
+            order.sort_by(|&a, &b| values[b].abs().total_cmp(&values[a].abs()));
+            order.truncate(count);
+            let converged = m == size
+                || order
+                    .iter()
+                    .all(|&i| (coupling * vectors[i][m - 1]).abs() <= 1e-12 * values[i].abs());
+            if converged {
+                return Ok((
+                    order.iter().map(|&i| values[i]).collect(),
+                    order
+                        .iter()
+                        .map(|&i| {
+                            (0..size)
+                                .map(|k| {
+                                    basis
+                                        .iter()
+                                        .zip(vectors[i].iter())
+                                        .map(|(q, y)| q[k] * y)
+                                        .sum()
+                                })
+                                .collect()
+                        })
+                        .collect(),
+                ));
+            }
+        }
+        if breakdown {
+            vector = random();
+            coupling = 0.0;
+        }
+    }
+    Err(PyErrGlue::new("The Lanczos iteration did not converge."))
+}
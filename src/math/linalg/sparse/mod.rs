@@ -0,0 +1,468 @@
+use super::check_length;
+use crate::PyErrGlue;
+use conspire::math::Scalar;
+use std::collections::VecDeque;
+
+/// Sparse matrix in compressed sparse row format, where duplicate entries are summed.
+pub struct CompressedSparseRows {
+    columns: usize,
+    row_pointers: Vec<usize>,
+    column_indices: Vec<usize>,
+    values: Vec<Scalar>,
+}
+
+impl CompressedSparseRows {
+    pub fn new(
+        shape: (usize, usize),
+        row_pointers: Vec<usize>,
+        column_indices: Vec<usize>,
+        values: Vec<Scalar>,
+    ) -> Result<Self, PyErrGlue> {
+        let (rows, columns) = shape;
+        if row_pointers.len() != rows + 1 {
+            return Err(PyErrGlue::new(&format!(
+                "Expected {} row pointers, got {}.",
+                rows + 1,
+                row_pointers.len()
+            )));
+        }
+        if column_indices.len() != values.len() {
+            return Err(PyErrGlue::new(&format!(
+                "Expected {} column indices, got {}.",
+                values.len(),
+                column_indices.len()
+            )));
+        }
+        if row_pointers[0] != 0
+            || row_pointers[rows] != values.len()
+            || row_pointers.windows(2).any(|pair| pair[0] > pair[1])
+        {
+            return Err(PyErrGlue::new(
+                "The row pointers must be nondecreasing from zero to the number of entries.",
+            ));
+        }
+        if let Some(column) = column_indices.iter().find(|&&column| column >= columns) {
+            return Err(PyErrGlue::new(&format!(
+                "Column {column} is out of range for {columns} columns."
+            )));
+        }
+        Ok(Self {
+            columns,
+            row_pointers,
+            column_indices,
+            values,
+        })
+    }
+    /// Collects entries given as triplets of row, column, and value, which must be in range.
+    pub fn from_triplets(shape: (usize, usize), triplets: &[(usize, usize, Scalar)]) -> Self {
+        let (rows, columns) = shape;
+        let mut row_pointers = vec![0; rows + 1];
+        triplets
+            .iter()
+            .for_each(|&(row, _, _)| row_pointers[row + 1] += 1);
+        (0..rows).for_each(|row| row_pointers[row + 1] += row_pointers[row]);
+        let mut next = row_pointers.clone();
+        let mut column_indices = vec![0; triplets.len()];
+        let mut values = vec![0.0; triplets.len()];
+        triplets.iter().for_each(|&(row, column, value)| {
+            column_indices[next[row]] = column;
+            values[next[row]] = value;
+            next[row] += 1;
+        });
+        Self {
+            columns,
+            row_pointers,
+            column_indices,
+            values,
+        }
+    }
+    pub fn rows(&self) -> usize {
+        self.row_pointers.len() - 1
+    }
+    pub fn check_square(&self) -> Result<(), PyErrGlue> {
+        if self.rows() == self.columns {
+            Ok(())
+        } else {
+            Err(PyErrGlue::new(&format!(
+                "Expected a square matrix, got shape ({}, {}).",
+                self.rows(),
+                self.columns
+            )))
+        }
+    }
+    pub fn check_vector(&self, vector: &[Scalar]) -> Result<(), PyErrGlue> {
+        check_length(self.rows(), vector)
+    }
+    /// Entries of each row as pairs of column indices and values.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, Scalar)> + '_ {
+        let range = self.row_pointers[row]..self.row_pointers[row + 1];
+        self.column_indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.values[range].iter().copied())
+    }
+    pub fn product(&self, vector: &[Scalar]) -> Vec<Scalar> {
+        (0..self.rows())
+            .map(|row| {
+                self.row(row)
+                    .map(|(column, value)| value * vector[column])
+                    .sum()
+            })
+            .collect()
+    }
+    pub fn diagonal(&self) -> Vec<Scalar> {
+        (0..self.rows())
+            .map(|row| {
+                self.row(row)
+                    .filter(|&(column, _)| column == row)
+                    .map(|(_, value)| value)
+                    .sum()
+            })
+            .collect()
+    }
+    pub fn transpose(&self) -> Self {
+        let mut counts = vec![0; self.columns + 1];
+        self.column_indices
+            .iter()
+            .for_each(|&column| counts[column + 1] += 1);
+        (0..self.columns).for_each(|column| counts[column + 1] += counts[column]);
+        let row_pointers = counts.clone();
+        let mut column_indices = vec![0; self.values.len()];
+        let mut values = vec![0.0; self.values.len()];
+        (0..self.rows()).for_each(|row| {
+            self.row(row).for_each(|(column, value)| {
+                column_indices[counts[column]] = row;
+                values[counts[column]] = value;
+                counts[column] += 1;
+            })
+        });
+        Self {
+            columns: self.rows(),
+            row_pointers,
+            column_indices,
+            values,
+        }
+    }
+    /// Orders the rows and columns of a square matrix by reverse Cuthill-McKee on the structure of its symmetric part to reduce the profile.
+    fn reverse_cuthill_mckee(&self) -> Vec<usize> {
+        let n = self.rows();
+        let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); n];
+        (0..n).for_each(|row| {
+            self.row(row)
+                .filter(|&(column, _)| column != row)
+                .for_each(|(column, _)| {
+                    neighbors[row].push(column);
+                    neighbors[column].push(row);
+                })
+        });
+        neighbors.iter_mut().for_each(|adjacent| {
+            adjacent.sort_unstable();
+            adjacent.dedup();
+        });
+        let mut nodes: Vec<usize> = (0..n).collect();
+        nodes.sort_by_key(|&node| neighbors[node].len());
+        let mut visited = vec![false; n];
+        let mut order = Vec::with_capacity(n);
+        let mut queue = VecDeque::new();
+        for start in nodes {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            queue.push_back(start);
+            while let Some(node) = queue.pop_front() {
+                order.push(node);
+                let mut adjacent: Vec<usize> = neighbors[node]
+                    .iter()
+                    .copied()
+                    .filter(|&neighbor| !visited[neighbor])
+                    .collect();
+                adjacent.sort_by_key(|&neighbor| neighbors[neighbor].len());
+                adjacent.into_iter().for_each(|neighbor| {
+                    visited[neighbor] = true;
+                    queue.push_back(neighbor)
+                });
+            }
+        }
+        order.reverse();
+        order
+    }
+}
+
+/// Cholesky decomposition of a sparse symmetric positive definite matrix, stored within the profile of the lower triangle after reverse Cuthill-McKee ordering.
+pub struct CholeskyDecomposition {
+    permutation: Vec<usize>,
+    first_columns: Vec<usize>,
+    row_pointers: Vec<usize>,
+    factors: Vec<Scalar>,
+}
+
+impl CholeskyDecomposition {
+    /// Factors the matrix using only the entries of its lower triangle.
+    pub fn new(matrix: &CompressedSparseRows) -> Result<Self, PyErrGlue> {
+        matrix.check_square()?;
+        let n = matrix.rows();
+        let permutation = matrix.reverse_cuthill_mckee();
+        let mut inverse = vec![0; n];
+        permutation
+            .iter()
+            .enumerate()
+            .for_each(|(new, &old)| inverse[old] = new);
+        let inverse = &inverse;
+        let lower_triangle = |old: usize| {
+            matrix
+                .row(old)
+                .filter(move |&(column, _)| column <= old)
+                .map(move |(column, value)| {
+                    let (row, column) = (inverse[old], inverse[column]);
+                    (row.max(column), row.min(column), value)
+                })
+        };
+        let mut first_columns: Vec<usize> = (0..n).collect();
+        (0..n).for_each(|old| {
+            lower_triangle(old)
+                .for_each(|(row, column, _)| first_columns[row] = first_columns[row].min(column))
+        });
+        let mut row_pointers = vec![0; n + 1];
+        (0..n).for_each(|row| {
+            row_pointers[row + 1] = row_pointers[row] + row + 1 - first_columns[row]
+        });
+        let mut factors = vec![0.0; row_pointers[n]];
+        (0..n).for_each(|old| {
+            lower_triangle(old).for_each(|(row, column, value)| {
+                factors[row_pointers[row] + column - first_columns[row]] += value
+            })
+        });
+        for i in 0..n {
+            for j in first_columns[i]..=i {
+                let start = first_columns[i].max(first_columns[j]);
+                let sum: Scalar = (start..j)
+                    .map(|k| {
+                        factors[row_pointers[i] + k - first_columns[i]]
+                            * factors[row_pointers[j] + k - first_columns[j]]
+                    })
+                    .sum();
+                let entry = row_pointers[i] + j - first_columns[i];
+                if j < i {
+                    factors[entry] = (factors[entry] - sum) / factors[row_pointers[j + 1] - 1]
+                } else {
+                    let pivot = factors[entry] - sum;
+                    if pivot.is_nan() || pivot <= 0.0 {
+                        return Err(PyErrGlue::new("The matrix is not positive definite."));
+                    }
+                    factors[entry] = pivot.sqrt()
+                }
+            }
+        }
+        Ok(Self {
+            permutation,
+            first_columns,
+            row_pointers,
+            factors,
+        })
+    }
+    pub fn solve(&self, vector: &[Scalar]) -> Result<Vec<Scalar>, PyErrGlue> {
+        let n = self.permutation.len();
+        check_length(n, vector)?;
+        let mut solution: Vec<Scalar> = self.permutation.iter().map(|&old| vector[old]).collect();
+        for i in 0..n {
+            let sum: Scalar = (self.first_columns[i]..i)
+                .map(|k| {
+                    self.factors[self.row_pointers[i] + k - self.first_columns[i]] * solution[k]
+                })
+                .sum();
+            solution[i] = (solution[i] - sum) / self.factors[self.row_pointers[i + 1] - 1];
+        }
+        for i in (0..n).rev() {
+            solution[i] /= self.factors[self.row_pointers[i + 1] - 1];
+            let value = solution[i];
+            (self.first_columns[i]..i).for_each(|k| {
+                solution[k] -=
+                    self.factors[self.row_pointers[i] + k - self.first_columns[i]] * value
+            });
+        }
+        let mut unpermuted = vec![0.0; n];
+        self.permutation
+            .iter()
+            .zip(solution)
+            .for_each(|(&old, value)| unpermuted[old] = value);
+        Ok(unpermuted)
+    }
+}
+
+/// Compressed sparse columns of a triangular factor.
+struct Factor {
+    column_pointers: Vec<usize>,
+    row_indices: Vec<usize>,
+    values: Vec<Scalar>,
+}
+
+impl Factor {
+    fn column(&self, column: usize) -> std::ops::Range<usize> {
+        self.column_pointers[column]..self.column_pointers[column + 1]
+    }
+}
+
+/// LU decomposition of a sparse matrix using the left-looking Gilbert-Peierls algorithm with threshold partial pivoting, after reverse Cuthill-McKee ordering of the columns.
+pub struct SparseLuDecomposition {
+    lower: Factor,
+    upper: Factor,
+    row_pivots: Vec<usize>,
+    column_permutation: Vec<usize>,
+}
+
+impl SparseLuDecomposition {
+    const PIVOT_TOLERANCE: Scalar = 0.1;
+    pub fn new(matrix: &CompressedSparseRows) -> Result<Self, PyErrGlue> {
+        matrix.check_square()?;
+        let n = matrix.rows();
+        let columns = matrix.transpose();
+        let column_permutation = matrix.reverse_cuthill_mckee();
+        let mut lower = Factor {
+            column_pointers: Vec::with_capacity(n + 1),
+            row_indices: Vec::new(),
+            values: Vec::new(),
+        };
+        let mut upper = Factor {
+            column_pointers: Vec::with_capacity(n + 1),
+            row_indices: Vec::new(),
+            values: Vec::new(),
+        };
+        let mut pivots: Vec<Option<usize>> = vec![None; n];
+        let mut work = vec![0.0; n];
+        let mut marked = vec![false; n];
+        let mut reach = Vec::with_capacity(n);
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+        for (k, &column) in column_permutation.iter().enumerate() {
+            lower.column_pointers.push(lower.values.len());
+            upper.column_pointers.push(upper.values.len());
+            reach.clear();
+            for (start, _) in columns.row(column) {
+                if marked[start] {
+                    continue;
+                }
+                marked[start] = true;
+                stack.push((start, 0));
+                while let Some((node, position)) = stack.pop() {
+                    let children = match pivots[node] {
+                        Some(pivot) => {
+                            &lower.row_indices
+                                [lower.column_pointers[pivot] + 1..lower.column_pointers[pivot + 1]]
+                        }
+                        None => &[],
+                    };
+                    match children.get(position) {
+                        Some(&child) => {
+                            stack.push((node, position + 1));
+                            if !marked[child] {
+                                marked[child] = true;
+                                stack.push((child, 0));
+                            }
+                        }
+                        None => reach.push(node),
+                    }
+                }
+            }
+            reach.iter().for_each(|&row| {
+                marked[row] = false;
+                work[row] = 0.0
+            });
+            columns
+                .row(column)
+                .for_each(|(row, value)| work[row] += value);
+            for &row in reach.iter().rev() {
+                if let Some(pivot) = pivots[row] {
+                    let value = work[row];
+                    let range = lower.column_pointers[pivot]..lower.column_pointers[pivot + 1];
+                    lower.row_indices[range.start + 1..range.end]
+                        .iter()
+                        .zip(lower.values[range.start + 1..range.end].iter())
+                        .for_each(|(&other, &factor)| work[other] -= factor * value);
+                }
+            }
+            let mut pivot_row = None;
+            let mut largest = 0.0;
+            for &row in reach.iter() {
+                match pivots[row] {
+                    Some(pivot) => {
+                        upper.row_indices.push(pivot);
+                        upper.values.push(work[row]);
+                    }
+                    None => {
+                        if work[row].abs() > largest {
+                            largest = work[row].abs();
+                            pivot_row = Some(row);
+                        }
+                    }
+                }
+            }
+            let pivot_row = match pivot_row {
+                Some(row) if largest.is_finite() => {
+                    if pivots[column].is_none()
+                        && work[column].abs() >= Self::PIVOT_TOLERANCE * largest
+                    {
+                        column
+                    } else {
+                        row
+                    }
+                }
+                _ => return Err(PyErrGlue::new("The matrix is singular.")),
+            };
+            let pivot = work[pivot_row];
+            upper.row_indices.push(k);
+            upper.values.push(pivot);
+            pivots[pivot_row] = Some(k);
+            lower.row_indices.push(pivot_row);
+            lower.values.push(1.0);
+            for &row in reach.iter() {
+                if pivots[row].is_none() {
+                    lower.row_indices.push(row);
+                    lower.values.push(work[row] / pivot);
+                }
+                work[row] = 0.0;
+            }
+        }
+        lower.column_pointers.push(lower.values.len());
+        upper.column_pointers.push(upper.values.len());
+        let row_pivots: Vec<usize> = pivots.into_iter().map(Option::unwrap).collect();
+        lower
+            .row_indices
+            .iter_mut()
+            .for_each(|row| *row = row_pivots[*row]);
+        Ok(Self {
+            lower,
+            upper,
+            row_pivots,
+            column_permutation,
+        })
+    }
+    pub fn solve(&self, vector: &[Scalar]) -> Result<Vec<Scalar>, PyErrGlue> {
+        let n = self.row_pivots.len();
+        check_length(n, vector)?;
+        let mut solution = vec![0.0; n];
+        self.row_pivots
+            .iter()
+            .zip(vector.iter())
+            .for_each(|(&pivot, &value)| solution[pivot] = value);
+        for j in 0..n {
+            let value = solution[j];
+            self.lower.column(j).skip(1).for_each(|entry| {
+                solution[self.lower.row_indices[entry]] -= self.lower.values[entry] * value
+            });
+        }
+        for j in (0..n).rev() {
+            let range = self.upper.column(j);
+            solution[j] /= self.upper.values[range.end - 1];
+            let value = solution[j];
+            (range.start..range.end - 1).for_each(|entry| {
+                solution[self.upper.row_indices[entry]] -= self.upper.values[entry] * value
+            });
+        }
+        let mut unpermuted = vec![0.0; n];
+        self.column_permutation
+            .iter()
+            .zip(solution)
+            .for_each(|(&column, value)| unpermuted[column] = value);
+        Ok(unpermuted)
+    }
+}
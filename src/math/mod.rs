@@ -1,4 +1,6 @@
 mod integrate;
+pub mod linalg;
+mod optimize;
 mod special;
 
 use crate::PyErrGlue;
@@ -52,16 +54,30 @@ where
 
 pub fn register_module(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     let submodule_integrate = PyModule::new(py, "integrate")?;
+    let submodule_linalg = PyModule::new(py, "linalg")?;
+    let submodule_optimize = PyModule::new(py, "optimize")?;
     let submodule_special = PyModule::new(py, "special")?;
     submodule_integrate.setattr("__doc__", "Integration and ODEs.\n\n")?;
+    submodule_linalg.setattr("__doc__", "Linear algebra.\n\n")?;
+    submodule_optimize.setattr("__doc__", "Optimization and root finding.\n\n")?;
     submodule_special.setattr("__doc__", "Special functions.\n\n")?;
     m.add_submodule(&submodule_integrate)?;
+    m.add_submodule(&submodule_linalg)?;
+    m.add_submodule(&submodule_optimize)?;
     m.add_submodule(&submodule_special)?;
     integrate::register_module(&submodule_integrate)?;
+    linalg::register_module(&submodule_linalg)?;
+    optimize::register_module(&submodule_optimize)?;
     special::register_module(&submodule_special)?;
     py.import("sys")?
         .getattr("modules")?
         .set_item("conspire.math.integrate", submodule_integrate)?;
+    py.import("sys")?
+        .getattr("modules")?
+        .set_item("conspire.math.linalg", submodule_linalg)?;
+    py.import("sys")?
+        .getattr("modules")?
+        .set_item("conspire.math.optimize", submodule_optimize)?;
     py.import("sys")?
         .getattr("modules")?
         .set_item("conspire.math.special", submodule_special)
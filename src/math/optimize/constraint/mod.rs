@@ -0,0 +1,74 @@
+use crate::PyErrGlue;
+use conspire::math::{Scalar, Vector, optimize};
+use pyo3::prelude::*;
+
+/// Equality constraint for the optimizers, either fixing entries at their initial guess values or the linear constraint $\mathbf{A}\cdot\mathbf{x} = \mathbf{b}$.
+#[pyclass]
+pub struct EqualityConstraint(optimize::EqualityConstraint);
+
+impl EqualityConstraint {
+    /// Returns the constraint for the given initial guess, with fixed entries expressed as a linear constraint.
+    pub(crate) fn get(
+        &self,
+        initial_guess: &[Scalar],
+    ) -> Result<optimize::EqualityConstraint, PyErrGlue> {
+        let size = initial_guess.len();
+        match &self.0 {
+            optimize::EqualityConstraint::Fixed(indices) => {
+                if let Some(index) = indices.iter().find(|&&index| index >= size) {
+                    Err(PyErrGlue::new(&format!(
+                        "Fixed index {index} is out of bounds for a solution of length {size}."
+                    )))
+                } else if indices.is_empty() {
+                    Ok(optimize::EqualityConstraint::None)
+                } else {
+                    Ok(optimize::EqualityConstraint::Linear(
+                        indices
+                            .iter()
+                            .map(|&index| {
+                                (0..size)
+                                    .map(|j| if j == index { 1.0 } else { 0.0 })
+                                    .collect()
+                            })
+                            .collect(),
+                        indices.iter().map(|&index| initial_guess[index]).collect(),
+                    ))
+                }
+            }
+            optimize::EqualityConstraint::Linear(matrix, _) if matrix.width() != size => {
+                Err(PyErrGlue::new(&format!(
+                    "Expected a constraint matrix with {size} columns, got {}.",
+                    matrix.width()
+                )))
+            }
+            constraint => Ok(constraint.clone()),
+        }
+    }
+}
+
+#[pymethods]
+impl EqualityConstraint {
+    /// Fixes the entries with the given indices at their initial guess values.
+    #[staticmethod]
+    fn fixed(indices: Vec<usize>) -> Self {
+        Self(optimize::EqualityConstraint::Fixed(indices))
+    }
+    /// Constrains the solution to satisfy $\mathbf{A}\cdot\mathbf{x} = \mathbf{b}$.
+    #[staticmethod]
+    fn linear(matrix: Vec<Vec<Scalar>>, vector: Vec<Scalar>) -> Result<Self, PyErrGlue> {
+        if matrix.is_empty() || matrix.len() != vector.len() {
+            Err(PyErrGlue::new(&format!(
+                "Expected a constraint matrix with {} rows, got {}.",
+                vector.len().max(1),
+                matrix.len()
+            )))
+        } else if matrix.iter().any(|row| row.len() != matrix[0].len()) {
+            Err(PyErrGlue::new("Expected a rectangular constraint matrix."))
+        } else {
+            Ok(Self(optimize::EqualityConstraint::Linear(
+                matrix.into_iter().map(Vector::from).collect(),
+                vector.into(),
+            )))
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use super::{LineSearch, constraint, scalar, vector};
+use crate::{PyErrGlue, math::optimize::EqualityConstraint};
+use conspire::math::{
+    Scalar, Vector,
+    optimize::{self, FirstOrderOptimization},
+};
+use numpy::PyArray1;
+use pyo3::{prelude::*, types::PyDict};
+
+/// The method of gradient descent with Barzilai-Borwein step sizes.
+///
+/// The iterations stop once the infinity norm of the gradient is below `abs_tol`, or below `rel_tol` relative to its initial value if given.
+/// An equality constraint is handled using Lagrange multipliers, which are updated simultaneously or, if `dual` is set, using the Lagrangian dual.
+#[pyclass]
+pub struct GradientDescent(optimize::GradientDescent);
+
+#[pymethods]
+impl GradientDescent {
+    #[new]
+    #[pyo3(signature = (**kwargs))]
+    fn new(kwargs: Option<&Bound<'_, PyDict>>) -> Result<Self, PyErr> {
+        let mut solver = optimize::GradientDescent::default();
+        if let Some(args) = kwargs {
+            args.into_iter().try_for_each(|(name, value)| {
+                match name.extract()? {
+                    "abs_tol" => solver.abs_tol = value.extract()?,
+                    "dual" => solver.dual = value.extract()?,
+                    "line_search" => {
+                        solver.line_search = value.extract::<PyRef<LineSearch>>()?.get()
+                    }
+                    "max_steps" => solver.max_steps = value.extract()?,
+                    "rel_tol" => solver.rel_tol = value.extract()?,
+                    _ => (),
+                };
+                Ok::<(), PyErr>(())
+            })?
+        }
+        Ok(Self(solver))
+    }
+    /// @private
+    #[getter]
+    pub fn abs_tol(&self) -> Scalar {
+        self.0.abs_tol
+    }
+    /// @private
+    #[getter]
+    pub fn dual(&self) -> bool {
+        self.0.dual
+    }
+    /// @private
+    #[getter]
+    pub fn max_steps(&self) -> usize {
+        self.0.max_steps
+    }
+    /// @private
+    #[getter]
+    pub fn rel_tol(&self) -> Option<Scalar> {
+        self.0.rel_tol
+    }
+    /// Minimizes the function given its gradient, optionally subject to an equality constraint.
+    #[pyo3(signature = (function, gradient, initial_guess, constraint = None))]
+    fn minimize<'py>(
+        &self,
+        function: Bound<'py, PyAny>,
+        gradient: Bound<'py, PyAny>,
+        initial_guess: Vec<Scalar>,
+        constraint: Option<PyRef<EqualityConstraint>>,
+    ) -> Result<Bound<'py, PyArray1<Scalar>>, PyErrGlue> {
+        let constraint = self::constraint(constraint, &initial_guess, &self.0.line_search)?;
+        let solution = self.0.minimize(
+            |x: &Vector| scalar(&function, x),
+            |x: &Vector| vector(&gradient, x),
+            Vector::from(initial_guess),
+            constraint,
+        )?;
+        Ok(PyArray1::from_vec(function.py(), solution.into()))
+    }
+}
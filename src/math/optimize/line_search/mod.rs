@@ -0,0 +1,106 @@
+use conspire::math::{Scalar, optimize};
+use pyo3::prelude::*;
+
+/// Backtracking line search for the optimizers, selected by one of the constructors below.
+#[pyclass]
+pub struct LineSearch(optimize::LineSearch);
+
+impl LineSearch {
+    pub fn get(&self) -> optimize::LineSearch {
+        match self.0 {
+            optimize::LineSearch::Armijo {
+                control,
+                cut_back,
+                max_steps,
+            } => optimize::LineSearch::Armijo {
+                control,
+                cut_back,
+                max_steps,
+            },
+            optimize::LineSearch::Error {
+                cut_back,
+                max_steps,
+            } => optimize::LineSearch::Error {
+                cut_back,
+                max_steps,
+            },
+            optimize::LineSearch::Goldstein {
+                control,
+                cut_back,
+                max_steps,
+            } => optimize::LineSearch::Goldstein {
+                control,
+                cut_back,
+                max_steps,
+            },
+            optimize::LineSearch::Wolfe {
+                control_1,
+                control_2,
+                cut_back,
+                max_steps,
+                strong,
+            } => optimize::LineSearch::Wolfe {
+                control_1,
+                control_2,
+                cut_back,
+                max_steps,
+                strong,
+            },
+            optimize::LineSearch::None => optimize::LineSearch::None,
+        }
+    }
+}
+
+#[pymethods]
+impl LineSearch {
+    /// Backtracks until the Armijo condition of sufficient decrease is satisfied.
+    #[staticmethod]
+    #[pyo3(signature = (control = 1e-3, cut_back = 0.9, max_steps = 100))]
+    fn armijo(control: Scalar, cut_back: Scalar, max_steps: usize) -> Self {
+        Self(optimize::LineSearch::Armijo {
+            control,
+            cut_back,
+            max_steps,
+        })
+    }
+    /// Backtracks until the function no longer raises an error.
+    #[staticmethod]
+    #[pyo3(signature = (cut_back = 0.9, max_steps = 100))]
+    fn error(cut_back: Scalar, max_steps: usize) -> Self {
+        Self(optimize::LineSearch::Error {
+            cut_back,
+            max_steps,
+        })
+    }
+    /// Backtracks until the Goldstein conditions are satisfied.
+    #[staticmethod]
+    #[pyo3(signature = (control = 1e-3, cut_back = 0.9, max_steps = 100))]
+    fn goldstein(control: Scalar, cut_back: Scalar, max_steps: usize) -> Self {
+        Self(optimize::LineSearch::Goldstein {
+            control,
+            cut_back,
+            max_steps,
+        })
+    }
+    /// Backtracks until the Wolfe conditions, or optionally the strong Wolfe conditions, are satisfied.
+    #[staticmethod]
+    #[pyo3(signature = (control_1 = 1e-3, control_2 = 0.9, cut_back = 0.9, max_steps = 100, strong = false))]
+    fn wolfe(
+        control_1: Scalar,
+        control_2: Scalar,
+        cut_back: Scalar,
+        max_steps: usize,
+        strong: bool,
+    ) -> Self {
+        Self(optimize::LineSearch::Wolfe {
+            control_1,
+            control_2,
+            cut_back,
+            max_steps,
+            strong,
+        })
+    }
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
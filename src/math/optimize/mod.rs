@@ -0,0 +1,77 @@
+mod constraint;
+mod gradient_descent;
+mod line_search;
+mod newton_raphson;
+
+use crate::{
+    PyErrGlue,
+    math::linalg::{check_length, check_matrix},
+};
+use conspire::math::{Scalar, SquareMatrix, Tensor, Vector, optimize};
+use numpy::PyArray1;
+use pyo3::prelude::*;
+
+pub use constraint::EqualityConstraint;
+pub use gradient_descent::GradientDescent;
+pub use line_search::LineSearch;
+pub use newton_raphson::NewtonRaphson;
+
+pub fn register_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<EqualityConstraint>()?;
+    m.add_class::<GradientDescent>()?;
+    m.add_class::<LineSearch>()?;
+    m.add_class::<NewtonRaphson>()
+}
+
+/// Returns the constraint for the initial guess, which cannot be combined with a line search if it is linear.
+fn constraint(
+    constraint: Option<PyRef<EqualityConstraint>>,
+    initial_guess: &[Scalar],
+    line_search: &optimize::LineSearch,
+) -> Result<optimize::EqualityConstraint, PyErrGlue> {
+    let constraint = match constraint {
+        Some(constraint) => constraint.get(initial_guess)?,
+        None => optimize::EqualityConstraint::None,
+    };
+    if matches!(constraint, optimize::EqualityConstraint::Linear(..))
+        && !matches!(line_search, optimize::LineSearch::None)
+    {
+        Err(PyErrGlue::new(
+            "A line search cannot be used with an equality constraint.",
+        ))
+    } else {
+        Ok(constraint)
+    }
+}
+
+fn call<'py>(function: &Bound<'py, PyAny>, x: &Vector) -> Result<Bound<'py, PyAny>, String> {
+    function
+        .call1((PyArray1::from_iter(function.py(), x.iter().copied()),))
+        .map_err(|error| error.to_string())
+}
+
+fn scalar(function: &Bound<'_, PyAny>, x: &Vector) -> Result<Scalar, String> {
+    call(function, x)?
+        .extract()
+        .map_err(|error: PyErr| error.to_string())
+}
+
+fn vector(function: &Bound<'_, PyAny>, x: &Vector) -> Result<Vector, String> {
+    let vector: Vec<Scalar> = call(function, x)?
+        .extract()
+        .map_err(|error: PyErr| error.to_string())?;
+    check_length(x.len(), &vector).map_err(|error| error.message)?;
+    Ok(vector.into())
+}
+
+fn matrix(function: &Bound<'_, PyAny>, x: &Vector) -> Result<Vec<Vec<Scalar>>, String> {
+    let matrix: Vec<Vec<Scalar>> = call(function, x)?
+        .extract()
+        .map_err(|error: PyErr| error.to_string())?;
+    check_matrix(x.len(), &matrix).map_err(|error| error.message)?;
+    Ok(matrix)
+}
+
+fn square_matrix(matrix: Vec<Vec<Scalar>>) -> SquareMatrix {
+    matrix.into_iter().map(Vector::from).collect()
+}
@@ -0,0 +1,166 @@
+use super::{LineSearch, constraint, matrix, scalar, square_matrix, vector};
+use crate::{
+    PyErrGlue,
+    math::{linalg::symmetric_eigen, optimize::EqualityConstraint},
+};
+use conspire::math::{
+    Scalar, SquareMatrix, Tensor, Vector,
+    optimize::{self, FirstOrderRootFinding, OptimizationError, SecondOrderOptimization},
+};
+use numpy::PyArray1;
+use pyo3::{prelude::*, types::PyDict};
+
+const MIN_CURVATURE: Scalar = 1e-4;
+
+/// Shifts the Hessian by a multiple of the identity if needed so that its smallest eigenvalue is positive relative to its largest, which makes each step a descent direction.
+fn positive_definite(mut hessian: Vec<Vec<Scalar>>) -> Vec<Vec<Scalar>> {
+    let (eigenvalues, _) = symmetric_eigen(hessian.clone());
+    let scale = eigenvalues
+        .iter()
+        .fold(1.0, |scale: Scalar, eigenvalue| scale.max(eigenvalue.abs()));
+    let minimum = eigenvalues.into_iter().fold(Scalar::INFINITY, Scalar::min);
+    let shift = MIN_CURVATURE * scale - minimum;
+    if shift > 0.0 {
+        hessian
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, row)| row[i] += shift);
+    }
+    hessian
+}
+
+/// Newton-Raphson iterations without a constraint, which upstream only provides for fixed-size tensors.
+fn unconstrained(
+    solver: &optimize::NewtonRaphson,
+    mut function: impl FnMut(&Vector) -> Result<Scalar, String>,
+    mut jacobian: impl FnMut(&Vector) -> Result<Vector, String>,
+    mut hessian: impl FnMut(&Vector) -> Result<SquareMatrix, String>,
+    initial_guess: Vector,
+) -> Result<Vector, OptimizationError> {
+    let mut solution = initial_guess;
+    for _ in 0..=solver.max_steps {
+        let residual = jacobian(&solution)?;
+        if residual.norm_inf() < solver.abs_tol {
+            return Ok(solution);
+        }
+        let mut decrement = hessian(&solution)?.solve_lu(&residual)?;
+        if !matches!(solver.line_search, optimize::LineSearch::None) {
+            decrement *= solver
+                .line_search
+                .backtrack(
+                    &mut function,
+                    &mut jacobian,
+                    &solution,
+                    &residual,
+                    &decrement,
+                    1.0,
+                )
+                .map_err(|error| {
+                    OptimizationError::Upstream(format!("{error}"), format!("{solver:?}"))
+                })?;
+        }
+        solution -= decrement;
+    }
+    Err(OptimizationError::MaximumStepsReached(
+        solver.max_steps,
+        format!("{solver:?}"),
+    ))
+}
+
+/// The Newton-Raphson method for minimization and root finding.
+///
+/// The iterations stop once the infinity norm of the residual is below `abs_tol`.
+/// When minimizing, a Hessian that is not positive definite is shifted by a multiple of the identity, so that each step is a descent direction.
+#[pyclass]
+pub struct NewtonRaphson(optimize::NewtonRaphson);
+
+#[pymethods]
+impl NewtonRaphson {
+    #[new]
+    #[pyo3(signature = (**kwargs))]
+    fn new(kwargs: Option<&Bound<'_, PyDict>>) -> Result<Self, PyErr> {
+        let mut solver = optimize::NewtonRaphson::default();
+        if let Some(args) = kwargs {
+            args.into_iter().try_for_each(|(name, value)| {
+                match name.extract()? {
+                    "abs_tol" => solver.abs_tol = value.extract()?,
+                    "line_search" => {
+                        solver.line_search = value.extract::<PyRef<LineSearch>>()?.get()
+                    }
+                    "max_steps" => solver.max_steps = value.extract()?,
+                    _ => (),
+                };
+                Ok::<(), PyErr>(())
+            })?
+        }
+        Ok(Self(solver))
+    }
+    /// @private
+    #[getter]
+    pub fn abs_tol(&self) -> Scalar {
+        self.0.abs_tol
+    }
+    /// @private
+    #[getter]
+    pub fn max_steps(&self) -> usize {
+        self.0.max_steps
+    }
+    /// Minimizes the function given its gradient and Hessian, optionally subject to an equality constraint.
+    #[pyo3(signature = (function, gradient, hessian, initial_guess, constraint = None))]
+    fn minimize<'py>(
+        &self,
+        function: Bound<'py, PyAny>,
+        gradient: Bound<'py, PyAny>,
+        hessian: Bound<'py, PyAny>,
+        initial_guess: Vec<Scalar>,
+        constraint: Option<PyRef<EqualityConstraint>>,
+    ) -> Result<Bound<'py, PyArray1<Scalar>>, PyErrGlue> {
+        let py = function.py();
+        let constraint = self::constraint(constraint, &initial_guess, &self.0.line_search)?;
+        let function = |x: &Vector| scalar(&function, x);
+        let gradient = |x: &Vector| vector(&gradient, x);
+        let hessian = |x: &Vector| Ok(square_matrix(positive_definite(matrix(&hessian, x)?)));
+        let initial_guess = Vector::from(initial_guess);
+        let solution = match constraint {
+            optimize::EqualityConstraint::None => {
+                unconstrained(&self.0, function, gradient, hessian, initial_guess)?
+            }
+            constraint => {
+                self.0
+                    .minimize(function, gradient, hessian, initial_guess, constraint, None)?
+            }
+        };
+        Ok(PyArray1::from_vec(py, solution.into()))
+    }
+    /// Finds a root of the function given its Jacobian, optionally subject to an equality constraint.
+    #[pyo3(signature = (function, jacobian, initial_guess, constraint = None))]
+    fn root<'py>(
+        &self,
+        function: Bound<'py, PyAny>,
+        jacobian: Bound<'py, PyAny>,
+        initial_guess: Vec<Scalar>,
+        constraint: Option<PyRef<EqualityConstraint>>,
+    ) -> Result<Bound<'py, PyArray1<Scalar>>, PyErrGlue> {
+        if !matches!(self.0.line_search, optimize::LineSearch::None) {
+            return Err(PyErrGlue::new(
+                "A line search cannot be used when finding a root.",
+            ));
+        }
+        let py = function.py();
+        let constraint = self::constraint(constraint, &initial_guess, &self.0.line_search)?;
+        let function = |x: &Vector| vector(&function, x);
+        let jacobian = |x: &Vector| Ok(square_matrix(matrix(&jacobian, x)?));
+        let initial_guess = Vector::from(initial_guess);
+        let solution = match constraint {
+            optimize::EqualityConstraint::None => unconstrained(
+                &self.0,
+                |_: &Vector| Ok(0.0),
+                function,
+                jacobian,
+                initial_guess,
+            )?,
+            constraint => self.0.root(function, jacobian, initial_guess, constraint)?,
+        };
+        Ok(PyArray1::from_vec(py, solution.into()))
+    }
+}
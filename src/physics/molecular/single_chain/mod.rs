@@ -26,9 +26,7 @@ pub fn register_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
 impl From<SingleChainError> for PyErrGlue {
     fn from(error: SingleChainError) -> Self {
-        PyErrGlue {
-            message: error.to_string(),
-        }
+        PyErrGlue::new(&error.to_string())
     }
 }
 
@@ -0,0 +1,54 @@
+from conspire import benchmark
+from pytest import raises
+
+
+models = [
+    "AlmansiHamel",
+    "ArrudaBoyce",
+    "Fung",
+    "Gent",
+    "Hencky",
+    "MooneyRivlin",
+    "NeoHookean",
+    "Plasticity",
+    "SaintVenantKirchhoff",
+]
+options = {"samples": 3, "warm_up_time": 0.0, "measurement_time": 1e-4}
+
+
+def test_benchmarks():
+    results = benchmark(divisions=[1, 2], **options)
+    expected = {"nodal_conductances/HeatConduction/6"}
+    expected |= {"nodal_conductances/HeatConduction/48"}
+    for model in models:
+        expected.add(f"cauchy_stress/{model}")
+        for elements in [6, 48]:
+            expected.add(f"nodal_forces/{model}/{elements}")
+            expected.add(f"nodal_stiffnesses/{model}/{elements}")
+    assert set(results) == expected
+    for statistics in results.values():
+        assert statistics["samples"] == 3
+        assert statistics["iterations"] >= 1
+        assert 0 < statistics["min"] <= statistics["median"] <= statistics["max"]
+        assert statistics["min"] <= statistics["mean"] <= statistics["max"]
+        assert statistics["std"] >= 0
+
+
+def test_filter():
+    results = benchmark(divisions=[1, 2], filter="stiffnesses/NeoHookean", **options)
+    assert set(results) == {
+        "nodal_stiffnesses/NeoHookean/6",
+        "nodal_stiffnesses/NeoHookean/48",
+    }
+    assert benchmark(filter="nothing", **options) == {}
+
+
+def test_invalid_inputs():
+    with raises(TypeError, match="The number of samples must be positive."):
+        benchmark(samples=0)
+    with raises(TypeError, match="The warm-up time must be nonnegative."):
+        benchmark(warm_up_time=-1)
+    with raises(TypeError, match="The measurement time must be positive."):
+        benchmark(measurement_time=0)
+    with raises(TypeError, match="The number of divisions must be positive."):
+        benchmark(divisions=[0], **options)
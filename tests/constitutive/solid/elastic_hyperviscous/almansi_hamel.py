@@ -0,0 +1,189 @@
+from conspire.constitutive.solid.elastic import AlmansiHamel as ElasticAlmansiHamel
+from conspire.constitutive.solid.elastic_hyperviscous import AlmansiHamel
+import numpy as np
+
+
+abs_tol = 1e-12
+epsilon = 1e-6
+bulk_modulus = 13
+shear_modulus = 3
+bulk_viscosity = 11
+shear_viscosity = 1
+zero = np.zeros((3, 3))
+identity = np.eye(3)
+deformation_gradient = np.array(
+    [
+        [0.63595746, 0.69157849, 0.71520784],
+        [0.80589604, 0.83687323, 0.19312595],
+        [0.05387420, 0.86551549, 0.41880244],
+    ]
+)
+deformation_gradient_rate = np.array(
+    [
+        [0.1, 0.2, 0.0],
+        [0.0, -0.1, 0.3],
+        [0.2, 0.0, 0.05],
+    ]
+)
+simple_shear_rate_small = np.array([[0, epsilon, 0], [0, 0, 0], [0, 0, 0]])
+volumetric_rate_small = identity * epsilon / 3
+
+model = AlmansiHamel(bulk_modulus, shear_modulus, bulk_viscosity, shear_viscosity)
+
+
+def test_str():
+    assert (
+        model.__str__()
+        == "AlmansiHamel("
+        + f"bulk_modulus={bulk_modulus}, shear_modulus={shear_modulus}, "
+        + f"bulk_viscosity={bulk_viscosity}, shear_viscosity={shear_viscosity})"
+    )
+
+
+def test_cauchy_stress_zero():
+    assert (model.cauchy_stress(identity, zero) == zero).all()
+
+
+def test_cauchy_stress_elastic():
+    elastic = ElasticAlmansiHamel(bulk_modulus, shear_modulus)
+    assert (
+        np.abs(
+            model.cauchy_stress(deformation_gradient, zero)
+            - elastic.cauchy_stress(deformation_gradient)
+        )
+        < abs_tol
+    ).all()
+
+
+def test_cauchy_stress_relate_first_piola_kirchhoff_stress():
+    assert (
+        np.abs(
+            model.cauchy_stress(deformation_gradient, deformation_gradient_rate)
+            - model.first_piola_kirchhoff_stress(
+                deformation_gradient, deformation_gradient_rate
+            ).dot(deformation_gradient.T)
+            / np.linalg.det(deformation_gradient)
+        )
+        < abs_tol
+    ).all()
+
+
+def test_cauchy_stress_relate_second_piola_kirchhoff_stress():
+    assert (
+        np.abs(
+            model.cauchy_stress(deformation_gradient, deformation_gradient_rate)
+            - deformation_gradient.dot(
+                model.second_piola_kirchhoff_stress(
+                    deformation_gradient, deformation_gradient_rate
+                )
+            ).dot(deformation_gradient.T)
+            / np.linalg.det(deformation_gradient)
+        )
+        < abs_tol
+    ).all()
+
+
+def test_shear_viscosity():
+    assert (
+        np.abs(
+            model.cauchy_stress(identity, simple_shear_rate_small)[0, 1] / epsilon
+            - shear_viscosity
+        )
+        < epsilon
+    )
+
+
+def test_bulk_viscosity():
+    assert (
+        np.abs(
+            model.cauchy_stress(identity, volumetric_rate_small).trace()
+            / 3
+            / epsilon
+            / bulk_viscosity
+            - 1
+        )
+        < epsilon
+    )
+
+
+def test_viscous_dissipation():
+    assert model.viscous_dissipation(deformation_gradient, zero) == 0
+    assert model.viscous_dissipation(
+        deformation_gradient, deformation_gradient_rate
+    ) > 0
+    assert (
+        np.abs(
+            model.dissipation_potential(deformation_gradient, deformation_gradient_rate)
+            - model.viscous_dissipation(deformation_gradient, deformation_gradient_rate)
+            - (
+                model.first_piola_kirchhoff_stress(deformation_gradient, zero)
+                * deformation_gradient_rate
+            ).sum()
+        )
+        < abs_tol
+    )
+
+
+def test_first_piola_kirchhoff_stress_derivative_dissipation_potential():
+    stress = model.first_piola_kirchhoff_stress(
+        deformation_gradient, deformation_gradient_rate
+    )
+    for k in range(3):
+        for m in range(3):
+            deformation_gradient_rate[k, m] += epsilon / 2
+            d_potential = model.dissipation_potential(
+                deformation_gradient, deformation_gradient_rate
+            )
+            deformation_gradient_rate[k, m] -= epsilon
+            d_potential -= model.dissipation_potential(
+                deformation_gradient, deformation_gradient_rate
+            )
+            assert np.abs(stress[k, m] - d_potential / epsilon) < epsilon
+            deformation_gradient_rate[k, m] += epsilon / 2
+
+
+def test_cauchy_rate_tangent_stiffness_finite_difference():
+    tan = model.cauchy_rate_tangent_stiffness(
+        deformation_gradient, deformation_gradient_rate
+    )
+    for i in range(3):
+        for j in range(3):
+            for k in range(3):
+                for m in range(3):
+                    deformation_gradient_rate[k, m] += epsilon / 2
+                    d_stress = model.cauchy_stress(
+                        deformation_gradient, deformation_gradient_rate
+                    )[i, j]
+                    deformation_gradient_rate[k, m] -= epsilon
+                    d_stress -= model.cauchy_stress(
+                        deformation_gradient, deformation_gradient_rate
+                    )[i, j]
+                    assert np.abs(tan[i, j, k, m] - d_stress / epsilon) < epsilon
+                    deformation_gradient_rate[k, m] += epsilon / 2
+
+
+def test_first_piola_kirchhoff_rate_tangent_stiffness_symmetry():
+    tan = model.first_piola_kirchhoff_rate_tangent_stiffness(
+        deformation_gradient, deformation_gradient_rate
+    )
+    assert (np.abs(tan - tan.transpose(2, 3, 0, 1)) < abs_tol).all()
+
+
+def test_second_piola_kirchhoff_rate_tangent_stiffness_finite_difference():
+    tan = model.second_piola_kirchhoff_rate_tangent_stiffness(
+        deformation_gradient, deformation_gradient_rate
+    )
+    for i in range(3):
+        for j in range(3):
+            for k in range(3):
+                for m in range(3):
+                    deformation_gradient_rate[k, m] += epsilon / 2
+                    d_stress = model.second_piola_kirchhoff_stress(
+                        deformation_gradient, deformation_gradient_rate
+                    )[i, j]
+                    deformation_gradient_rate[k, m] -= epsilon
+                    d_stress -= model.second_piola_kirchhoff_stress(
+                        deformation_gradient, deformation_gradient_rate
+                    )[i, j]
+                    assert np.abs(tan[i, j, k, m] - d_stress / epsilon) < epsilon
+                    deformation_gradient_rate[k, m] += epsilon / 2
@@ -0,0 +1,123 @@
+from conspire.constitutive.solid.hyperelastic import (
+    SaintVenantKirchhoff as HyperelasticSaintVenantKirchhoff,
+)
+from conspire.constitutive.solid.hyperviscoelastic import SaintVenantKirchhoff
+import numpy as np
+
+
+abs_tol = 1e-12
+epsilon = 1e-6
+bulk_modulus = 13
+shear_modulus = 3
+bulk_viscosity = 11
+shear_viscosity = 1
+zero = np.zeros((3, 3))
+identity = np.eye(3)
+deformation_gradient = np.array(
+    [
+        [0.63595746, 0.69157849, 0.71520784],
+        [0.80589604, 0.83687323, 0.19312595],
+        [0.05387420, 0.86551549, 0.41880244],
+    ]
+)
+deformation_gradient_rate = np.array(
+    [
+        [0.1, 0.2, 0.0],
+        [0.0, -0.1, 0.3],
+        [0.2, 0.0, 0.05],
+    ]
+)
+
+model = SaintVenantKirchhoff(
+    bulk_modulus, shear_modulus, bulk_viscosity, shear_viscosity
+)
+hyperelastic = HyperelasticSaintVenantKirchhoff(bulk_modulus, shear_modulus)
+
+
+def test_str():
+    assert (
+        model.__str__()
+        == "SaintVenantKirchhoff("
+        + f"bulk_modulus={bulk_modulus}, shear_modulus={shear_modulus}, "
+        + f"bulk_viscosity={bulk_viscosity}, shear_viscosity={shear_viscosity})"
+    )
+
+
+def test_second_piola_kirchhoff_stress_zero():
+    assert (model.second_piola_kirchhoff_stress(identity, zero) == zero).all()
+
+
+def test_hyperelastic():
+    assert (
+        np.abs(
+            model.first_piola_kirchhoff_stress(deformation_gradient, zero)
+            - hyperelastic.first_piola_kirchhoff_stress(deformation_gradient)
+        )
+        < abs_tol
+    ).all()
+    assert (
+        np.abs(
+            model.helmholtz_free_energy_density(deformation_gradient)
+            - hyperelastic.helmholtz_free_energy_density(deformation_gradient)
+        )
+        < abs_tol
+    )
+
+
+def test_cauchy_stress_relate_first_piola_kirchhoff_stress():
+    assert (
+        np.abs(
+            model.cauchy_stress(deformation_gradient, deformation_gradient_rate)
+            - model.first_piola_kirchhoff_stress(
+                deformation_gradient, deformation_gradient_rate
+            ).dot(deformation_gradient.T)
+            / np.linalg.det(deformation_gradient)
+        )
+        < abs_tol
+    ).all()
+
+
+def test_viscous_dissipation():
+    assert model.viscous_dissipation(deformation_gradient, zero) == 0
+    assert (
+        model.viscous_dissipation(deformation_gradient, deformation_gradient_rate) > 0
+    )
+
+
+def test_first_piola_kirchhoff_stress_derivative_dissipation_potential():
+    stress = model.first_piola_kirchhoff_stress(
+        deformation_gradient, deformation_gradient_rate
+    )
+    for k in range(3):
+        for m in range(3):
+            deformation_gradient_rate[k, m] += epsilon / 2
+            d_potential = model.dissipation_potential(
+                deformation_gradient, deformation_gradient_rate
+            )
+            deformation_gradient_rate[k, m] -= epsilon
+            d_potential -= model.dissipation_potential(
+                deformation_gradient, deformation_gradient_rate
+            )
+            assert np.abs(stress[k, m] - d_potential / epsilon) < epsilon
+            deformation_gradient_rate[k, m] += epsilon / 2
+
+
+def test_first_piola_kirchhoff_rate_tangent_stiffness_finite_difference():
+    tan = model.first_piola_kirchhoff_rate_tangent_stiffness(
+        deformation_gradient, deformation_gradient_rate
+    )
+    assert (np.abs(tan - tan.transpose(2, 3, 0, 1)) < abs_tol).all()
+    for i in range(3):
+        for j in range(3):
+            for k in range(3):
+                for m in range(3):
+                    deformation_gradient_rate[k, m] += epsilon / 2
+                    d_stress = model.first_piola_kirchhoff_stress(
+                        deformation_gradient, deformation_gradient_rate
+                    )[i, j]
+                    deformation_gradient_rate[k, m] -= epsilon
+                    d_stress -= model.first_piola_kirchhoff_stress(
+                        deformation_gradient, deformation_gradient_rate
+                    )[i, j]
+                    assert np.abs(tan[i, j, k, m] - d_stress / epsilon) < epsilon
+                    deformation_gradient_rate[k, m] += epsilon / 2
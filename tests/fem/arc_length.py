@@ -0,0 +1,149 @@
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import BodyForce, Block, Mesh, RigidPlane, Traction
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+bulk_modulus = 13
+shear_modulus = 3
+arc_length = 0.05
+top = [0, 1, 2, 3, 8]
+bottom = [4, 5, 6, 7, 9]
+free = [node for node in range(len(reference_coordinates)) if node not in bottom]
+top_faces = [[0, 1, 8], [1, 2, 8], [2, 3, 8], [3, 0, 8]]
+traction = np.array([0.0, 0.0, 1.0])
+model = NeoHookean(bulk_modulus, shear_modulus)
+block = Block(model, connectivity, reference_coordinates)
+load = Traction(traction, top_faces, reference_coordinates)
+
+
+def test_equilibrium_path():
+    path, load_factors = block.arc_length(
+        reference_coordinates, bottom, [load], arc_length, num_increments=5
+    )
+    assert path.shape == (6, len(reference_coordinates), 3)
+    assert load_factors.shape == (6,)
+    assert np.all(path[0] == reference_coordinates)
+    assert load_factors[0] == 0
+    assert np.all(np.diff(load_factors) > 0)
+    for coordinates, load_factor in zip(path, load_factors):
+        forces = block.nodal_forces(coordinates) + load_factor * load.nodal_forces()
+        assert np.all(np.abs(forces[free]) < 1e-8)
+        assert np.all(coordinates[bottom] == reference_coordinates[bottom])
+    for previous, current, increment in zip(path, path[1:], np.diff(load_factors)):
+        assert (
+            np.abs(np.sum((current - previous) ** 2) + increment**2 - arc_length**2)
+            < 1e-8
+        )
+
+
+def test_load_control():
+    path, load_factors = block.arc_length(
+        reference_coordinates, bottom, [load], arc_length, num_increments=3
+    )
+    for coordinates, load_factor in zip(path[1:], load_factors[1:]):
+        solution = block.solve(
+            reference_coordinates,
+            bottom,
+            loads=[Traction(load_factor * traction, top_faces, reference_coordinates)],
+            abs_tol=abs_tol,
+        )
+        assert np.all(np.abs(solution - coordinates) < 1e-8)
+
+
+def test_load_factor_weight():
+    load_factor_weight = 10
+    path, load_factors = block.arc_length(
+        reference_coordinates,
+        bottom,
+        [load],
+        arc_length,
+        num_increments=2,
+        load_factor_weight=load_factor_weight,
+    )
+    for previous, current, increment in zip(path, path[1:], np.diff(load_factors)):
+        assert (
+            np.abs(
+                np.sum((current - previous) ** 2)
+                + (load_factor_weight * increment) ** 2
+                - arc_length**2
+            )
+            < 1e-8
+        )
+
+
+def shallow_arch():
+    mesh = Mesh.box([10, 1, 0.1], [20, 1, 1])
+    arch_coordinates = mesh.reference_nodal_coordinates.copy()
+    arch_coordinates[:, 2] += 1 - (arch_coordinates[:, 0] / 5 - 1) ** 2
+    connectivity = mesh.blocks["block"]
+    clamped = list(mesh.node_sets["xmin"]) + list(mesh.node_sets["xmax"])
+    return (
+        Block(model, connectivity, arch_coordinates),
+        arch_coordinates,
+        clamped,
+        BodyForce([0, 0, -0.01], connectivity, arch_coordinates),
+    )
+
+
+def test_snap_through():
+    arch, arch_coordinates, clamped, weight = shallow_arch()
+    path, load_factors = arch.arc_length(
+        arch_coordinates, clamped, [weight], 0.6, num_increments=8
+    )
+    peak = np.argmax(load_factors)
+    assert 0 < peak < len(load_factors) - 1
+    assert load_factors[-1] < load_factors[peak]
+    crown = np.flatnonzero(
+        (arch_coordinates[:, 0] == 5) & (arch_coordinates[:, 1] == 0)
+    )
+    assert np.all(np.diff(path[:, crown, 2], axis=0) < 0)
+    unclamped = [node for node in range(len(arch_coordinates)) if node not in clamped]
+    for coordinates, load_factor in zip(path, load_factors):
+        forces = arch.nodal_forces(coordinates) + load_factor * weight.nodal_forces()
+        assert np.all(np.abs(forces[unclamped]) < 1e-8)
+
+
+def test_cutback():
+    arch, arch_coordinates, clamped, weight = shallow_arch()
+    with raises(TypeError, match="The maximum number of steps was reached."):
+        arch.arc_length(
+            arch_coordinates,
+            clamped,
+            [weight],
+            0.6,
+            num_increments=3,
+            max_cutbacks=0,
+            max_steps=4,
+        )
+    path, load_factors = arch.arc_length(
+        arch_coordinates, clamped, [weight], 0.6, num_increments=3, max_steps=4
+    )
+    lengths = np.sqrt(
+        np.sum(np.diff(path, axis=0) ** 2, axis=(1, 2)) + np.diff(load_factors) ** 2
+    )
+    assert np.all(lengths < 0.6 + 1e-8)
+    assert np.any(lengths < 0.5)
+
+
+def test_invalid():
+    with raises(TypeError, match="The arc length must be positive."):
+        block.arc_length(reference_coordinates, bottom, [load], 0)
+    with raises(
+        TypeError,
+        match="Contact loads are not supported by arc-length continuation.",
+    ):
+        block.arc_length(
+            reference_coordinates,
+            bottom,
+            [RigidPlane([0, 0, 0.6], [0, 0, -1], top, 1e3)],
+            arc_length,
+        )
+    with raises(TypeError, match="Fixed node 14 is out of range for 14 nodes."):
+        block.arc_length(reference_coordinates, [14], [load], arc_length)
+    with raises(TypeError, match="Unexpected keyword argument max_iterations."):
+        block.arc_length(
+            reference_coordinates, bottom, [load], arc_length, max_iterations=1
+        )
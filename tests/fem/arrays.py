@@ -0,0 +1,73 @@
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Block
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+model = NeoHookean(13, 3)
+coordinates = reference_coordinates @ np.array(
+    [
+        [1.1, 0.05, -0.02],
+        [0.03, 0.95, 0.04],
+        [-0.01, 0.02, 1.05],
+    ]
+).T
+forces = Block(model, connectivity, reference_coordinates).nodal_forces(coordinates)
+
+
+def test_dtypes():
+    for dtype in [np.int32, np.int64, np.uint32, np.uint64]:
+        block = Block(model, connectivity.astype(dtype), reference_coordinates)
+        assert np.all(block.nodal_forces(coordinates) == forces)
+    block = Block(model, connectivity.tolist(), reference_coordinates.tolist())
+    assert np.all(block.nodal_forces(coordinates) == forces)
+    block = Block(model, connectivity, reference_coordinates.astype(np.float32))
+    assert np.all(np.abs(block.nodal_forces(coordinates) - forces) < 1e-6)
+    block = Block(model, connectivity, np.asfortranarray(reference_coordinates))
+    assert np.all(block.nodal_forces(coordinates) == forces)
+
+
+def test_wrong_shapes():
+    expected = r"Expected reference nodal coordinates with shape \(N, 3\), got "
+    with raises(ValueError, match=expected + r"\(14, 2\)."):
+        Block(model, connectivity, reference_coordinates[:, :2])
+    with raises(ValueError, match=expected + r"\(42,\)."):
+        Block(model, connectivity, reference_coordinates.ravel())
+    with raises(
+        ValueError, match=r"Expected a connectivity with shape \(E, 4\), got \(24, 3\)."
+    ):
+        Block(model, connectivity[:, :3], reference_coordinates)
+
+
+def test_wrong_dtype():
+    with raises(ValueError, match="Expected a connectivity of integers, got float64."):
+        Block(model, connectivity.astype(float), reference_coordinates)
+
+
+def test_node_indices():
+    out_of_range = connectivity.copy()
+    out_of_range[3, 2] = 14
+    with raises(ValueError, match="Node 14 in element 3 is out of range for 14 nodes."):
+        Block(model, out_of_range, reference_coordinates)
+    negative = connectivity.copy()
+    negative[5, 0] = -1
+    with raises(ValueError, match="Node -1 in element 5 is out of range for 14 nodes."):
+        Block(model, negative, reference_coordinates)
+    duplicate = connectivity.copy()
+    duplicate[7, 3] = duplicate[7, 1]
+    with raises(ValueError, match="Element 7 has duplicate node 11."):
+        Block(model, duplicate, reference_coordinates)
+    with raises(ValueError, match="Node 14 is not used by any element."):
+        Block(model, connectivity, np.vstack([reference_coordinates, [[2, 2, 2]]]))
+
+
+def test_inverted_elements():
+    inverted = connectivity.copy()
+    inverted[2, [1, 2]] = inverted[2, [2, 1]]
+    with raises(TypeError, match="Element 2 has a non-positive reference volume."):
+        Block(model, inverted, reference_coordinates)
+    degenerate = reference_coordinates.copy()
+    degenerate[13] = degenerate[12]
+    with raises(TypeError, match="Element 0 has a non-positive reference volume."):
+        Block(model, connectivity, degenerate)
@@ -0,0 +1,163 @@
+from conspire.constitutive.solid.elastic import AlmansiHamel
+from conspire.constitutive.solid.hyperelastic import MooneyRivlin, NeoHookean
+from conspire.fem import Assembly, Block, InvertedElementError
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+epsilon = 1e-6
+bulk_modulus = 13
+shear_modulus = 3
+extra_modulus = 1
+deformed_coordinates = np.array(
+    [
+        [0.48419081, -0.52698494, 0.42026988],
+        [0.43559430, 0.52696224, 0.54477963],
+        [-0.56594965, 0.57076191, 0.51683869],
+        [-0.56061746, -0.42795457, 0.55275658],
+        [0.41878700, 0.53190268, -0.44744274],
+        [0.47232357, -0.57252738, -0.42946606],
+        [-0.45168197, -0.5102938, -0.57959825],
+        [-0.41776733, 0.41581785, -0.45911886],
+        [0.05946988, 0.03773822, 0.44149305],
+        [-0.08478334, -0.09009810, -0.46105872],
+        [-0.04039882, -0.58201398, 0.09346960],
+        [-0.57820738, 0.08325131, 0.03614415],
+        [-0.04145077, 0.56406301, 0.09988905],
+        [0.52149656, -0.08553510, -0.03187069],
+    ]
+)
+rubber = NeoHookean(bulk_modulus, shear_modulus)
+steel = MooneyRivlin(10 * bulk_modulus, 10 * shear_modulus, extra_modulus)
+elastic = AlmansiHamel(bulk_modulus, shear_modulus)
+
+block = Block(rubber, connectivity, reference_coordinates)
+uniform = Assembly(
+    [(rubber, connectivity[:12]), (rubber, connectivity[12:])],
+    reference_coordinates,
+)
+assembly = Assembly(
+    [(rubber, connectivity[:12]), (steel, connectivity[12:])],
+    reference_coordinates,
+)
+mixed = Assembly(
+    [(elastic, connectivity[:12]), (steel, connectivity[12:])],
+    reference_coordinates,
+)
+
+
+def test_uniform():
+    assert (
+        np.abs(
+            uniform.helmholtz_free_energy(deformed_coordinates)
+            - block.helmholtz_free_energy(deformed_coordinates)
+        )
+        < abs_tol
+    )
+    assert np.all(
+        np.abs(
+            uniform.nodal_forces(deformed_coordinates)
+            - block.nodal_forces(deformed_coordinates)
+        )
+        < abs_tol
+    )
+    assert np.all(
+        np.abs(
+            uniform.nodal_stiffnesses(deformed_coordinates)
+            - block.nodal_stiffnesses(deformed_coordinates)
+        )
+        < abs_tol
+    )
+
+
+def test_blocks():
+    forces = assembly.nodal_forces(deformed_coordinates)
+    assert np.all(
+        np.abs(
+            forces
+            - sum(
+                block.nodal_forces(deformed_coordinates) for block in assembly.blocks
+            )
+        )
+        < abs_tol
+    )
+
+
+def test_inverted():
+    split = Assembly(
+        [(rubber, connectivity[1:6]), (rubber, connectivity[6:])],
+        reference_coordinates,
+    )
+    inverted_coordinates = reference_coordinates.copy()
+    inverted_coordinates[13, 0] = -0.9
+    jacobians = Block(rubber, connectivity[1:], reference_coordinates).element_quality(
+        inverted_coordinates
+    )["jacobian"]
+    element = np.argmax(jacobians <= 0)
+    assert element >= 5
+    for method in [
+        split.nodal_forces,
+        split.nodal_stiffnesses,
+        split.helmholtz_free_energy,
+    ]:
+        with raises(InvertedElementError) as error:
+            method(inverted_coordinates)
+        assert error.value.element == element
+        assert f"Element {element} is inverted" in str(error.value)
+
+
+def test_helmholtz_free_energy_mixed():
+    elastic_block, _ = mixed.blocks
+    for system in [mixed, elastic_block]:
+        with raises(
+            TypeError,
+            match="The Helmholtz free energy density"
+            + " is undefined for elastic constitutive models.",
+        ):
+            system.helmholtz_free_energy(deformed_coordinates)
+
+
+def test_nodal_forces_zero():
+    assert np.all(assembly.nodal_forces(reference_coordinates) == 0.0)
+    assert np.all(mixed.nodal_forces(reference_coordinates) == 0.0)
+
+
+def test_nodal_forces_finite_difference():
+    forces = assembly.nodal_forces(deformed_coordinates)
+    for a in range(len(reference_coordinates)):
+        for i in range(3):
+            deformed_coordinates[a, i] += epsilon / 2
+            d_helmholtz = assembly.helmholtz_free_energy(deformed_coordinates)
+            deformed_coordinates[a, i] -= epsilon
+            d_helmholtz -= assembly.helmholtz_free_energy(deformed_coordinates)
+            assert np.abs(forces[a, i] - d_helmholtz / epsilon) < epsilon
+            deformed_coordinates[a, i] += epsilon / 2
+
+
+def test_nodal_stiffnesses_finite_difference():
+    tan = mixed.nodal_stiffnesses(deformed_coordinates)
+    for a in range(len(reference_coordinates)):
+        for b in range(len(reference_coordinates)):
+            for i in range(3):
+                for j in range(3):
+                    deformed_coordinates[b, j] += epsilon / 2
+                    d_force = mixed.nodal_forces(deformed_coordinates)[a, i]
+                    deformed_coordinates[b, j] -= epsilon
+                    d_force -= mixed.nodal_forces(deformed_coordinates)[a, i]
+                    assert np.abs(tan[a, b, i, j] - d_force / epsilon) < epsilon
+                    deformed_coordinates[b, j] += epsilon / 2
+
+
+def test_solve():
+    fixed_nodes = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
+    initial_coordinates = reference_coordinates.copy()
+    initial_coordinates[fixed_nodes, 2] *= 1.1
+    for system in [assembly, mixed]:
+        coordinates = system.solve(initial_coordinates, fixed_nodes, abs_tol=abs_tol)
+        assert np.all(coordinates[fixed_nodes] == initial_coordinates[fixed_nodes])
+        forces = system.nodal_forces(coordinates)
+        assert np.all(np.abs(forces[10:]) < abs_tol)
+        for block in system.blocks:
+            assert np.all(block.nodal_coordinates == coordinates)
@@ -0,0 +1,111 @@
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Block, LinearConstraint, RigidBody
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+bulk_modulus = 13
+shear_modulus = 3
+stretch = 0.1
+twist = 0.1
+top = [0, 1, 2, 3, 8]
+bottom = [4, 5, 6, 7, 9]
+reference_point = np.array([0.0, 0.0, 0.5])
+block = Block(
+    NeoHookean(bulk_modulus, shear_modulus), connectivity, reference_coordinates
+)
+
+
+def test_rigid_body_translation():
+    rigid = RigidBody(reference_point, top, translation=[0, 0, stretch])
+    coordinates = block.solve(
+        reference_coordinates, bottom, constraints=[rigid], abs_tol=abs_tol
+    )
+    assert np.all(
+        np.abs(coordinates[top] - reference_coordinates[top] - [0, 0, stretch])
+        < abs_tol
+    )
+    prescribed = reference_coordinates.copy()
+    prescribed[top, 2] += stretch
+    expected = block.solve(prescribed, top + bottom, abs_tol=abs_tol)
+    assert np.all(np.abs(coordinates - expected) < abs_tol)
+    forces = block.nodal_forces(coordinates)
+    assert np.all(np.abs(rigid.reaction_force - forces[top].sum(axis=0)) < abs_tol)
+    assert rigid.reaction_force[2] > 0
+
+
+def test_rigid_body_rotation():
+    rigid = RigidBody(reference_point, top, rotation=[0, 0, twist])
+    coordinates = block.solve(
+        reference_coordinates, bottom, constraints=[rigid], abs_tol=abs_tol
+    )
+    rotation = np.array(
+        [
+            [np.cos(twist), -np.sin(twist), 0],
+            [np.sin(twist), np.cos(twist), 0],
+            [0, 0, 1],
+        ]
+    )
+    assert np.all(
+        np.abs(
+            coordinates[top]
+            - reference_point
+            - (reference_coordinates[top] - reference_point) @ rotation.T
+        )
+        < abs_tol
+    )
+    assert np.all(
+        np.abs(rigid.nodal_coordinates(reference_coordinates) - coordinates)[top]
+        < abs_tol
+    )
+    forces = block.nodal_forces(coordinates)
+    moment = np.cross(coordinates[top] - reference_point, forces[top]).sum(axis=0)
+    assert np.all(np.abs(rigid.reaction_moment - moment) < abs_tol)
+    assert rigid.reaction_moment[2] > 0
+
+
+def test_linear_constraint():
+    tie = LinearConstraint([(11, 2, 1.0), (13, 2, -1.0)])
+    symmetry = LinearConstraint([(10, 0, 1.0)], value=reference_coordinates[10, 0])
+    coordinates = reference_coordinates.copy()
+    coordinates[top, 2] += stretch
+    coordinates[top, 0] += stretch
+    coordinates = block.solve(
+        coordinates, top + bottom, constraints=[tie, symmetry], abs_tol=abs_tol
+    )
+    assert np.abs(coordinates[11, 2] - coordinates[13, 2]) < abs_tol
+    assert np.abs(coordinates[10, 0] - reference_coordinates[10, 0]) < abs_tol
+    forces = block.nodal_forces(coordinates)
+    assert np.abs(forces[11, 2] + tie.multiplier) < abs_tol
+    assert np.abs(forces[13, 2] - tie.multiplier) < abs_tol
+    assert np.abs(forces[10, 0] + symmetry.multiplier) < abs_tol
+    assert np.abs(symmetry.multiplier) > abs_tol
+    assert np.all(np.abs(forces[12]) < abs_tol)
+
+
+def test_invalid():
+    with raises(TypeError, match="Coordinate 3 is out of range for 3 dimensions."):
+        LinearConstraint([(0, 3, 1.0)])
+    with raises(TypeError, match="Constrained node 14 is out of range for 14 nodes."):
+        block.solve(
+            reference_coordinates,
+            bottom,
+            constraints=[RigidBody(reference_point, [14])],
+        )
+    with raises(TypeError, match="Constrained node 14 is out of range for 14 nodes."):
+        block.solve(
+            reference_coordinates,
+            bottom,
+            constraints=[LinearConstraint([(14, 0, 1.0)])],
+        )
+    with raises(TypeError, match="Constraint 1 only involves fixed coordinates."):
+        block.solve(
+            reference_coordinates,
+            bottom,
+            constraints=[
+                LinearConstraint([(10, 0, 1.0)]),
+                LinearConstraint([(bottom[0], 0, 1.0), (11, 1, 0.0)]),
+            ],
+        )
@@ -0,0 +1,135 @@
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Block, RigidCylinder, RigidPlane
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+epsilon = 1e-6
+bulk_modulus = 13
+shear_modulus = 3
+penalty = 1e3
+compression = 0.05
+top_nodes = [0, 1, 2, 3, 8]
+bottom_nodes = [4, 5, 6, 7, 9]
+model = NeoHookean(bulk_modulus, shear_modulus)
+block = Block(model, connectivity, reference_coordinates)
+np.random.seed(0)
+perturbed_coordinates = reference_coordinates + 0.05 * np.random.rand(
+    *reference_coordinates.shape
+)
+
+
+def compressed_coordinates():
+    coordinates = reference_coordinates.copy()
+    coordinates[top_nodes, 2] -= compression
+    return coordinates
+
+
+def press(contact):
+    coordinates = block.solve(
+        compressed_coordinates(), top_nodes, loads=[contact], abs_tol=abs_tol
+    )
+    forces = block.nodal_forces(coordinates) + contact.nodal_forces(coordinates)
+    free_nodes = [node for node in range(len(coordinates)) if node not in top_nodes]
+    assert (np.abs(forces[free_nodes]) < abs_tol).all()
+    return coordinates
+
+
+def check_stiffnesses(contact, coordinates):
+    stiffnesses = contact.nodal_stiffnesses(coordinates)
+    for b in range(len(coordinates)):
+        for j in range(3):
+            coordinates[b, j] += epsilon / 2
+            forces = contact.nodal_forces(coordinates)
+            coordinates[b, j] -= epsilon
+            forces -= contact.nodal_forces(coordinates)
+            coordinates[b, j] += epsilon / 2
+            assert (np.abs(stiffnesses[:, b, :, j] - forces / epsilon) < epsilon).all()
+
+
+def test_rigid_plane_penalty():
+    plane = RigidPlane([0, 0, -0.5], [0, 0, 1], bottom_nodes, penalty)
+    coordinates = press(plane)
+    gaps = plane.gaps(coordinates)
+    assert (gaps < 0).all()
+    forces = block.nodal_forces(coordinates)
+    contact_forces = plane.nodal_forces(coordinates)
+    assert np.abs(forces[top_nodes, 2].sum() - contact_forces[:, 2].sum()) < 1e-8
+    assert (np.abs(contact_forces[bottom_nodes, 2] - penalty * gaps) < abs_tol).all()
+    assert (np.abs(contact_forces[bottom_nodes, :2]) < abs_tol).all()
+
+
+def test_rigid_plane_augmented():
+    penalty_only = RigidPlane([0, 0, -0.5], [0, 0, 1], bottom_nodes, penalty)
+    plane = RigidPlane(
+        [0, 0, -0.5], [0, 0, 1], bottom_nodes, penalty, augmented=True, abs_tol=1e-8
+    )
+    gaps = plane.gaps(press(plane))
+    assert (np.abs(gaps) <= 1e-8).all()
+    assert (plane.multipliers > 0).all()
+    assert np.abs(gaps).max() < np.abs(penalty_only.gaps(press(penalty_only))).max()
+
+
+def test_rigid_plane_friction():
+    frictionless = RigidPlane([0, 0, -0.5], [0, 0, 1], bottom_nodes, penalty)
+    plane = RigidPlane([0, 0, -0.5], [0, 0, 1], bottom_nodes, penalty, friction=10)
+    reference = reference_coordinates[bottom_nodes, :2]
+    sliding = press(frictionless)[bottom_nodes, :2] - reference
+    sticking = press(plane)[bottom_nodes, :2] - reference
+    assert np.abs(sticking).max() < 0.1 * np.abs(sliding).max()
+
+
+def test_rigid_plane_stiffnesses():
+    plane = RigidPlane([0, 0, -0.45], [0, 0.1, 1], bottom_nodes, penalty)
+    check_stiffnesses(plane, perturbed_coordinates.copy())
+
+
+def test_rigid_cylinder_stiffnesses():
+    for inside in [False, True]:
+        cylinder = RigidCylinder(
+            [0, 0.2, 0.1], [1, 0, 0], 0.6, list(range(14)), penalty, inside=inside
+        )
+        assert (cylinder.gaps(perturbed_coordinates) < 0).any()
+        check_stiffnesses(cylinder, perturbed_coordinates.copy())
+
+
+def test_rigid_cylinder_gaps():
+    cylinder = RigidCylinder([0, 0, 0], [0, 0, 1], 0.25, [8, 10], penalty)
+    gaps = cylinder.gaps(reference_coordinates)
+    assert np.abs(gaps[0] + 0.25) < abs_tol
+    assert np.abs(gaps[1] - 0.25) < abs_tol
+    inside = RigidCylinder([0, 0, 0], [0, 0, 1], 0.25, [8, 10], penalty, inside=True)
+    assert (np.abs(inside.gaps(reference_coordinates) + gaps) < abs_tol).all()
+
+
+def test_invalid():
+    with raises(TypeError):
+        RigidPlane([0, 0, 0], [0, 0, 0], bottom_nodes, penalty)
+    with raises(TypeError):
+        RigidPlane([0, 0, 0], [0, 0, 1], bottom_nodes, -penalty)
+    with raises(TypeError):
+        RigidCylinder([0, 0, 0], [0, 0, 1], 0, bottom_nodes, penalty)
+    with raises(TypeError, match="Unexpected keyword argument inside."):
+        RigidPlane([0, 0, 0], [0, 0, 1], bottom_nodes, penalty, inside=True)
+    with raises(TypeError, match="Contact node 9 is repeated at position 5."):
+        RigidPlane([0, 0, 0], [0, 0, 1], bottom_nodes + [9], penalty)
+    plane = RigidPlane([0, 0, 0], [0, 0, 1], [14], penalty)
+    with raises(TypeError, match="Contact node 14 is out of range for 14 nodes."):
+        plane.gaps(reference_coordinates)
+    with raises(TypeError, match="Contact node 14 is out of range for 14 nodes."):
+        plane.nodal_forces(reference_coordinates)
+    with raises(TypeError, match="Contact node 14 is out of range for 14 nodes."):
+        block.solve(reference_coordinates, bottom_nodes, loads=[plane])
+
+
+def test_rigid_cylinder_on_axis():
+    cylinder = RigidCylinder([0, 0, 0], [0, 0, 1], 0.25, [8], penalty)
+    message = "Contact node 8 is on the axis, where the normal is undefined."
+    with raises(TypeError, match=message):
+        cylinder.nodal_forces(reference_coordinates)
+    with raises(TypeError, match=message):
+        cylinder.nodal_stiffnesses(reference_coordinates)
+    inside = RigidCylinder([0, 0, 0], [0, 0, 1], 0.25, [8], penalty, inside=True)
+    assert (np.abs(inside.nodal_forces(reference_coordinates)) == 0).all()
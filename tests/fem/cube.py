@@ -0,0 +1,49 @@
+import numpy as np
+
+
+connectivity = np.array(
+    [
+        [13, 12, 8, 1],
+        [10, 3, 0, 8],
+        [11, 10, 8, 3],
+        [12, 11, 8, 2],
+        [11, 2, 3, 8],
+        [12, 2, 8, 1],
+        [13, 10, 5, 0],
+        [13, 11, 10, 8],
+        [10, 6, 9, 5],
+        [12, 7, 4, 9],
+        [12, 11, 7, 9],
+        [11, 7, 9, 6],
+        [13, 1, 8, 0],
+        [13, 9, 4, 5],
+        [13, 12, 1, 4],
+        [11, 10, 6, 9],
+        [11, 10, 3, 6],
+        [12, 11, 2, 7],
+        [13, 11, 9, 10],
+        [13, 12, 4, 9],
+        [13, 10, 0, 8],
+        [13, 10, 9, 5],
+        [13, 12, 11, 8],
+        [13, 12, 9, 11],
+    ]
+)
+reference_coordinates = np.array(
+    [
+        [0.5, -0.5, 0.5],
+        [0.5, 0.5, 0.5],
+        [-0.5, 0.5, 0.5],
+        [-0.5, -0.5, 0.5],
+        [0.5, 0.5, -0.5],
+        [0.5, -0.5, -0.5],
+        [-0.5, -0.5, -0.5],
+        [-0.5, 0.5, -0.5],
+        [0.0, 0.0, 0.5],
+        [0.0, 0.0, -0.5],
+        [0.0, -0.5, 0.0],
+        [-0.5, 0.0, 0.0],
+        [0.0, 0.5, 0.0],
+        [0.5, 0.0, 0.0],
+    ]
+)
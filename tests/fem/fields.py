@@ -0,0 +1,125 @@
+from conspire.constitutive.solid.elastic import AlmansiHamel
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Block
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+bulk_modulus = 13
+shear_modulus = 3
+deformation_gradient = np.array(
+    [
+        [1.1, 0.2, -0.1],
+        [0.1, 0.9, 0.05],
+        [-0.2, 0.1, 1.05],
+    ]
+)
+nodal_coordinates = reference_coordinates.dot(deformation_gradient.T)
+model = NeoHookean(bulk_modulus, shear_modulus)
+block = Block(model, connectivity, reference_coordinates)
+np.random.seed(0)
+perturbed_coordinates = nodal_coordinates + 0.05 * np.random.rand(
+    *nodal_coordinates.shape
+)
+
+
+def volumes():
+    return np.array(
+        [
+            np.linalg.det(
+                reference_coordinates[element[1:]] - reference_coordinates[element[0]]
+            )
+            / 6
+            for element in connectivity
+        ]
+    )
+
+
+def lumped_masses():
+    masses = np.zeros(len(reference_coordinates))
+    for element, volume in zip(connectivity, volumes()):
+        masses[element] += volume / 4
+    return masses
+
+
+def test_deformation_gradients():
+    deformation_gradients = block.deformation_gradients(nodal_coordinates)
+    assert deformation_gradients.shape == (len(connectivity), 1, 3, 3)
+    assert (np.abs(deformation_gradients - deformation_gradient) < abs_tol).all()
+
+
+def test_deformation_gradients_perturbed():
+    deformation_gradients = block.deformation_gradients(perturbed_coordinates)
+    for element, deformation_gradient in zip(connectivity, deformation_gradients):
+        edges = perturbed_coordinates[element[1:]] - perturbed_coordinates[element[0]]
+        reference_edges = (
+            reference_coordinates[element[1:]] - reference_coordinates[element[0]]
+        )
+        assert (
+            np.abs(reference_edges.dot(deformation_gradient[0].T) - edges) < abs_tol
+        ).all()
+
+
+def test_cauchy_stresses():
+    cauchy_stresses = block.cauchy_stresses(perturbed_coordinates)
+    deformation_gradients = block.deformation_gradients(perturbed_coordinates)
+    assert cauchy_stresses.shape == (len(connectivity), 1, 3, 3)
+    for cauchy_stress, deformation_gradient in zip(
+        cauchy_stresses, deformation_gradients
+    ):
+        assert (
+            np.abs(cauchy_stress[0] - model.cauchy_stress(deformation_gradient[0]))
+            < abs_tol
+        ).all()
+
+
+def test_helmholtz_free_energy_densities():
+    densities = block.helmholtz_free_energy_densities(perturbed_coordinates)
+    assert densities.shape == (len(connectivity), 1)
+    assert (
+        np.abs(
+            densities[:, 0].dot(volumes())
+            - block.helmholtz_free_energy(perturbed_coordinates)
+        )
+        < abs_tol
+    )
+
+
+def test_helmholtz_free_energy_densities_elastic():
+    elastic = Block(
+        AlmansiHamel(bulk_modulus, shear_modulus), connectivity, reference_coordinates
+    )
+    with raises(TypeError):
+        elastic.helmholtz_free_energy_densities(nodal_coordinates)
+
+
+def test_project_to_nodes_uniform():
+    cauchy_stresses = block.cauchy_stresses(nodal_coordinates)
+    cauchy_stress = model.cauchy_stress(deformation_gradient)
+    for lumped in [False, True]:
+        nodal = block.project_to_nodes(cauchy_stresses, lumped=lumped)
+        assert nodal.shape == (len(reference_coordinates), 3, 3)
+        assert (np.abs(nodal - cauchy_stress) < abs_tol).all()
+
+
+def test_project_to_nodes_integral():
+    densities = block.helmholtz_free_energy_densities(perturbed_coordinates)
+    energy = block.helmholtz_free_energy(perturbed_coordinates)
+    for lumped in [False, True]:
+        nodal = block.project_to_nodes(densities, lumped=lumped)
+        assert nodal.shape == (len(reference_coordinates),)
+        assert np.abs(nodal.dot(lumped_masses()) - energy) < abs_tol
+
+
+def test_project_to_nodes_shape():
+    with raises(TypeError):
+        block.project_to_nodes(np.zeros((len(connectivity) - 1, 1, 3)))
+    with raises(TypeError):
+        block.project_to_nodes(np.zeros(len(connectivity)))
+
+
+def test_wrong_number_of_nodes():
+    with raises(TypeError):
+        block.cauchy_stresses(nodal_coordinates[:-1])
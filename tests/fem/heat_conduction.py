@@ -0,0 +1,138 @@
+from conspire.fem import HeatConductionBlock
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+thermal_conductivity = 2
+heat_capacity = 3
+top = [0, 1, 2, 3, 8]
+bottom = [4, 5, 6, 7, 9]
+free = [10, 11, 12, 13]
+block = HeatConductionBlock(
+    connectivity, reference_coordinates, thermal_conductivity, heat_capacity
+)
+steady = reference_coordinates[:, 2] + 0.5
+initial = steady.copy()
+initial[free] = 0
+
+
+def test_matrices():
+    conductances = block.nodal_conductances()
+    assert np.all(np.abs(conductances - conductances.T) < abs_tol)
+    assert np.all(np.abs(conductances.sum(axis=1)) < abs_tol)
+    assert np.all(np.linalg.eigvalsh(conductances) > -abs_tol)
+    capacitances = block.nodal_capacitances()
+    assert np.all(np.abs(capacitances - capacitances.T) < abs_tol)
+    assert np.abs(capacitances.sum() - heat_capacity) < abs_tol
+    lumped = block.nodal_capacitances(lumped=True)
+    assert np.all(np.abs(np.diag(lumped) - capacitances.sum(axis=1)) < abs_tol)
+    assert np.all(lumped == np.diag(np.diag(lumped)))
+
+
+def test_nodal_fluxes():
+    assert np.all(np.abs(block.nodal_fluxes(np.ones(len(steady)))) < abs_tol)
+    fluxes = block.nodal_fluxes(steady)
+    assert np.all(np.abs(fluxes - block.nodal_conductances() @ steady) < abs_tol)
+    assert np.all(np.abs(fluxes[free]) < abs_tol)
+    assert np.abs(fluxes[top].sum() - thermal_conductivity) < abs_tol
+
+
+def test_steady():
+    temperatures = block.solve(initial, top + bottom)
+    assert np.all(np.abs(temperatures - steady) < abs_tol)
+    heat_sources = np.zeros(len(steady))
+    heat_sources[free] = 0.1
+    temperatures = block.solve(initial, top + bottom, heat_sources=heat_sources)
+    assert np.all(temperatures[free] > steady[free])
+    assert np.all(
+        np.abs(block.nodal_fluxes(temperatures)[free] - heat_sources[free]) < abs_tol
+    )
+
+
+def test_transient():
+    times = np.linspace(0, 0.1, 11)
+    conductances = block.nodal_conductances()
+    for theta in [0.5, 1]:
+        for lumped in [False, True]:
+            history = block.solve_transient(
+                initial, top + bottom, times, theta=theta, lumped=lumped
+            )
+            assert history.shape == (len(times), len(steady))
+            assert np.all(history[0] == initial)
+            assert np.all(history[:, top + bottom] == initial[top + bottom])
+            capacitances = block.nodal_capacitances(lumped=lumped)
+            expected = initial.copy()
+            for row, time_step in zip(history[1:], np.diff(times)):
+                implicit = capacitances + theta * time_step * conductances
+                explicit = capacitances - (1 - theta) * time_step * conductances
+                rhs = explicit @ expected - implicit[:, top + bottom] @ initial[
+                    top + bottom
+                ]
+                expected[free] = np.linalg.solve(
+                    implicit[np.ix_(free, free)], rhs[free]
+                )
+                assert np.all(np.abs(row - expected) < abs_tol)
+
+
+def test_transient_steady_state():
+    history = block.solve_transient(initial, top + bottom, np.linspace(0, 100, 101))
+    assert np.all(np.abs(history[-1] - steady) < abs_tol)
+
+
+def test_transient_conservation():
+    history = block.solve_transient(steady, [], [0, 0.01, 0.03, 0.1, 100])
+    capacitances = block.nodal_capacitances()
+    heat = history @ capacitances.sum(axis=1)
+    assert np.all(np.abs(heat - heat[0]) < abs_tol)
+    assert np.all(np.abs(history[-1] - history[-1].mean()) < 1e-3)
+
+
+def test_assembly():
+    blocks = [
+        (connectivity[:12], thermal_conductivity, heat_capacity),
+        (connectivity[12:], thermal_conductivity, heat_capacity),
+    ]
+    assembly = HeatConductionBlock.assembly(blocks, reference_coordinates)
+    assert assembly.thermal_conductivity == thermal_conductivity
+    assert np.all(
+        np.abs(assembly.nodal_conductances() - block.nodal_conductances()) < abs_tol
+    )
+    assert np.all(
+        np.abs(assembly.nodal_capacitances() - block.nodal_capacitances()) < abs_tol
+    )
+    temperatures = assembly.solve(initial, top + bottom)
+    assert np.all(np.abs(temperatures - steady) < abs_tol)
+    blocks[1] = (connectivity[12:], 2 * thermal_conductivity, heat_capacity)
+    assembly = HeatConductionBlock.assembly(blocks, reference_coordinates)
+    conductances = assembly.nodal_conductances()
+    assert np.all(np.abs(conductances - conductances.T) < abs_tol)
+    assert np.all(np.abs(conductances.sum(axis=1)) < abs_tol)
+    temperatures = assembly.solve(initial, top + bottom)
+    assert np.all(np.abs(assembly.nodal_fluxes(temperatures)[free]) < abs_tol)
+    with raises(TypeError, match="different values of the thermal conductivity."):
+        assembly.thermal_conductivity
+    with raises(ValueError, match="Node 0 is not used by any element."):
+        HeatConductionBlock.assembly([(connectivity[:1], 1, 1)], reference_coordinates)
+    with raises(TypeError, match="Expected at least one block."):
+        HeatConductionBlock.assembly([], reference_coordinates)
+
+
+def test_invalid():
+    with raises(ValueError, match="Node 14 in element 0 is out of range for 14 nodes."):
+        HeatConductionBlock(connectivity + 1, reference_coordinates, 1)
+    with raises(ValueError, match="Node 14 is not used by any element."):
+        HeatConductionBlock(
+            connectivity, np.vstack([reference_coordinates, [[2, 2, 2]]]), 1
+        )
+    with raises(TypeError, match="Expected 14 nodal temperatures, got 13."):
+        block.nodal_fluxes(steady[:-1])
+    with raises(TypeError, match="Fixed node 14 is out of range for 14 nodes."):
+        block.solve(initial, [14])
+    with raises(TypeError, match="The times must be strictly increasing."):
+        block.solve_transient(initial, top, [0, 1, 1])
+    with raises(TypeError, match="The parameter theta must be between 0 and 1."):
+        block.solve_transient(initial, top, [0, 1], theta=2)
+    with raises(TypeError, match="Unexpected keyword argument alpha."):
+        block.solve_transient(initial, top, [0, 1], alpha=0.5)
@@ -0,0 +1,102 @@
+from conspire.constitutive.solid.hyperelastic import MooneyRivlin, NeoHookean
+from conspire.fem import Assembly, Block, RepresentativeVolumeElement
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+epsilon = 1e-6
+rel_tol = 1e-4
+bulk_modulus = 13
+shear_modulus = 3
+extra_modulus = 1
+deformation_gradient = np.array(
+    [
+        [1.1, 0.05, -0.02],
+        [0.03, 0.95, 0.04],
+        [-0.01, 0.02, 1.05],
+    ]
+)
+rubber = NeoHookean(bulk_modulus, shear_modulus)
+steel = MooneyRivlin(10 * bulk_modulus, 10 * shear_modulus, extra_modulus)
+block = Block(rubber, connectivity, reference_coordinates)
+assembly = Assembly(
+    [(rubber, connectivity[:12]), (steel, connectivity[12:])],
+    reference_coordinates,
+)
+rve = RepresentativeVolumeElement(block)
+composite = RepresentativeVolumeElement(assembly)
+
+
+def finite_difference(element):
+    tangent = np.zeros((3, 3, 3, 3))
+    for k in range(3):
+        for m in range(3):
+            perturbed = deformation_gradient.copy()
+            perturbed[k, m] += epsilon / 2
+            upper, _ = element.homogenize(perturbed, abs_tol=abs_tol)
+            perturbed[k, m] -= epsilon
+            lower, _ = element.homogenize(perturbed, abs_tol=abs_tol)
+            tangent[:, :, k, m] = (upper - lower) / epsilon
+    return tangent
+
+
+def test_node_pairs():
+    pairs = rve.node_pairs
+    assert pairs.shape == (7 + 3, 2)
+    for node, image in pairs:
+        difference = reference_coordinates[node] - reference_coordinates[image]
+        assert np.all(np.isin(difference, [0.0, 1.0]))
+    assert set(pairs[:, 1]) == {6, 9, 10, 11}
+    assert rve.volume == 1.0
+
+
+def test_no_periodic_image():
+    coordinates = reference_coordinates.copy()
+    coordinates[8, 0] = 0.1
+    with raises(TypeError, match="Node 8 has no periodic image."):
+        RepresentativeVolumeElement(Block(rubber, connectivity, coordinates))
+
+
+def test_undeformed():
+    stress, _ = rve.homogenize(np.eye(3), abs_tol=abs_tol)
+    assert np.all(np.abs(stress) < abs_tol)
+
+
+def test_homogeneous():
+    coordinates = rve.solve(deformation_gradient, abs_tol=abs_tol)
+    assert np.all(
+        np.abs(coordinates - reference_coordinates @ deformation_gradient.T) < abs_tol
+    )
+    stress, _ = rve.homogenize(deformation_gradient, abs_tol=abs_tol)
+    assert np.all(
+        np.abs(stress - rubber.first_piola_kirchhoff_stress(deformation_gradient))
+        < abs_tol
+    )
+
+
+def test_periodic():
+    coordinates = composite.solve(deformation_gradient, abs_tol=abs_tol)
+    fluctuations = coordinates - reference_coordinates @ deformation_gradient.T
+    for node, image in composite.node_pairs:
+        assert np.all(np.abs(fluctuations[node] - fluctuations[image]) < abs_tol)
+    assert np.any(np.abs(fluctuations) > epsilon)
+
+
+def test_tangent_finite_difference():
+    for element in [rve, composite]:
+        _, tangent = element.homogenize(deformation_gradient, abs_tol=abs_tol)
+        assert tangent.shape == (3, 3, 3, 3)
+        assert np.all(
+            np.abs(tangent - finite_difference(element))
+            < rel_tol * np.abs(tangent).max()
+        )
+
+
+def test_tangent_symmetry():
+    _, tangent = composite.homogenize(deformation_gradient, abs_tol=abs_tol)
+    assert np.all(
+        np.abs(tangent - tangent.transpose(2, 3, 0, 1))
+        < abs_tol * np.abs(tangent).max()
+    )
@@ -0,0 +1,134 @@
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Block, BodyForce, Pressure, Traction
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+epsilon = 1e-6
+bulk_modulus = 13
+shear_modulus = 3
+pressure = 0.7
+body_force = np.array([0.1, -0.2, -0.9])
+traction = np.array([0.3, 0.2, 0.5])
+deformed_coordinates = np.array(
+    [
+        [0.48419081, -0.52698494, 0.42026988],
+        [0.43559430, 0.52696224, 0.54477963],
+        [-0.56594965, 0.57076191, 0.51683869],
+        [-0.56061746, -0.42795457, 0.55275658],
+        [0.41878700, 0.53190268, -0.44744274],
+        [0.47232357, -0.57252738, -0.42946606],
+        [-0.45168197, -0.5102938, -0.57959825],
+        [-0.41776733, 0.41581785, -0.45911886],
+        [0.05946988, 0.03773822, 0.44149305],
+        [-0.08478334, -0.09009810, -0.46105872],
+        [-0.04039882, -0.58201398, 0.09346960],
+        [-0.57820738, 0.08325131, 0.03614415],
+        [-0.04145077, 0.56406301, 0.09988905],
+        [0.52149656, -0.08553510, -0.03187069],
+    ]
+)
+
+
+def boundary_faces(connectivity, coordinates):
+    faces = {}
+    for element in connectivity:
+        for k in range(4):
+            face = [element[(k + 1) % 4], element[(k + 2) % 4], element[(k + 3) % 4]]
+            normal = np.cross(
+                coordinates[face[1]] - coordinates[face[0]],
+                coordinates[face[2]] - coordinates[face[0]],
+            )
+            if normal.dot(coordinates[element[k]] - coordinates[face[0]]) > 0:
+                face = [face[0], face[2], face[1]]
+            key = tuple(sorted(face))
+            if key in faces:
+                del faces[key]
+            else:
+                faces[key] = face
+    return np.array(list(faces.values()))
+
+
+faces = boundary_faces(connectivity, reference_coordinates)
+top_faces = np.array(
+    [face for face in faces if np.all(reference_coordinates[face, 2] == 0.5)]
+)
+
+model = NeoHookean(bulk_modulus, shear_modulus)
+block = Block(model, connectivity, reference_coordinates)
+body = BodyForce(body_force, connectivity, reference_coordinates)
+follower = Pressure(pressure, faces)
+dead = Traction(traction, top_faces, reference_coordinates)
+
+
+def test_boundary_faces():
+    assert len(faces) == 24
+    assert len(top_faces) == 4
+
+
+def test_body_force_total():
+    assert np.all(np.abs(body.nodal_forces().sum(axis=0) + body_force) < abs_tol)
+
+
+def test_traction_total():
+    assert np.all(np.abs(dead.nodal_forces().sum(axis=0) + traction) < abs_tol)
+
+
+def test_pressure_closed_surface():
+    assert np.all(
+        np.abs(follower.nodal_forces(deformed_coordinates).sum(axis=0)) < abs_tol
+    )
+
+
+def test_pressure_nodal_forces_reference():
+    forces = follower.nodal_forces(reference_coordinates)
+    for node in [8, 9, 10, 11, 12, 13]:
+        normal = np.round(2 * reference_coordinates[node])
+        assert np.all(np.abs(forces[node] - pressure * normal / 3) < abs_tol)
+
+
+def test_pressure_nodal_stiffnesses_finite_difference():
+    tan = follower.nodal_stiffnesses(deformed_coordinates)
+    for a in range(len(reference_coordinates)):
+        for b in range(len(reference_coordinates)):
+            for i in range(3):
+                for j in range(3):
+                    deformed_coordinates[b, j] += epsilon / 2
+                    d_force = follower.nodal_forces(deformed_coordinates)[a, i]
+                    deformed_coordinates[b, j] -= epsilon
+                    d_force -= follower.nodal_forces(deformed_coordinates)[a, i]
+                    assert np.abs(tan[a, b, i, j] - d_force / epsilon) < epsilon
+                    deformed_coordinates[b, j] += epsilon / 2
+
+
+def test_solve():
+    fixed_nodes = [4, 5, 6, 7, 9]
+    free_nodes = [0, 1, 2, 3, 8, 10, 11, 12, 13]
+    loads = [body, dead, Pressure(pressure, top_faces)]
+    coordinates = block.solve(
+        reference_coordinates, fixed_nodes, loads=loads, abs_tol=abs_tol
+    )
+    forces = block.nodal_forces(coordinates)
+    forces += body.nodal_forces() + dead.nodal_forces()
+    forces += loads[2].nodal_forces(coordinates)
+    assert np.all(np.abs(forces[free_nodes]) < abs_tol)
+
+
+def test_invalid():
+    with raises(TypeError, match="Node 14 in element 0 is out of range for 14 nodes."):
+        BodyForce(body_force, connectivity + 1, reference_coordinates)
+    with raises(TypeError, match="Node 14 in face 0 is out of range for 14 nodes."):
+        Traction(traction, top_faces + 14, reference_coordinates)
+    with raises(TypeError, match="Node 14 in face 0 is out of range for 14 nodes."):
+        Pressure(pressure, faces + 14).nodal_forces(reference_coordinates)
+    with raises(TypeError, match="Node 14 in face 0 is out of range for 14 nodes."):
+        Pressure(pressure, faces + 14).nodal_stiffnesses(reference_coordinates)
+    fixed_nodes = [4, 5, 6, 7, 9]
+    with raises(TypeError, match="Expected a load on 14 nodes, got 13."):
+        block.solve(
+            reference_coordinates,
+            fixed_nodes,
+            loads=[Traction(traction, top_faces, reference_coordinates[:13])],
+        )
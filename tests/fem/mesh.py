@@ -0,0 +1,283 @@
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Block, Mesh
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+import struct
+
+
+top_nodes = [0, 1, 2, 3, 8]
+top_faces = np.array([[0, 1, 8], [1, 2, 8], [2, 3, 8], [3, 0, 8]])
+model = NeoHookean(13, 3)
+
+
+def tetrahedron_side(element, face):
+    sides = [[0, 1, 3], [1, 2, 3], [0, 3, 2], [0, 2, 1]]
+    for side, nodes in enumerate(sides):
+        if sorted(element[node] for node in nodes) == sorted(face):
+            return side
+
+
+def write_gmsh_2(path, faces=top_faces):
+    lines = ["$MeshFormat", "2.2 0 8", "$EndMeshFormat"]
+    lines += ["$PhysicalNames", "2", '2 1 "top"', '3 2 "body"', "$EndPhysicalNames"]
+    lines += ["$Nodes", str(len(reference_coordinates))]
+    for node, (x, y, z) in enumerate(reference_coordinates):
+        lines.append(f"{node + 101} {x} {y} {z}")
+    lines += ["$EndNodes", "$Elements", str(len(faces) + len(connectivity))]
+    for face, nodes in enumerate(faces):
+        lines.append(f"{face + 1} 2 2 1 1 " + " ".join(str(n + 101) for n in nodes))
+    for element, nodes in enumerate(connectivity):
+        lines.append(f"{element + 5} 4 2 2 1 " + " ".join(str(n + 101) for n in nodes))
+    lines.append("$EndElements")
+    path.write_text("\n".join(lines) + "\n")
+
+
+def write_gmsh_4(path):
+    lines = ["$MeshFormat", "4.1 0 8", "$EndMeshFormat"]
+    lines += ["$PhysicalNames", "2", '2 1 "top"', '3 2 "body"', "$EndPhysicalNames"]
+    lines += ["$Entities", "0 0 1 1"]
+    lines += ["1 -0.5 -0.5 0.5 0.5 0.5 0.5 1 1 0", "1 -0.5 -0.5 -0.5 0.5 0.5 0.5 1 2 0"]
+    lines += ["$EndEntities", "$Nodes", "1 14 1 14", "3 1 0 14"]
+    lines += [str(node + 1) for node in range(len(reference_coordinates))]
+    lines += [f"{x} {y} {z}" for x, y, z in reference_coordinates]
+    lines += ["$EndNodes", "$Elements", "2 28 1 28", "2 1 2 4"]
+    for face, nodes in enumerate(top_faces):
+        lines.append(f"{face + 1} " + " ".join(str(n + 1) for n in nodes))
+    lines.append("3 1 4 24")
+    for element, nodes in enumerate(connectivity):
+        lines.append(f"{element + 5} " + " ".join(str(n + 1) for n in nodes))
+    lines.append("$EndElements")
+    path.write_text("\n".join(lines) + "\n")
+
+
+def vtk_cells():
+    return [(10, nodes) for nodes in connectivity] + [(5, nodes) for nodes in top_faces]
+
+
+def write_vtk(path):
+    cells = vtk_cells()
+    lines = ["# vtk DataFile Version 3.0", "cube", "ASCII", "DATASET UNSTRUCTURED_GRID"]
+    lines.append(f"POINTS {len(reference_coordinates)} double")
+    lines += [f"{x} {y} {z}" for x, y, z in reference_coordinates]
+    lines.append(f"CELLS {len(cells)} {sum(len(nodes) + 1 for _, nodes in cells)}")
+    for _, nodes in cells:
+        lines.append(f"{len(nodes)} " + " ".join(str(n) for n in nodes))
+    lines += [f"CELL_TYPES {len(cells)}"] + [str(cell_type) for cell_type, _ in cells]
+    lines += [f"CELL_DATA {len(cells)}", "SCALARS BlockId int 1"]
+    lines.append("LOOKUP_TABLE default")
+    lines += ["2" if cell_type == 10 else "1" for cell_type, _ in cells]
+    path.write_text("\n".join(lines) + "\n")
+
+
+def write_vtu(path):
+    cells = vtk_cells()
+    offsets = np.cumsum([len(nodes) for _, nodes in cells])
+    points = " ".join(f"{x} {y} {z}" for x, y, z in reference_coordinates)
+    lines = [
+        '<VTKFile type="UnstructuredGrid" version="1.0">',
+        "<UnstructuredGrid>",
+        f'<Piece NumberOfPoints="14" NumberOfCells="{len(cells)}">',
+        "<Points>",
+        f'<DataArray type="Float64" NumberOfComponents="3" format="ascii">{points}',
+        "</DataArray>",
+        "</Points>",
+        "<Cells>",
+        '<DataArray type="Int64" Name="connectivity" format="ascii">',
+        " ".join(str(n) for _, nodes in cells for n in nodes),
+        "</DataArray>",
+        '<DataArray type="Int64" Name="offsets" format="ascii">',
+        " ".join(str(offset) for offset in offsets),
+        "</DataArray>",
+        '<DataArray type="UInt8" Name="types" format="ascii">',
+        " ".join(str(cell_type) for cell_type, _ in cells),
+        "</DataArray>",
+        "</Cells>",
+        "<CellData>",
+        '<DataArray type="Int32" Name="BlockId" format="ascii">',
+        " ".join("2" if cell_type == 10 else "1" for cell_type, _ in cells),
+        "</DataArray>",
+        "</CellData>",
+        "</Piece>",
+        "</UnstructuredGrid>",
+        "</VTKFile>",
+    ]
+    path.write_text("\n".join(lines) + "\n")
+
+
+def write_exodus(path, element_type="TETRA4", nodes=top_nodes):
+    def name(string):
+        return struct.pack(">i", len(string)) + string.encode().ljust(
+            4 * ((len(string) + 3) // 4), b"\0"
+        )
+
+    def padded(data):
+        return data.ljust(4 * ((len(data) + 3) // 4), b"\0")
+
+    sides = []
+    for face in top_faces:
+        for element, nodes in enumerate(connectivity):
+            side = tetrahedron_side(nodes, face)
+            if side is not None:
+                sides.append((element + 1, side + 1))
+    dimensions = [
+        ("len_name", 8),
+        ("num_dim", 3),
+        ("num_nodes", len(reference_coordinates)),
+        ("num_elem", len(connectivity)),
+        ("num_el_blk", 1),
+        ("num_node_sets", 1),
+        ("num_side_sets", 1),
+        ("num_el_in_blk1", len(connectivity)),
+        ("num_nod_per_el1", 4),
+        ("num_nod_ns1", len(nodes)),
+        ("num_side_ss1", len(sides)),
+    ]
+    index = {dimension: i for i, (dimension, _) in enumerate(dimensions)}
+    variables = [
+        ("coordx", 6, ["num_nodes"], reference_coordinates[:, 0], None),
+        ("coordy", 6, ["num_nodes"], reference_coordinates[:, 1], None),
+        ("coordz", 6, ["num_nodes"], reference_coordinates[:, 2], None),
+        ("eb_prop1", 4, ["num_el_blk"], [7], None),
+        ("eb_names", 2, ["num_el_blk", "len_name"], b"body", None),
+        (
+            "connect1",
+            4,
+            ["num_el_in_blk1", "num_nod_per_el1"],
+            (connectivity + 1).flatten(),
+            element_type,
+        ),
+        ("ns_prop1", 4, ["num_node_sets"], [3], None),
+        ("ns_names", 2, ["num_node_sets", "len_name"], b"top", None),
+        ("node_ns1", 4, ["num_nod_ns1"], [node + 1 for node in nodes], None),
+        ("ss_prop1", 4, ["num_side_sets"], [5], None),
+        ("ss_names", 2, ["num_side_sets", "len_name"], b"top", None),
+        ("elem_ss1", 4, ["num_side_ss1"], [element for element, _ in sides], None),
+        ("side_ss1", 4, ["num_side_ss1"], [side for _, side in sides], None),
+    ]
+    data = []
+    for _, nc_type, _, values, _ in variables:
+        if nc_type == 2:
+            data.append(padded(values.ljust(8, b"\0")))
+        elif nc_type == 4:
+            data.append(padded(struct.pack(f">{len(values)}i", *values)))
+        else:
+            data.append(padded(struct.pack(f">{len(values)}d", *values)))
+
+    def header(offsets):
+        output = b"CDF\x01" + struct.pack(">i", 0)
+        output += struct.pack(">ii", 10, len(dimensions))
+        for dimension, length in dimensions:
+            output += name(dimension) + struct.pack(">i", length)
+        output += struct.pack(">ii", 0, 0) + struct.pack(">ii", 11, len(variables))
+        for (variable, nc_type, dims, _, attribute), offset, values in zip(
+            variables, offsets, data
+        ):
+            output += name(variable) + struct.pack(">i", len(dims))
+            output += b"".join(struct.pack(">i", index[d]) for d in dims)
+            if attribute is None:
+                output += struct.pack(">ii", 0, 0)
+            else:
+                output += struct.pack(">ii", 12, 1) + name("elem_type")
+                output += struct.pack(">ii", 2, len(attribute))
+                output += padded(attribute.encode())
+            output += struct.pack(">iii", nc_type, len(values), offset)
+        return output
+
+    size = len(header([0] * len(variables)))
+    offsets = np.cumsum([size] + [len(values) for values in data])[:-1]
+    path.write_bytes(header(offsets) + b"".join(data))
+
+
+def check(mesh, block="body", top="top"):
+    assert np.all(mesh.reference_nodal_coordinates == reference_coordinates)
+    assert list(mesh.blocks) == [block]
+    assert np.all(mesh.blocks[block] == connectivity)
+    assert np.all(mesh.node_sets[top] == top_nodes)
+    assert sorted(sorted(face) for face in mesh.side_sets[top].tolist()) == sorted(
+        sorted(face) for face in top_faces.tolist()
+    )
+    for face in mesh.side_sets[top]:
+        normal = np.cross(
+            reference_coordinates[face[1]] - reference_coordinates[face[0]],
+            reference_coordinates[face[2]] - reference_coordinates[face[0]],
+        )
+        assert normal[2] > 0
+    forces = Block(model, connectivity, reference_coordinates).nodal_forces(
+        2 * reference_coordinates
+    )
+    assert np.all(mesh.block(model).nodal_forces(2 * reference_coordinates) == forces)
+    assert np.all(mesh.block(model).node_sets[top] == top_nodes)
+    assert np.all(
+        mesh.assembly({block: model}).nodal_forces(2 * reference_coordinates) == forces
+    )
+
+
+def test_gmsh_2(tmp_path):
+    write_gmsh_2(tmp_path / "cube.msh")
+    check(Mesh(tmp_path / "cube.msh"))
+
+
+def test_gmsh_4(tmp_path):
+    write_gmsh_4(tmp_path / "cube.msh")
+    check(Mesh(tmp_path / "cube.msh"))
+
+
+def test_vtk(tmp_path):
+    write_vtk(tmp_path / "cube.vtk")
+    check(Mesh(tmp_path / "cube.vtk"), block="2", top="1")
+
+
+def test_vtu(tmp_path):
+    write_vtu(tmp_path / "cube.vtu")
+    check(Mesh(tmp_path / "cube.vtu"), block="2", top="1")
+
+
+def test_exodus(tmp_path):
+    write_exodus(tmp_path / "cube.exo")
+    check(Mesh(tmp_path / "cube.exo"))
+
+
+def test_inward_side_set(tmp_path):
+    write_gmsh_2(tmp_path / "cube.msh", faces=top_faces[:, ::-1])
+    check(Mesh(tmp_path / "cube.msh"))
+
+
+def test_side_set_without_element(tmp_path):
+    write_gmsh_2(tmp_path / "cube.msh", faces=[[0, 1, 2]])
+    with raises(TypeError, match="Side set top has a face that is not a face of any"):
+        Mesh(tmp_path / "cube.msh")
+
+
+def test_node_set_out_of_range(tmp_path):
+    write_exodus(tmp_path / "cube.exo", nodes=[0, len(reference_coordinates)])
+    with raises(TypeError, match="Node set top references a node that does not exist"):
+        Mesh(tmp_path / "cube.exo")
+
+
+def test_refine_node_set(tmp_path):
+    write_exodus(tmp_path / "cube.exo", nodes=range(len(reference_coordinates)))
+    refined = Mesh(tmp_path / "cube.exo").refine()
+    coordinates = refined.reference_nodal_coordinates
+    surface = np.any(np.abs(np.abs(coordinates) - 0.5) < 1e-12, axis=1)
+    assert np.all(refined.node_sets["top"] == np.flatnonzero(surface))
+
+
+def test_exodus_unsupported_element_type(tmp_path):
+    write_exodus(tmp_path / "cube.exo", element_type="HEX8")
+    with raises(TypeError, match="Unsupported element type HEX8"):
+        Mesh(tmp_path / "cube.exo")
+
+
+def test_gmsh_unsupported_element_type(tmp_path):
+    write_gmsh_2(tmp_path / "cube.msh")
+    text = (tmp_path / "cube.msh").read_text().replace(" 4 2 2 1 ", " 11 2 2 1 ", 1)
+    (tmp_path / "cube.msh").write_text(text)
+    with raises(TypeError, match="Unsupported element type 10-node tetrahedron"):
+        Mesh(tmp_path / "cube.msh")
+
+
+def test_unsupported_extension(tmp_path):
+    (tmp_path / "cube.stl").write_text("solid cube")
+    with raises(TypeError, match="Unsupported mesh file extension .stl"):
+        Mesh(tmp_path / "cube.stl")
@@ -0,0 +1,159 @@
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Mesh
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-12
+lengths = [1.0, 2.0, 3.0]
+divisions = [2, 3, 4]
+radius = 1.0
+height = 2.0
+model = NeoHookean(13, 3)
+
+
+def volumes(connectivity, coordinates):
+    x = coordinates[connectivity]
+    return np.linalg.det(x[:, 1:] - x[:, :1]) / 6
+
+
+def area_vectors(faces, coordinates):
+    x = coordinates[faces]
+    return np.cross(x[:, 1] - x[:, 0], x[:, 2] - x[:, 0]) / 2
+
+
+def edges(connectivity):
+    return {
+        tuple(sorted((element[a], element[b])))
+        for element in connectivity
+        for a in range(4)
+        for b in range(a + 1, 4)
+    }
+
+
+def test_box():
+    mesh = Mesh.box(lengths, divisions)
+    coordinates = mesh.reference_nodal_coordinates
+    (connectivity,) = mesh.blocks.values()
+    assert coordinates.shape == (np.prod(np.array(divisions) + 1), 3)
+    assert connectivity.shape == (6 * np.prod(divisions), 4)
+    assert np.all(volumes(connectivity, coordinates) > 0)
+    assert np.abs(volumes(connectivity, coordinates).sum() - np.prod(lengths)) < abs_tol
+    for axis, name in enumerate("xyz"):
+        for bound, sign in [("min", -1), ("max", 1)]:
+            nodes = mesh.node_sets[f"{name}{bound}"]
+            value = 0 if bound == "min" else lengths[axis]
+            assert np.all(np.abs(coordinates[nodes, axis] - value) < abs_tol)
+            area = np.zeros(3)
+            area[axis] = sign * np.prod(lengths) / lengths[axis]
+            area_vector = area_vectors(mesh.side_sets[f"{name}{bound}"], coordinates)
+            assert np.all(np.abs(area_vector.sum(axis=0) - area) < abs_tol)
+    block = mesh.block(model)
+    deformation_gradient = np.array(
+        [
+            [1.1, 0.05, -0.02],
+            [0.03, 0.95, 0.04],
+            [-0.01, 0.02, 1.05],
+        ]
+    )
+    boundary = np.unique(np.concatenate(list(mesh.node_sets.values())))
+    solution = block.solve(coordinates @ deformation_gradient.T, boundary)
+    assert np.all(np.abs(solution - coordinates @ deformation_gradient.T) < 1e-8)
+
+
+def test_cylinder():
+    radial, circumferential, axial = 2, 8, 3
+    mesh = Mesh.cylinder(radius, height, [radial, circumferential, axial])
+    coordinates = mesh.reference_nodal_coordinates
+    (connectivity,) = mesh.blocks.values()
+    assert coordinates.shape == ((1 + radial * circumferential) * (axial + 1), 3)
+    assert connectivity.shape == ((6 * radial - 3) * circumferential * axial, 4)
+    assert np.all(volumes(connectivity, coordinates) > 0)
+    polygon = circumferential * radius**2 * np.sin(2 * np.pi / circumferential) / 2
+    assert (
+        np.abs(volumes(connectivity, coordinates).sum() - polygon * height) < abs_tol
+    )
+    assert np.all(
+        np.abs(np.linalg.norm(coordinates[mesh.node_sets["outer"], :2], axis=1) - 1)
+        < abs_tol
+    )
+    assert np.all(coordinates[mesh.node_sets["bottom"], 2] == 0)
+    assert np.all(coordinates[mesh.node_sets["top"], 2] == height)
+    top = area_vectors(mesh.side_sets["top"], coordinates).sum(axis=0)
+    assert np.all(np.abs(top - [0, 0, polygon]) < abs_tol)
+    outer = area_vectors(mesh.side_sets["outer"], coordinates)
+    centroids = coordinates[mesh.side_sets["outer"]].mean(axis=1)
+    assert np.all(np.sum(outer[:, :2] * centroids[:, :2], axis=1) > 0)
+
+
+def test_refine():
+    mesh = Mesh.box(lengths, divisions)
+    refined = mesh.refine()
+    (connectivity,) = mesh.blocks.values()
+    (refined_connectivity,) = refined.blocks.values()
+    coordinates = mesh.reference_nodal_coordinates
+    refined_coordinates = refined.reference_nodal_coordinates
+    assert len(refined_connectivity) == 8 * len(connectivity)
+    assert len(refined_coordinates) == len(coordinates) + len(edges(connectivity))
+    assert np.all(refined_coordinates[: len(coordinates)] == coordinates)
+    refined_volumes = volumes(refined_connectivity, refined_coordinates)
+    assert np.all(refined_volumes > 0)
+    assert np.abs(refined_volumes.sum() - np.prod(lengths)) < abs_tol
+    for name, faces in mesh.side_sets.items():
+        refined_faces = refined.side_sets[name]
+        assert len(refined_faces) == 4 * len(faces)
+        assert np.all(
+            np.abs(
+                area_vectors(refined_faces, refined_coordinates).sum(axis=0)
+                - area_vectors(faces, coordinates).sum(axis=0)
+            )
+            < abs_tol
+        )
+        nodes = refined.node_sets[name]
+        assert np.all(np.isin(refined_faces, nodes))
+    refined.block(model)
+
+
+def test_refine_connectivity():
+    mesh = Mesh.from_connectivity(connectivity, reference_coordinates)
+    refined = mesh.refine().refine()
+    (refined_connectivity,) = refined.blocks.values()
+    assert len(refined_connectivity) == 64 * len(connectivity)
+    refined_volumes = volumes(refined_connectivity, refined.reference_nodal_coordinates)
+    assert np.all(refined_volumes > 0)
+    assert np.abs(refined_volumes.sum() - 1) < abs_tol
+
+
+def test_quadratic():
+    mesh = Mesh.box(lengths, divisions)
+    (linear,) = mesh.blocks.values()
+    blocks, coordinates = mesh.quadratic()
+    (quadratic,) = blocks.values()
+    assert quadratic.shape == (len(linear), 10)
+    assert np.all(quadratic[:, :4] == linear)
+    assert len(coordinates) == len(mesh.reference_nodal_coordinates) + len(
+        edges(linear)
+    )
+    pairs = [(0, 1), (1, 2), (2, 0), (0, 3), (1, 3), (2, 3)]
+    for node, (a, b) in enumerate(pairs, start=4):
+        midpoints = (coordinates[quadratic[:, a]] + coordinates[quadratic[:, b]]) / 2
+        assert np.all(np.abs(coordinates[quadratic[:, node]] - midpoints) < abs_tol)
+
+
+def test_invalid():
+    with raises(TypeError, match="The lengths must be positive."):
+        Mesh.box([1, 0, 1], divisions)
+    with raises(TypeError, match="The number of divisions must be positive."):
+        Mesh.box(lengths, [1, 0, 1])
+    with raises(TypeError, match="The radius and height must be positive."):
+        Mesh.cylinder(-1, height, [1, 8, 1])
+    with raises(
+        TypeError,
+        match="The number of circumferential divisions must be at least 3.",
+    ):
+        Mesh.cylinder(radius, height, [1, 2, 1])
+    with raises(
+        TypeError, match="Element block block references a node that does not exist."
+    ):
+        Mesh.from_connectivity(connectivity, reference_coordinates[:10])
@@ -0,0 +1,90 @@
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Block
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+rel_tol = 1e-8
+bulk_modulus = 13
+shear_modulus = 3
+density = 2.5
+compression = 0.05
+num_modes = 4
+top = [0, 1, 2, 3, 8]
+bottom = [4, 5, 6, 7, 9]
+free = [node for node in range(len(reference_coordinates)) if node not in bottom]
+dofs = [3 * node + i for node in free for i in range(3)]
+block = Block(
+    NeoHookean(bulk_modulus, shear_modulus), connectivity, reference_coordinates
+)
+
+
+def restrict(stiffnesses):
+    nodes = len(reference_coordinates)
+    matrix = stiffnesses.transpose(0, 2, 1, 3).reshape(3 * nodes, 3 * nodes)
+    return matrix[np.ix_(dofs, dofs)]
+
+
+def generalized_eigenvalues(stiffness, mass):
+    factor = np.linalg.inv(np.linalg.cholesky(mass))
+    return np.linalg.eigvalsh(factor @ stiffness @ factor.T)
+
+
+def test_nodal_masses():
+    masses = block.nodal_masses(density=density)
+    assert np.all(np.abs(masses - masses.T) < abs_tol)
+    assert np.abs(masses.sum() - density) < abs_tol
+    assert np.all(np.linalg.eigvalsh(masses) > 0)
+
+
+def test_eigenmodes():
+    frequencies, modes = block.eigenmodes(
+        reference_coordinates, num_modes, bottom, density=density
+    )
+    assert frequencies.shape == (num_modes,)
+    assert modes.shape == (num_modes, len(reference_coordinates), 3)
+    assert np.all(np.diff(frequencies) >= 0)
+    assert np.all(modes[:, bottom] == 0)
+    stiffness = restrict(block.nodal_stiffnesses(reference_coordinates))
+    mass = np.kron(block.nodal_masses(density=density), np.eye(3))[np.ix_(dofs, dofs)]
+    expected = generalized_eigenvalues(stiffness, mass)[:num_modes]
+    assert np.all(np.abs(frequencies**2 - expected) < rel_tol * expected)
+    for frequency, mode in zip(frequencies, modes):
+        vector = mode[free].flatten()
+        assert np.abs(vector @ mass @ vector - 1) < rel_tol
+        residual = stiffness @ vector - frequency**2 * mass @ vector
+        assert np.all(np.abs(residual) < rel_tol * np.abs(stiffness @ vector).max())
+
+
+def test_eigenmodes_rigid_body():
+    frequencies, _ = block.eigenmodes(reference_coordinates, 7, [], shift=-1.0)
+    assert np.all(np.abs(frequencies[:6]) < 1e-6)
+    assert frequencies[6] > 1e-3
+
+
+def test_buckling_modes():
+    coordinates = reference_coordinates.copy()
+    coordinates[top, 2] -= compression
+    coordinates = block.solve(coordinates, top + bottom, abs_tol=abs_tol)
+    load_factors, modes = block.buckling_modes(coordinates, num_modes, bottom)
+    assert load_factors.shape == (num_modes,)
+    assert np.all(np.diff(np.abs(load_factors)) >= 0)
+    assert np.all(modes[:, bottom] == 0)
+    assert np.all(np.abs(np.abs(modes).max(axis=(1, 2)) - 1) < abs_tol)
+    reference = restrict(block.nodal_stiffnesses(reference_coordinates))
+    stiffness = restrict(block.nodal_stiffnesses(coordinates))
+    for load_factor, mode in zip(load_factors, modes):
+        vector = mode[free].flatten()
+        residual = (reference + load_factor * (stiffness - reference)) @ vector
+        assert np.all(np.abs(residual) < rel_tol * np.abs(reference @ vector).max())
+
+
+def test_invalid():
+    with raises(TypeError, match="Requested 37 modes"):
+        block.eigenmodes(reference_coordinates, 37, bottom)
+    with raises(TypeError, match="Fixed node 14 is out of range for 14 nodes."):
+        block.buckling_modes(reference_coordinates, 1, [14])
+    with raises(TypeError, match="Expected 14 nodal coordinates, got 13."):
+        block.eigenmodes(reference_coordinates[:-1], 1, bottom)
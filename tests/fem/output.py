@@ -0,0 +1,148 @@
+from conspire.constitutive.solid.elastic import AlmansiHamel
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Assembly, Block, Mesh, TimeSeries, write_vtu
+from cube import connectivity, reference_coordinates
+from pytest import raises
+from xml.etree import ElementTree
+import numpy as np
+
+
+abs_tol = 1e-10
+bulk_modulus = 13
+shear_modulus = 3
+deformation_gradient = np.array(
+    [
+        [1.1, 0.2, -0.1],
+        [0.1, 0.9, 0.05],
+        [-0.2, 0.1, 1.05],
+    ]
+)
+nodal_coordinates = reference_coordinates.dot(deformation_gradient.T)
+model = NeoHookean(bulk_modulus, shear_modulus)
+
+
+def read(path):
+    arrays = {}
+    for array in ElementTree.parse(path).iter("DataArray"):
+        values = np.array(array.text.split(), dtype=float)
+        components = int(array.get("NumberOfComponents", 1))
+        if components > 1:
+            values = values.reshape(-1, components)
+        arrays[array.get("Name")] = values
+    return arrays
+
+
+def test_write_vtu(tmp_path):
+    path = tmp_path / "block.vtu"
+    block = Block(model, connectivity, reference_coordinates)
+    write_vtu(path, block, nodal_coordinates)
+    arrays = read(path)
+    cauchy_stress = model.cauchy_stress(deformation_gradient)
+    deviatoric = cauchy_stress - np.trace(cauchy_stress) / 3 * np.eye(3)
+    assert (np.abs(arrays[None] - nodal_coordinates) < abs_tol).all()
+    assert (
+        np.abs(arrays["displacement"] - nodal_coordinates + reference_coordinates)
+        < abs_tol
+    ).all()
+    assert (arrays["BlockId"] == 1).all()
+    assert (arrays["connectivity"] == connectivity).all()
+    for element in range(len(connectivity)):
+        assert (
+            np.abs(
+                arrays["deformation_gradient"][element] - deformation_gradient.flatten()
+            )
+            < abs_tol
+        ).all()
+        assert (
+            np.abs(arrays["cauchy_stress"][element] - cauchy_stress.flatten())
+            < abs_tol
+        ).all()
+        assert (
+            np.abs(
+                arrays["von_mises_stress"][element]
+                - np.sqrt(1.5 * np.sum(deviatoric * deviatoric))
+            )
+            < abs_tol
+        )
+        assert (
+            np.abs(
+                arrays["helmholtz_free_energy_density"][element]
+                - model.helmholtz_free_energy_density(deformation_gradient)
+            )
+            < abs_tol
+        )
+
+
+def test_write_vtu_mesh(tmp_path):
+    path = tmp_path / "block.vtu"
+    block = Block(model, connectivity, reference_coordinates)
+    write_vtu(path, block, nodal_coordinates)
+    mesh = Mesh(path)
+    assert (
+        np.abs(mesh.reference_nodal_coordinates - nodal_coordinates) < abs_tol
+    ).all()
+    assert (mesh.blocks["1"] == connectivity).all()
+
+
+def test_write_vtu_assembly(tmp_path):
+    path = tmp_path / "assembly.vtu"
+    assembly = Assembly(
+        [
+            (AlmansiHamel(bulk_modulus, shear_modulus), connectivity[:12]),
+            (model, connectivity[12:]),
+        ],
+        reference_coordinates,
+    )
+    write_vtu(path, assembly, nodal_coordinates)
+    arrays = read(path)
+    assert (arrays["BlockId"] == [1] * 12 + [2] * 12).all()
+    assert np.isnan(arrays["helmholtz_free_energy_density"][:12]).all()
+    assert not np.isnan(arrays["helmholtz_free_energy_density"][12:]).any()
+    assert (Mesh(path).blocks["2"] == connectivity[12:]).all()
+
+
+def test_write_vtu_elastic(tmp_path):
+    path = tmp_path / "block.vtu"
+    block = Block(
+        AlmansiHamel(bulk_modulus, shear_modulus), connectivity, reference_coordinates
+    )
+    write_vtu(path, block, nodal_coordinates)
+    assert "helmholtz_free_energy_density" not in read(path)
+
+
+def test_write_vtu_nodes(tmp_path):
+    block = Block(model, connectivity, reference_coordinates)
+    with raises(TypeError):
+        write_vtu(tmp_path / "block.vtu", block, nodal_coordinates[:-1])
+
+
+def test_time_series(tmp_path):
+    block = Block(model, connectivity, reference_coordinates)
+    series = TimeSeries(tmp_path / "results.pvd")
+    times = [0.0, 0.5, 1.0]
+    for time in times:
+        coordinates = reference_coordinates + time * (
+            nodal_coordinates - reference_coordinates
+        )
+        series.write(block, coordinates, time)
+    assert series.times == times
+    assert series.path == tmp_path / "results.pvd"
+    datasets = list(ElementTree.parse(series.path).iter("DataSet"))
+    assert [float(dataset.get("timestep")) for dataset in datasets] == times
+    for step, dataset in enumerate(datasets):
+        assert dataset.get("file") == f"results_{step}.vtu"
+        arrays = read(tmp_path / dataset.get("file"))
+        assert (
+            np.abs(
+                arrays["displacement"]
+                - times[step] * (nodal_coordinates - reference_coordinates)
+            )
+            < abs_tol
+        ).all()
+
+
+def test_time_series_extension(tmp_path):
+    with raises(TypeError, match="Expected a path with the extension .pvd, got "):
+        TimeSeries(tmp_path / "results")
+    with raises(TypeError, match="Expected a path with the extension .pvd, got "):
+        TimeSeries(tmp_path / "results.vtu")
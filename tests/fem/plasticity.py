@@ -0,0 +1,153 @@
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import (
+    Assembly,
+    Block,
+    Plasticity,
+    ThermomechanicalBlock,
+)
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+epsilon = 1e-6
+rel_tol = 1e-5
+bulk_modulus = 13
+shear_modulus = 3
+yield_stress = 0.3
+hardening_modulus = 1
+top = [0, 1, 2, 3, 8]
+bottom = [4, 5, 6, 7, 9]
+rubber = NeoHookean(bulk_modulus, shear_modulus)
+steel = Plasticity(rubber, yield_stress, hardening_modulus)
+
+
+def stretched(stretch):
+    return reference_coordinates @ np.diag(
+        [1 / np.sqrt(stretch), 1 / np.sqrt(stretch), stretch]
+    )
+
+
+def test_elastic_range():
+    elastic = Block(rubber, connectivity, reference_coordinates)
+    plastic = Block(steel, connectivity, reference_coordinates)
+    coordinates = stretched(1.01)
+    assert np.all(
+        np.abs(plastic.nodal_forces(coordinates) - elastic.nodal_forces(coordinates))
+        < abs_tol
+    )
+    stiffnesses = elastic.nodal_stiffnesses(coordinates)
+    assert np.all(
+        np.abs(plastic.nodal_stiffnesses(coordinates) - stiffnesses)
+        < abs_tol * np.abs(stiffnesses).max()
+    )
+    plastic.commit_state()
+    assert np.all(plastic.equivalent_plastic_strains == 0)
+
+
+def test_commit_and_revert_state():
+    block = Block(steel, connectivity, reference_coordinates)
+    assert block.equivalent_plastic_strains.shape == (len(connectivity), 1)
+    coordinates = stretched(1.2)
+    forces = block.nodal_forces(coordinates)
+    block.revert_state()
+    assert np.all(block.equivalent_plastic_strains == 0)
+    assert np.all(np.abs(block.nodal_forces(coordinates) - forces) < abs_tol)
+    block.commit_state()
+    strains = block.equivalent_plastic_strains
+    assert np.all(strains > 0)
+    assert np.all(np.abs(strains - strains[0]) < abs_tol)
+    stresses = block.cauchy_stresses(reference_coordinates)
+    assert np.abs(stresses).max() > yield_stress / 10
+    block.nodal_forces(stretched(1.1))
+    block.revert_state()
+    block.commit_state()
+    assert np.all(block.equivalent_plastic_strains == strains)
+
+
+def test_consistent_tangent():
+    block = Block(steel, connectivity, reference_coordinates)
+    block.nodal_forces(stretched(1.1))
+    block.commit_state()
+    coordinates = stretched(1.15) + 0.01 * np.sin(reference_coordinates)
+    stiffnesses = block.nodal_stiffnesses(coordinates)
+    scale = np.abs(stiffnesses).max()
+    for node in range(len(reference_coordinates)):
+        for i in range(3):
+            upper, lower = coordinates.copy(), coordinates.copy()
+            upper[node, i] += epsilon / 2
+            lower[node, i] -= epsilon / 2
+            difference = (
+                block.nodal_forces(upper) - block.nodal_forces(lower)
+            ) / epsilon
+            assert np.all(
+                np.abs(stiffnesses[:, node, :, i] - difference) < rel_tol * scale
+            )
+    block.revert_state()
+
+
+def test_permanent_set():
+    elastic = Block(rubber, connectivity, reference_coordinates)
+    plastic = Block(steel, connectivity, reference_coordinates)
+    coordinates = reference_coordinates.copy()
+    for displacement in np.linspace(0.02, 0.2, 10):
+        coordinates[top, 2] = reference_coordinates[top, 2] + displacement
+        coordinates = plastic.solve(coordinates, top + bottom, abs_tol=abs_tol)
+        plastic.commit_state()
+    unloaded = plastic.solve(coordinates, bottom, abs_tol=abs_tol)
+    plastic.commit_state()
+    assert np.all(unloaded[top, 2] > reference_coordinates[top, 2] + 0.01)
+    assert np.all(np.abs(plastic.nodal_forces(unloaded)[top + [10, 11, 12, 13]]) < 1e-8)
+    recovered = elastic.solve(coordinates, bottom, abs_tol=abs_tol)
+    assert np.all(np.abs(recovered - reference_coordinates) < 1e-8)
+
+
+def test_mixed_assembly():
+    assembly = Assembly(
+        [(steel, connectivity[:12]), (rubber, connectivity[12:])],
+        reference_coordinates,
+    )
+    plastic, elastic = assembly.blocks
+    coordinates = reference_coordinates.copy()
+    coordinates[top, 2] += 0.2
+    coordinates = assembly.solve(coordinates, top + bottom, abs_tol=abs_tol)
+    assembly.commit_state()
+    assert np.any(plastic.equivalent_plastic_strains > 0)
+    assert np.all(elastic.equivalent_plastic_strains == 0)
+    assert np.all(
+        np.abs(
+            assembly.nodal_forces(coordinates)
+            - plastic.nodal_forces(coordinates)
+            - elastic.nodal_forces(coordinates)
+        )
+        < abs_tol
+    )
+    assembly.nodal_forces(reference_coordinates)
+    assembly.revert_state()
+    assert np.any(plastic.equivalent_plastic_strains > 0)
+
+
+def test_invalid():
+    with raises(TypeError, match="The yield stress must be positive."):
+        Plasticity(rubber, 0, hardening_modulus)
+    with raises(TypeError, match="The hardening modulus must be nonnegative."):
+        Plasticity(rubber, yield_stress, -1)
+    block = Block(steel, connectivity, reference_coordinates)
+    with raises(
+        TypeError,
+        match="The Helmholtz free energy is undefined for elastic-plastic constitutive",
+    ):
+        block.helmholtz_free_energy(reference_coordinates)
+    with raises(
+        TypeError,
+        match="The stress of an elastic-plastic block depends on the internal",
+    ):
+        block.shape_sensitivities(
+            reference_coordinates, bottom, np.ones(reference_coordinates.shape)
+        )
+    with raises(
+        TypeError,
+        match="Thermomechanical blocks do not support elastic-plastic constitutive",
+    ):
+        ThermomechanicalBlock(steel, connectivity, reference_coordinates, 1, 1e-3)
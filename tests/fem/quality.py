@@ -0,0 +1,80 @@
+from conspire.constitutive.solid.elastic import AlmansiHamel
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Block, InvertedElementError
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-12
+bulk_modulus = 13
+shear_modulus = 3
+deformation_gradient = np.array(
+    [
+        [1.2, 0.1, 0.0],
+        [0.0, 0.9, 0.2],
+        [0.1, 0.0, 1.1],
+    ]
+)
+block = Block(
+    NeoHookean(bulk_modulus, shear_modulus), connectivity, reference_coordinates
+)
+regular = Block(
+    NeoHookean(bulk_modulus, shear_modulus),
+    [[0, 2, 1, 3]],
+    [[1, 1, 1], [1, -1, -1], [-1, 1, -1], [-1, -1, 1]],
+)
+inverted_coordinates = reference_coordinates.copy()
+inverted_coordinates[13, 0] = -0.9
+
+
+def test_reference():
+    quality = block.element_quality()
+    assert set(quality.keys()) == {"volume", "jacobian", "aspect_ratio", "skew"}
+    assert np.abs(quality["volume"].sum() - 1) < abs_tol
+    assert np.all(np.abs(quality["jacobian"] - 1) < abs_tol)
+    assert np.all(quality["aspect_ratio"] >= 1 - abs_tol)
+    assert np.all((quality["skew"] >= -abs_tol) & (quality["skew"] < 1))
+
+
+def test_regular():
+    quality = regular.element_quality()
+    assert np.abs(quality["volume"][0] - 8 / 3) < abs_tol
+    assert np.abs(quality["aspect_ratio"][0] - 1) < abs_tol
+    assert np.abs(quality["skew"][0]) < abs_tol
+
+
+def test_current():
+    coordinates = reference_coordinates @ deformation_gradient.T
+    quality = block.element_quality(coordinates)
+    reference = block.element_quality(reference_coordinates)
+    jacobian = np.linalg.det(deformation_gradient)
+    assert np.all(np.abs(quality["jacobian"] - jacobian) < abs_tol)
+    assert np.all(np.abs(quality["volume"] - jacobian * reference["volume"]) < abs_tol)
+
+
+def test_inverted():
+    jacobians = block.element_quality(inverted_coordinates)["jacobian"]
+    element = np.argmax(jacobians <= 0)
+    assert jacobians[element] <= 0
+    for method in [
+        block.nodal_forces,
+        block.nodal_stiffnesses,
+        block.helmholtz_free_energy,
+    ]:
+        with raises(InvertedElementError) as error:
+            method(inverted_coordinates)
+        assert error.value.element == element
+        assert np.abs(error.value.jacobian - jacobians[element]) < abs_tol
+        assert f"Element {element} is inverted" in str(error.value)
+    with raises(TypeError):
+        Block(
+            AlmansiHamel(bulk_modulus, shear_modulus),
+            connectivity,
+            reference_coordinates,
+        ).nodal_forces(inverted_coordinates)
+
+
+def test_wrong_number_of_nodes():
+    with raises(TypeError, match="Expected 14 nodal coordinates, got 13."):
+        block.element_quality(reference_coordinates[:-1])
@@ -0,0 +1,102 @@
+from conspire.constitutive.solid.elastic import AlmansiHamel
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Block
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+rel_tol = 1e-2
+bulk_modulus = 13
+shear_modulus = 3
+stretch = 0.1
+steps = 5
+node_sets = {"top": [0, 1, 2, 3, 8], "bottom": [4, 5, 6, 7, 9]}
+fixed_nodes = node_sets["top"] + node_sets["bottom"]
+free_nodes = [10, 11, 12, 13]
+block = Block(
+    NeoHookean(bulk_modulus, shear_modulus),
+    connectivity,
+    reference_coordinates,
+    node_sets=node_sets,
+)
+
+
+def load_steps():
+    coordinates = reference_coordinates.copy()
+    history = [coordinates.copy()]
+    for step in range(1, steps + 1):
+        coordinates[node_sets["top"], 2] = 0.5 + stretch * step / steps
+        coordinates = block.solve(coordinates, fixed_nodes, abs_tol=abs_tol)
+        history.append(coordinates.copy())
+    return history
+
+
+history = load_steps()
+
+
+def test_node_sets():
+    assert list(block.node_sets.keys()) == ["top", "bottom"]
+    for name, nodes in node_sets.items():
+        assert (block.node_sets[name] == nodes).all()
+
+
+def test_node_sets_out_of_range():
+    with raises(TypeError):
+        Block(
+            NeoHookean(bulk_modulus, shear_modulus),
+            connectivity,
+            reference_coordinates,
+            node_sets={"top": [len(reference_coordinates)]},
+        )
+
+
+def test_reaction_forces():
+    coordinates = history[-1]
+    forces = block.nodal_forces(coordinates)
+    reactions = block.reaction_forces(coordinates)
+    for name, nodes in node_sets.items():
+        assert (np.abs(reactions[name] - forces[nodes].sum(axis=0)) < abs_tol).all()
+    assert (np.abs(reactions["top"] + reactions["bottom"]) < abs_tol).all()
+    assert reactions["top"][2] > 0
+
+
+def test_reaction_moments():
+    coordinates = history[-1]
+    forces = block.nodal_forces(coordinates)
+    origin = np.array([0.1, -0.2, 0.3])
+    moments = block.reaction_moments(coordinates, origin=origin)
+    for name, nodes in node_sets.items():
+        moment = np.cross(coordinates[nodes] - origin, forces[nodes]).sum(axis=0)
+        assert (np.abs(moments[name] - moment) < abs_tol).all()
+    assert (np.abs(moments["top"] + moments["bottom"]) < abs_tol).all()
+
+
+def test_energy_balance():
+    balance = block.energy_balance(history)
+    energy = balance["helmholtz_free_energy"]
+    assert balance["external_work"].shape == (steps + 1,)
+    assert balance["external_work"][0] == 0
+    assert (
+        np.abs(balance["error"] - balance["external_work"] + energy - energy[0])
+        < abs_tol
+    ).all()
+    assert np.abs(balance["error"][-1]) < rel_tol * (energy[-1] - energy[0])
+    assert (
+        np.abs(balance["node_set_work"]["top"] - balance["external_work"]) < abs_tol
+    ).all()
+    assert (np.abs(balance["node_set_work"]["bottom"]) < abs_tol).all()
+
+
+def test_energy_balance_empty():
+    with raises(TypeError):
+        block.energy_balance([])
+
+
+def test_energy_balance_elastic():
+    elastic = Block(
+        AlmansiHamel(bulk_modulus, shear_modulus), connectivity, reference_coordinates
+    )
+    with raises(TypeError):
+        elastic.energy_balance(history)
@@ -0,0 +1,131 @@
+from conspire.constitutive.solid.elastic import AlmansiHamel
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Block
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+epsilon = 1e-6
+rel_tol = 1e-5
+bulk_modulus = 13
+shear_modulus = 3
+stretch = 0.1
+shear = 0.05
+parameters = ["bulk_modulus", "shear_modulus"]
+top = [0, 1, 2, 3, 8]
+bottom = [4, 5, 6, 7, 9]
+block = Block(
+    NeoHookean(bulk_modulus, shear_modulus), connectivity, reference_coordinates
+)
+coordinates = reference_coordinates.copy()
+coordinates[top, 2] += stretch
+coordinates[top, 0] += shear
+
+
+def solve(model):
+    return Block(model, connectivity, reference_coordinates).solve(
+        coordinates, top + bottom, abs_tol=abs_tol
+    )
+
+
+def test_helmholtz_free_energy_sensitivities():
+    sensitivities = block.helmholtz_free_energy_sensitivities(coordinates)
+    assert list(sensitivities) == parameters
+    energy = block.helmholtz_free_energy(coordinates)
+    assert np.abs(
+        bulk_modulus * sensitivities["bulk_modulus"]
+        + shear_modulus * sensitivities["shear_modulus"]
+        - energy
+    ) < rel_tol * energy
+    upper = Block(
+        NeoHookean(bulk_modulus, shear_modulus + epsilon),
+        connectivity,
+        reference_coordinates,
+    ).helmholtz_free_energy(coordinates)
+    assert np.abs(
+        (upper - energy) / epsilon - sensitivities["shear_modulus"]
+    ) < rel_tol * np.abs(sensitivities["shear_modulus"])
+
+
+def test_helmholtz_free_energy_sensitivities_reference():
+    sensitivities = block.helmholtz_free_energy_sensitivities(reference_coordinates)
+    assert all(np.abs(value) < abs_tol for value in sensitivities.values())
+
+
+def test_nodal_force_sensitivities():
+    sensitivities = block.nodal_force_sensitivities(coordinates)
+    assert list(sensitivities) == parameters
+    forces = block.nodal_forces(coordinates)
+    assert sensitivities["bulk_modulus"].shape == forces.shape
+    assert np.all(
+        np.abs(
+            bulk_modulus * sensitivities["bulk_modulus"]
+            + shear_modulus * sensitivities["shear_modulus"]
+            - forces
+        )
+        < rel_tol * np.abs(forces).max()
+    )
+
+
+def test_relative_step():
+    default = block.nodal_force_sensitivities(coordinates)
+    coarse = block.nodal_force_sensitivities(coordinates, relative_step=1e-3)
+    scale = np.abs(default["shear_modulus"]).max()
+    for name in parameters:
+        assert np.all(np.abs(coarse[name] - default[name]) < rel_tol * scale)
+
+
+def test_adjoint_sensitivities():
+    solution = solve(NeoHookean(bulk_modulus, shear_modulus))
+    node, coordinate = 10, 1
+    objective_gradient = np.zeros_like(reference_coordinates)
+    objective_gradient[node, coordinate] = 1
+    sensitivities = block.adjoint_sensitivities(
+        solution, top + bottom, objective_gradient
+    )
+    assert list(sensitivities) == parameters
+    step = 1e-4
+    for parameter, (upper, lower) in zip(
+        parameters,
+        [
+            (
+                NeoHookean(bulk_modulus + step, shear_modulus),
+                NeoHookean(bulk_modulus - step, shear_modulus),
+            ),
+            (
+                NeoHookean(bulk_modulus, shear_modulus + step),
+                NeoHookean(bulk_modulus, shear_modulus - step),
+            ),
+        ],
+    ):
+        expected = (
+            solve(upper)[node, coordinate] - solve(lower)[node, coordinate]
+        ) / (2 * step)
+        assert np.abs(expected) > epsilon
+        assert np.abs(sensitivities[parameter] - expected) < rel_tol * np.abs(expected)
+
+
+def test_elastic():
+    elastic = Block(
+        AlmansiHamel(bulk_modulus, shear_modulus), connectivity, reference_coordinates
+    )
+    with raises(TypeError, match="The Helmholtz free energy density is undefined"):
+        elastic.helmholtz_free_energy_sensitivities(coordinates)
+    sensitivities = elastic.nodal_force_sensitivities(coordinates)
+    assert list(sensitivities) == parameters
+
+
+def test_invalid():
+    objective_gradient = np.zeros_like(reference_coordinates)
+    with raises(TypeError, match="Fixed node 14 is out of range for 14 nodes."):
+        block.adjoint_sensitivities(coordinates, [14], objective_gradient)
+    with raises(
+        TypeError, match="Expected 14 objective gradient components, got 13."
+    ):
+        block.adjoint_sensitivities(coordinates, bottom, objective_gradient[:-1])
+    with raises(TypeError, match="Expected 14 nodal coordinates, got 13."):
+        block.nodal_force_sensitivities(coordinates[:-1])
+    with raises(TypeError, match="The relative step must be positive."):
+        block.helmholtz_free_energy_sensitivities(coordinates, relative_step=0)
@@ -0,0 +1,98 @@
+from conspire.constitutive.solid.elastic import AlmansiHamel
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Block
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+epsilon = 1e-6
+rel_tol = 1e-5
+bulk_modulus = 13
+shear_modulus = 3
+stretch = 0.1
+shear = 0.05
+top = [0, 1, 2, 3, 8]
+bottom = [4, 5, 6, 7, 9]
+model = NeoHookean(bulk_modulus, shear_modulus)
+block = Block(model, connectivity, reference_coordinates)
+coordinates = reference_coordinates.copy()
+coordinates[top, 2] += stretch
+coordinates[top, 0] += shear
+
+
+def perturbed(node, coordinate, step):
+    reference = reference_coordinates.copy()
+    reference[node, coordinate] += step
+    return Block(model, connectivity, reference)
+
+
+def test_helmholtz_free_energy_shape_sensitivities():
+    sensitivities = block.helmholtz_free_energy_shape_sensitivities(coordinates)
+    assert sensitivities.shape == reference_coordinates.shape
+    assert np.all(np.abs(sensitivities.sum(axis=0)) < abs_tol)
+    for node in range(len(reference_coordinates)):
+        for coordinate in range(3):
+            expected = (
+                perturbed(node, coordinate, epsilon).helmholtz_free_energy(coordinates)
+                - perturbed(node, coordinate, -epsilon).helmholtz_free_energy(
+                    coordinates
+                )
+            ) / (2 * epsilon)
+            assert np.abs(sensitivities[node, coordinate] - expected) < rel_tol
+
+
+def test_helmholtz_free_energy_shape_sensitivities_reference():
+    sensitivities = block.helmholtz_free_energy_shape_sensitivities(
+        reference_coordinates
+    )
+    assert np.all(np.abs(sensitivities) < abs_tol)
+
+
+def test_shape_sensitivities():
+    solution = block.solve(coordinates, top + bottom, abs_tol=abs_tol)
+    node, coordinate = 10, 1
+    objective_gradient = np.zeros_like(reference_coordinates)
+    objective_gradient[node, coordinate] = 1
+    sensitivities = block.shape_sensitivities(
+        solution, top + bottom, objective_gradient
+    )
+    assert sensitivities.shape == reference_coordinates.shape
+    assert np.all(np.abs(sensitivities.sum(axis=0)) < rel_tol)
+    step = 1e-4
+    for shape_node in [2, 11, 13]:
+        for shape_coordinate in range(3):
+            upper, lower = [
+                perturbed(shape_node, shape_coordinate, sign * step).solve(
+                    coordinates, top + bottom, abs_tol=abs_tol
+                )[node, coordinate]
+                for sign in [1, -1]
+            ]
+            expected = (upper - lower) / (2 * step)
+            assert np.abs(
+                sensitivities[shape_node, shape_coordinate] - expected
+            ) < rel_tol * max(np.abs(expected), 1)
+
+
+def test_elastic():
+    elastic = Block(
+        AlmansiHamel(bulk_modulus, shear_modulus), connectivity, reference_coordinates
+    )
+    with raises(TypeError, match="The Helmholtz free energy density is undefined"):
+        elastic.helmholtz_free_energy_shape_sensitivities(coordinates)
+    objective_gradient = np.zeros_like(reference_coordinates)
+    sensitivities = elastic.shape_sensitivities(coordinates, bottom, objective_gradient)
+    assert np.all(sensitivities == 0)
+
+
+def test_invalid():
+    objective_gradient = np.zeros_like(reference_coordinates)
+    with raises(TypeError, match="Fixed node 14 is out of range for 14 nodes."):
+        block.shape_sensitivities(coordinates, [14], objective_gradient)
+    with raises(
+        TypeError, match="Expected 14 objective gradient components, got 13."
+    ):
+        block.shape_sensitivities(coordinates, bottom, objective_gradient[:-1])
+    with raises(TypeError, match="Expected 14 nodal coordinates, got 13."):
+        block.helmholtz_free_energy_shape_sensitivities(coordinates[:-1])
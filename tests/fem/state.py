@@ -0,0 +1,105 @@
+from conspire.constitutive.solid.elastic import AlmansiHamel
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Block, InvertedElementError, Plasticity
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+models = [
+    AlmansiHamel(13, 3),
+    NeoHookean(13, 3),
+    Plasticity(NeoHookean(13, 3), 0.1, 1),
+]
+coordinates = reference_coordinates @ np.array(
+    [
+        [1.1, 0.05, -0.02],
+        [0.03, 0.95, 0.04],
+        [-0.01, 0.02, 1.05],
+    ]
+).T
+
+
+def test_reference_configuration():
+    for model in models:
+        block = Block(model, connectivity, reference_coordinates)
+        assert np.all(block.nodal_coordinates == reference_coordinates)
+        assert np.all(np.abs(block.nodal_forces()) < 1e-12)
+
+
+def test_set_coordinates():
+    for model in models:
+        block = Block(model, connectivity, reference_coordinates)
+        block.set_coordinates(coordinates)
+        assert np.all(block.nodal_coordinates == coordinates)
+        assert np.all(block.nodal_forces() == block.nodal_forces(coordinates))
+        assert np.all(block.nodal_stiffnesses() == block.nodal_stiffnesses(coordinates))
+        assert np.all(block.nodal_coordinates == coordinates)
+    block = Block(models[1], connectivity, reference_coordinates)
+    block.set_coordinates(coordinates)
+    assert block.helmholtz_free_energy() == block.helmholtz_free_energy(coordinates)
+    assert block.helmholtz_free_energy() > 0
+
+
+def test_update_displacements():
+    for model in models:
+        block = Block(model, connectivity, reference_coordinates)
+        displacements = coordinates - reference_coordinates
+        block.update_displacements(0.5 * displacements)
+        block.update_displacements(0.5 * displacements)
+        assert np.all(np.abs(block.nodal_coordinates - coordinates) < 1e-15)
+        assert np.all(
+            np.abs(block.nodal_forces() - block.nodal_forces(coordinates)) < 1e-12
+        )
+
+
+def test_solve_state():
+    fixed_nodes = list(range(10))
+    initial_coordinates = reference_coordinates.copy()
+    initial_coordinates[fixed_nodes, 2] *= 1.1
+    for model in models[:2]:
+        block = Block(model, connectivity, reference_coordinates)
+        solution = block.solve(initial_coordinates, fixed_nodes, abs_tol=1e-10)
+        assert np.all(block.nodal_coordinates == solution)
+        assert np.all(np.abs(block.nodal_forces()[10:]) < 1e-10)
+        block.reaction_forces(coordinates)
+        block.eigenmodes(coordinates, 3, fixed_nodes)
+        assert np.all(block.nodal_coordinates == solution)
+        with raises(TypeError, match="The maximum number of steps was reached."):
+            block.solve(coordinates, fixed_nodes, abs_tol=1e-10, max_steps=1)
+        assert np.all(block.nodal_coordinates == solution)
+
+
+def test_plastic_state():
+    block = Block(models[2], connectivity, reference_coordinates)
+    block.set_coordinates(coordinates)
+    forces = block.nodal_forces()
+    block.revert_state()
+    assert np.all(block.nodal_forces() == forces)
+    assert np.all(block.equivalent_plastic_strains == 0)
+    block.commit_state()
+    assert np.any(block.equivalent_plastic_strains > 0)
+    block.set_coordinates(reference_coordinates)
+    assert np.any(np.abs(block.nodal_forces()) > 1e-6)
+
+
+def test_invalid_inputs():
+    block = Block(models[1], connectivity, reference_coordinates)
+    with raises(TypeError, match="Expected 14 nodal coordinates, got 13."):
+        block.set_coordinates(coordinates[:13])
+    with raises(TypeError, match="Expected 14 nodal coordinates, got 13."):
+        block.update_displacements(coordinates[:13])
+    assert np.all(block.nodal_coordinates == reference_coordinates)
+    inverted = reference_coordinates.copy()
+    inverted[:, 2] *= -1
+    block.set_coordinates(inverted)
+    with raises(InvertedElementError):
+        block.nodal_forces()
+    with raises(
+        TypeError,
+        match="The Helmholtz free energy density is undefined for elastic "
+        + "constitutive models.",
+    ):
+        Block(models[0], connectivity, reference_coordinates).helmholtz_free_energy()
+    with raises(TypeError, match="Unexpected keyword argument max_iterations."):
+        block.solve(coordinates, [0, 1, 2, 3], max_iterations=1)
@@ -0,0 +1,144 @@
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Block, ThermomechanicalBlock
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+epsilon = 1e-6
+rel_tol = 1e-5
+bulk_modulus = 13
+shear_modulus = 3
+thermal_conductivity = 2
+thermal_expansion_coefficient = 1e-2
+reference_temperature = 300
+top = [0, 1, 2, 3, 8]
+bottom = [4, 5, 6, 7, 9]
+model = NeoHookean(bulk_modulus, shear_modulus)
+coordinates = reference_coordinates.copy()
+coordinates[top, 2] += 0.1
+coordinates[top, 0] += 0.05
+temperatures = reference_temperature + 10 * (reference_coordinates[:, 2] + 0.5)
+
+
+def thermomechanical_block(expansion=thermal_expansion_coefficient):
+    return ThermomechanicalBlock(
+        model,
+        connectivity,
+        reference_coordinates,
+        thermal_conductivity,
+        expansion,
+        reference_temperature=reference_temperature,
+    )
+
+
+def test_isothermal():
+    block = thermomechanical_block()
+    assert np.all(block.nodal_temperatures == reference_temperature)
+    isothermal = Block(model, connectivity, reference_coordinates)
+    assert np.all(
+        np.abs(block.nodal_forces(coordinates) - isothermal.nodal_forces(coordinates))
+        < abs_tol
+    )
+
+
+def test_free_thermal_expansion():
+    block = thermomechanical_block()
+    temperature = reference_temperature + 20
+    expanded = (1 + 20 * thermal_expansion_coefficient) * reference_coordinates
+    uniform = np.full(len(reference_coordinates), temperature)
+    forces = block.nodal_forces(expanded, uniform)
+    assert np.all(np.abs(forces) < abs_tol)
+
+
+def test_steady_conduction():
+    block = thermomechanical_block(0)
+    initial = temperatures.copy()
+    initial[10:] = reference_temperature
+    block.nodal_temperatures = initial
+    solution, solved = block.solve(
+        reference_coordinates, top + bottom, top + bottom, abs_tol=abs_tol
+    )
+    assert np.all(np.abs(solution - reference_coordinates) < abs_tol)
+    assert np.all(np.abs(solved - temperatures) < abs_tol)
+    assert np.all(block.nodal_temperatures == solved)
+    fluxes = block.nodal_fluxes(reference_coordinates)
+    assert np.abs(fluxes[top].sum() - 10 * thermal_conductivity) < abs_tol
+    assert np.abs(fluxes.sum()) < abs_tol
+
+
+def test_monolithic_staggered():
+    solutions = []
+    for staggered in [False, True]:
+        block = thermomechanical_block()
+        block.nodal_temperatures = temperatures
+        solutions.append(
+            block.solve(
+                coordinates, bottom, top + bottom, staggered=staggered, abs_tol=abs_tol
+            )
+        )
+        solution, solved = solutions[-1]
+        assert np.all(np.abs(block.nodal_forces(solution)[10:]) < abs_tol)
+        assert np.all(np.abs(block.nodal_fluxes(solution)[10:]) < abs_tol)
+    assert np.all(np.abs(solutions[0][0] - solutions[1][0]) < 1e-8)
+    assert np.all(np.abs(solutions[0][1] - solutions[1][1]) < 1e-8)
+    assert np.any(np.abs(solutions[0][0][top] - coordinates[top]) > epsilon)
+
+
+def test_tangents_finite_difference():
+    block = thermomechanical_block()
+    stiffnesses = block.nodal_stiffnesses(coordinates, temperatures)
+    conductances = block.nodal_conductances(coordinates, temperatures)
+    thermal_stiffnesses, couplings = block.nodal_couplings(coordinates, temperatures)
+    scale = np.abs(stiffnesses).max()
+    for node in range(len(reference_coordinates)):
+        for i in range(3):
+            upper, lower = coordinates.copy(), coordinates.copy()
+            upper[node, i] += epsilon / 2
+            lower[node, i] -= epsilon / 2
+            difference = (
+                block.nodal_forces(upper, temperatures)
+                - block.nodal_forces(lower, temperatures)
+            ) / epsilon
+            assert np.all(
+                np.abs(stiffnesses[:, node, :, i] - difference) < rel_tol * scale
+            )
+            difference = (
+                block.nodal_fluxes(upper, temperatures)
+                - block.nodal_fluxes(lower, temperatures)
+            ) / epsilon
+            assert np.all(np.abs(couplings[:, node, i] - difference) < rel_tol * scale)
+        upper, lower = temperatures.copy(), temperatures.copy()
+        upper[node] += epsilon / 2
+        lower[node] -= epsilon / 2
+        difference = (
+            block.nodal_forces(coordinates, upper)
+            - block.nodal_forces(coordinates, lower)
+        ) / epsilon
+        assert np.all(np.abs(thermal_stiffnesses[:, node] - difference) < rel_tol)
+        difference = (
+            block.nodal_fluxes(coordinates, upper)
+            - block.nodal_fluxes(coordinates, lower)
+        ) / epsilon
+        assert np.all(np.abs(conductances[:, node] - difference) < rel_tol)
+
+
+def test_invalid():
+    with raises(ValueError, match="Node 14 in element 0 is out of range for 14 nodes."):
+        ThermomechanicalBlock(model, connectivity + 1, reference_coordinates, 1, 1e-3)
+    with raises(ValueError, match="Element 0 has duplicate node 13."):
+        ThermomechanicalBlock(
+            model,
+            np.vstack([[13, 13, 8, 1], connectivity[1:]]),
+            reference_coordinates,
+            1,
+            1e-3,
+        )
+    block = thermomechanical_block()
+    with raises(TypeError, match="Expected 14 nodal temperatures, got 13."):
+        block.nodal_temperatures = temperatures[:-1]
+    with raises(TypeError, match="Expected 14 nodal temperatures, got 13."):
+        block.nodal_forces(coordinates, temperatures[:-1])
+    with raises(TypeError, match="Fixed node 14 is out of range for 14 nodes."):
+        block.solve(coordinates, bottom, [14])
@@ -0,0 +1,181 @@
+from conspire.constitutive.solid.elastic_hyperviscous import AlmansiHamel
+from conspire.constitutive.solid.hyperelastic import (
+    SaintVenantKirchhoff as HyperelasticSaintVenantKirchhoff,
+)
+from conspire.constitutive.solid.hyperviscoelastic import SaintVenantKirchhoff
+from conspire.fem import Block, RigidPlane, Traction, ViscoelasticBlock
+from cube import connectivity, reference_coordinates
+from pytest import raises
+import numpy as np
+
+
+abs_tol = 1e-10
+rel_tol = 1e-6
+bulk_modulus = 13
+shear_modulus = 3
+bulk_viscosity = 11
+shear_viscosity = 1
+top = [0, 1, 2, 3, 8]
+bottom = [4, 5, 6, 7, 9]
+top_faces = [[0, 1, 8], [1, 2, 8], [2, 3, 8], [3, 0, 8]]
+traction = np.array([0.0, 0.0, 0.5])
+model = SaintVenantKirchhoff(
+    bulk_modulus, shear_modulus, bulk_viscosity, shear_viscosity
+)
+block = ViscoelasticBlock(model, connectivity, reference_coordinates)
+elastic = Block(
+    HyperelasticSaintVenantKirchhoff(bulk_modulus, shear_modulus),
+    connectivity,
+    reference_coordinates,
+)
+deformation_gradient = np.array(
+    [
+        [1.1, 0.05, -0.02],
+        [0.03, 0.95, 0.04],
+        [-0.01, 0.02, 1.05],
+    ]
+)
+coordinates = reference_coordinates @ deformation_gradient.T
+velocity_gradient = np.array(
+    [
+        [0.1, 0.2, 0.0],
+        [0.0, -0.1, 0.3],
+        [0.2, 0.0, 0.05],
+    ]
+)
+velocities = coordinates @ velocity_gradient.T
+zero = np.zeros_like(coordinates)
+
+
+def test_hyperelastic():
+    forces = elastic.nodal_forces(coordinates)
+    assert np.all(np.abs(block.nodal_forces(coordinates, zero) - forces) < abs_tol)
+    assert (
+        np.abs(
+            block.helmholtz_free_energy(coordinates)
+            - elastic.helmholtz_free_energy(coordinates)
+        )
+        < abs_tol
+    )
+    assert block.viscous_dissipation(coordinates, zero) == 0
+    assert block.viscous_dissipation(coordinates, velocities) > 0
+
+
+def test_dissipation_potential():
+    assert (
+        np.abs(
+            block.dissipation_potential(coordinates, velocities)
+            - block.viscous_dissipation(coordinates, velocities)
+            - (block.nodal_forces(coordinates, zero) * velocities).sum()
+        )
+        < abs_tol
+    )
+
+
+def test_stiffnesses_finite_difference():
+    epsilon = 1e-6
+    stiffnesses = block.nodal_stiffnesses(coordinates, velocities)
+    scale = np.abs(stiffnesses).max()
+    for node in range(len(reference_coordinates)):
+        for i in range(3):
+            upper, lower = velocities.copy(), velocities.copy()
+            upper[node, i] += epsilon / 2
+            lower[node, i] -= epsilon / 2
+            difference = (
+                block.nodal_forces(coordinates, upper)
+                - block.nodal_forces(coordinates, lower)
+            ) / epsilon
+            assert np.all(
+                np.abs(stiffnesses[:, node, :, i] - difference) < rel_tol * scale
+            )
+    assert np.all(
+        np.abs(stiffnesses - stiffnesses.transpose(1, 0, 3, 2)) < abs_tol * scale
+    )
+
+
+def test_solve_creep():
+    times = [0, 0.1, 0.2, 0.5, 1, 2, 5, 10, 100]
+    history, rates = block.solve(
+        reference_coordinates,
+        bottom,
+        times,
+        loads=[Traction(traction, top_faces, reference_coordinates)],
+    )
+    assert history.shape == rates.shape == (len(times), len(reference_coordinates), 3)
+    assert np.all(history[0] == reference_coordinates)
+    assert np.all(np.abs(history[:, bottom] - reference_coordinates[bottom]) < abs_tol)
+    assert np.all(np.abs(rates[:, bottom]) < abs_tol)
+    assert np.all(np.diff(history[:, 8, 2]) > 0)
+    assert np.all(rates[:-1, 8, 2] > 0)
+    long_term = elastic.solve(
+        reference_coordinates,
+        bottom,
+        loads=[Traction(traction, top_faces, reference_coordinates)],
+        abs_tol=abs_tol,
+    )
+    assert np.all(np.abs(history[-1] - long_term) < rel_tol)
+    assert np.all(np.abs(rates[-1]) < rel_tol)
+
+
+def test_solve_stretch():
+    rate = 0.1
+    times = [0, 0.5, 1]
+    history, rates = block.solve(
+        reference_coordinates,
+        top + bottom,
+        times,
+        fixed_velocities=[[0, 0, rate]] * len(top) + [[0, 0, 0]] * len(bottom),
+    )
+    for time, coordinates, velocities in zip(times, history, rates):
+        assert np.all(
+            np.abs(coordinates[top, 2] - reference_coordinates[top, 2] - rate * time)
+            < abs_tol
+        )
+        assert np.all(np.abs(velocities[top, 2] - rate) < abs_tol)
+        free = [node for node in range(len(coordinates)) if node not in top + bottom]
+        assert np.all(
+            np.abs(block.nodal_forces(coordinates, velocities)[free]) < rel_tol
+        )
+
+
+def test_elastic_hyperviscous():
+    viscous = ViscoelasticBlock(
+        AlmansiHamel(bulk_modulus, shear_modulus, bulk_viscosity, shear_viscosity),
+        connectivity,
+        reference_coordinates,
+    )
+    assert np.all(viscous.nodal_forces(reference_coordinates, zero) == 0)
+    assert viscous.viscous_dissipation(coordinates, velocities) > 0
+    with raises(
+        TypeError,
+        match="The Helmholtz free energy is undefined for elastic-hyperviscous",
+    ):
+        viscous.helmholtz_free_energy(coordinates)
+
+
+def test_invalid():
+    with raises(ValueError, match="Node 14 in element 0 is out of range for 14 nodes."):
+        ViscoelasticBlock(model, connectivity + 1, reference_coordinates)
+    with raises(TypeError, match="Expected 14 nodal coordinates, got 13."):
+        block.nodal_forces(coordinates[:-1], velocities)
+    with raises(TypeError, match="Expected 14 nodal velocities, got 13."):
+        block.nodal_stiffnesses(coordinates, velocities[:-1])
+    with raises(TypeError, match="At least two times are required."):
+        block.solve(reference_coordinates, bottom, [0])
+    with raises(TypeError, match="The times must be strictly increasing."):
+        block.solve(reference_coordinates, bottom, [0, 0])
+    with raises(TypeError, match="At least one node must be fixed."):
+        block.solve(reference_coordinates, [], [0, 1])
+    with raises(TypeError, match="Fixed node 14 is out of range for 14 nodes."):
+        block.solve(reference_coordinates, [14], [0, 1])
+    with raises(TypeError, match="Expected 5 fixed velocities, got 1."):
+        block.solve(reference_coordinates, bottom, [0, 1], fixed_velocities=[[0, 0, 0]])
+    with raises(
+        TypeError, match="Contact loads are not supported by viscoelastic blocks."
+    ):
+        block.solve(
+            reference_coordinates,
+            bottom,
+            [0, 1],
+            loads=[RigidPlane([0, 0, -0.5], [0, 0, 1], top, 1e3)],
+        )
@@ -0,0 +1,118 @@
+from conspire.math.integrate import BDF, SDIRK4, BackwardEuler
+from pytest import raises
+import numpy as np
+
+
+integrators = [BDF, SDIRK4]
+rate = 1e4
+
+
+def relaxation(t, y):
+    return [-rate * (y[0] - np.cos(t))]
+
+
+def relaxation_jacobian(t, y):
+    return [[-rate]]
+
+
+def relaxation_exact(t):
+    factor = rate**2 / (rate**2 + 1)
+    return factor * (np.cos(t) + np.sin(t) / rate - np.exp(-rate * t))
+
+
+def robertson(t, y):
+    return [
+        -0.04 * y[0] + 1e4 * y[1] * y[2],
+        0.04 * y[0] - 1e4 * y[1] * y[2] - 3e7 * y[1] ** 2,
+        3e7 * y[1] ** 2,
+    ]
+
+
+robertson_reference = [0.7158271, 9.185535e-6, 0.2841637]
+
+
+def test_settings():
+    for integrator in integrators:
+        method = integrator(abs_tol=1e-8, rel_tol=1e-7, dt_beta=0.8)
+        assert method.abs_tol == 1e-8
+        assert method.rel_tol == 1e-7
+        assert method.dt_beta == 0.8
+        assert method.dt_cut == 0.5
+        assert method.dt_min == 1e-12
+    method = BackwardEuler(dt=0.1, abs_tol=1e-10, max_steps=10)
+    assert method.dt == 0.1
+    assert method.abs_tol == 1e-10
+    assert method.max_steps == 10
+    method = BackwardEuler()
+    assert method.dt == 0
+    assert method.abs_tol == 1e-12
+    assert method.max_steps == 25
+
+
+def test_stiff_relaxation():
+    for integrator in integrators:
+        for jacobian in [None, relaxation_jacobian]:
+            t, y, dydt = integrator().integrate(relaxation, [0, 2], [0.0], jacobian)
+            assert t[0] == 0 and t[-1] == 2
+            assert np.all(np.diff(t) > 0)
+            assert len(y) == len(dydt) == len(t)
+            exact = relaxation_exact(np.array(t))
+            assert np.allclose(np.array(y)[:, 0], exact, atol=1e-3)
+            assert np.allclose(dydt, [relaxation(t_i, y_i) for t_i, y_i in zip(t, y)])
+    t, y, dydt = BackwardEuler(dt=1e-2).integrate(relaxation, [0, 2], [0.0])
+    assert np.allclose(t, np.linspace(0, 2, 201))
+    assert len(y) == len(dydt) == len(t)
+    error = np.abs(np.array(y)[:, 0] - relaxation_exact(np.array(t)))
+    assert np.all(error < 1e-2)
+    assert np.all(error[np.array(t) > 0.05] < 1e-6)
+    assert np.allclose(dydt, [relaxation(t_i, y_i) for t_i, y_i in zip(t, y)])
+
+
+def test_output_times():
+    times = np.linspace(0, 2, 5)
+    for integrator in integrators:
+        t, y, _ = integrator(abs_tol=1e-8, rel_tol=1e-8).integrate(
+            lambda t, y: [-y[0], y[0] - 2 * y[1]], times, [1.0, 0.0]
+        )
+        assert np.all(np.array(t) == times)
+        exact = np.array([np.exp(-times), np.exp(-times) - np.exp(-2 * times)]).T
+        assert np.allclose(y, exact, atol=1e-4)
+    times = np.linspace(0, 2, 2001)
+    t, y, _ = BackwardEuler().integrate(
+        lambda t, y: [-y[0], y[0] - 2 * y[1]], times, [1.0, 0.0]
+    )
+    assert np.all(np.array(t) == times)
+    exact = np.array([np.exp(-times), np.exp(-times) - np.exp(-2 * times)]).T
+    assert np.allclose(y, exact, atol=1e-3)
+
+
+def test_robertson():
+    for integrator in integrators:
+        _, y, _ = integrator(abs_tol=1e-10, rel_tol=1e-6).integrate(
+            robertson, [0, 40, 1e5], [1.0, 0.0, 0.0]
+        )
+        assert np.allclose(y[1], robertson_reference, rtol=1e-3)
+        assert np.isclose(sum(y[2]), 1)
+    times = np.concatenate([[0], np.geomspace(1e-6, 40, 400)])
+    _, y, _ = BackwardEuler().integrate(robertson, times, [1.0, 0.0, 0.0])
+    assert np.allclose(y[-1], robertson_reference, rtol=1e-2)
+    assert np.isclose(sum(y[-1]), 1)
+
+
+def test_errors():
+    for integrator in [*integrators, BackwardEuler]:
+        with raises(TypeError, match="The time must contain at least two entries."):
+            integrator().integrate(relaxation, [0], [0.0])
+        with raises(TypeError, match="Expected a vector of length 1, got 2."):
+            integrator().integrate(lambda t, y: [1.0, 2.0], [0, 0.5, 1], [1.0])
+    for integrator in integrators:
+        with raises(TypeError, match="The times must be strictly increasing."):
+            integrator().integrate(relaxation, [0, 1, 1], [0.0])
+        with raises(TypeError, match="The minimum step size was reached."):
+            integrator().integrate(lambda t, y: [y[0] ** 2], [0, 2], [1.0])
+        with raises(TypeError, match="Expected a matrix of shape \\(1, 1\\)."):
+            integrator().integrate(relaxation, [0, 1], [0.0], lambda t, y: [[1, 2]])
+    with raises(TypeError, match="A positive time step must be set"):
+        BackwardEuler().integrate(relaxation, [0, 1], [0.0])
+    with raises(TypeError, match="Maximum number of steps \\(25\\) reached."):
+        BackwardEuler().integrate(lambda t, y: [y[0] ** 2], [0, 1, 2], [1.0])
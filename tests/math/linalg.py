@@ -0,0 +1,112 @@
+from conspire.constitutive.solid.hyperelastic import NeoHookean
+from conspire.fem import Mesh
+from conspire.math.linalg import LU, Cholesky, conjugate_gradient, gmres, to_csr
+from pytest import raises
+import numpy as np
+
+
+mesh = Mesh.box([1, 1, 1], [2, 2, 1])
+nodes = len(mesh.reference_nodal_coordinates)
+stiffness = mesh.block(NeoHookean(13, 3)).nodal_stiffnesses()
+stiffness = stiffness.transpose(0, 2, 1, 3).reshape(3 * nodes, 3 * nodes)
+stiffness += np.eye(3 * nodes)
+laplacian = 2 * np.eye(50) - np.eye(50, k=1) - np.eye(50, k=-1)
+nonsymmetric = np.array(
+    [
+        [0.0, 2.0, 0.0, 1.0],
+        [3.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 4.0],
+        [1.0, 0.0, 5.0, 0.0],
+    ]
+)
+
+
+def rhs(size):
+    return np.sin(np.arange(1, size + 1))
+
+
+def test_to_csr():
+    data, indices, indptr = to_csr(laplacian)
+    assert len(data) == 148
+    assert np.all(indptr == np.append(0, np.cumsum([2] + [3] * 48 + [2])))
+    dense = np.zeros((50, 50))
+    for row in range(50):
+        entries = slice(indptr[row], indptr[row + 1])
+        dense[row, indices[entries]] = data[entries]
+    assert np.all(dense == laplacian)
+    nodal = stiffness.reshape(nodes, 3, nodes, 3).transpose(0, 2, 1, 3)
+    for a, b in zip(to_csr(nodal), to_csr(stiffness)):
+        assert np.all(a == b)
+    with raises(TypeError, match="Expected a matrix with 2 or 4 dimensions, got 3."):
+        to_csr(np.zeros((2, 2, 2)))
+
+
+def test_direct():
+    for matrix in [laplacian, stiffness]:
+        vector = rhs(len(matrix))
+        solution = np.linalg.solve(matrix, vector)
+        assert np.allclose(Cholesky(to_csr(matrix)).solve(vector), solution)
+        assert np.allclose(Cholesky(to_csr(np.tril(matrix))).solve(vector), solution)
+        assert np.allclose(LU(to_csr(matrix)).solve(vector), solution)
+    vector = rhs(4)
+    assert np.allclose(
+        LU(to_csr(nonsymmetric)).solve(vector), np.linalg.solve(nonsymmetric, vector)
+    )
+
+
+def test_iterative():
+    for matrix in [laplacian, stiffness]:
+        vector = rhs(len(matrix))
+        solution = np.linalg.solve(matrix, vector)
+        csr = to_csr(matrix)
+        for preconditioner in ["jacobi", None]:
+            assert np.allclose(
+                conjugate_gradient(csr, vector, preconditioner=preconditioner),
+                solution,
+            )
+            assert np.allclose(
+                gmres(csr, vector, preconditioner=preconditioner, restart=50),
+                solution,
+            )
+        assert np.allclose(conjugate_gradient(csr, vector, guess=solution), solution)
+    vector = rhs(4)
+    assert np.allclose(
+        gmres(to_csr(nonsymmetric), vector, preconditioner=None),
+        np.linalg.solve(nonsymmetric, vector),
+    )
+
+
+class Matrix:
+    def __init__(self, matrix):
+        self.data, self.indices, self.indptr = to_csr(matrix)
+        self.shape = matrix.shape
+
+
+def test_sparse_attributes():
+    vector = rhs(50)
+    solution = np.linalg.solve(laplacian, vector)
+    assert np.allclose(Cholesky(Matrix(laplacian)).solve(vector), solution)
+    assert np.allclose(conjugate_gradient(Matrix(laplacian), vector), solution)
+
+
+def test_errors():
+    with raises(TypeError, match="The matrix is not positive definite."):
+        Cholesky(to_csr(-laplacian))
+    with raises(TypeError, match="The matrix is not positive definite."):
+        conjugate_gradient(to_csr(nonsymmetric - 3 * np.eye(4)), rhs(4))
+    with raises(TypeError, match="The matrix is singular."):
+        LU(to_csr(np.ones((3, 3))))
+    with raises(TypeError, match="Expected a vector of length 50, got 3."):
+        LU(to_csr(laplacian)).solve(rhs(3))
+    with raises(TypeError, match="Expected a vector of length 50, got 3."):
+        gmres(to_csr(laplacian), rhs(3))
+    with raises(TypeError, match="Expected a square matrix, got shape"):
+        conjugate_gradient(Matrix(np.ones((2, 3))), rhs(2))
+    with raises(TypeError, match="Unknown preconditioner ilu."):
+        gmres(to_csr(laplacian), rhs(50), preconditioner="ilu")
+    with raises(TypeError, match="The restart length must be positive."):
+        gmres(to_csr(laplacian), rhs(50), restart=0)
+    with raises(TypeError, match="The conjugate gradient method did not converge."):
+        conjugate_gradient(to_csr(laplacian), rhs(50), max_iterations=2)
+    with raises(TypeError, match="GMRES did not converge."):
+        gmres(to_csr(laplacian), rhs(50), max_iterations=2)
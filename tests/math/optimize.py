@@ -0,0 +1,203 @@
+from conspire.math.optimize import (
+    EqualityConstraint,
+    GradientDescent,
+    LineSearch,
+    NewtonRaphson,
+)
+from pytest import raises
+import numpy as np
+
+
+matrix = np.array([[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]])
+vector = np.array([1.0, 2.0, 3.0])
+constraint = EqualityConstraint.linear(np.ones((1, 3)), np.ones(1))
+weights = np.array([1.0, 2.0, 3.0])
+
+
+def quadratic(x):
+    return 0.5 * x @ matrix @ x - vector @ x
+
+
+def quadratic_gradient(x):
+    return matrix @ x - vector
+
+
+def quadratic_hessian(x):
+    return matrix
+
+
+def rosenbrock(x):
+    return (1 - x[0]) ** 2 + 100 * (x[1] - x[0] ** 2) ** 2
+
+
+def rosenbrock_gradient(x):
+    return np.array(
+        [
+            -2 * (1 - x[0]) - 400 * x[0] * (x[1] - x[0] ** 2),
+            200 * (x[1] - x[0] ** 2),
+        ]
+    )
+
+
+def rosenbrock_hessian(x):
+    return np.array(
+        [
+            [2 - 400 * (x[1] - x[0] ** 2) + 800 * x[0] ** 2, -400 * x[0]],
+            [-400 * x[0], 200],
+        ]
+    )
+
+
+def weighted(x):
+    return weights @ x**2
+
+
+def weighted_gradient(x):
+    return 2 * weights * x
+
+
+def weighted_hessian(x):
+    return np.diag(2 * weights)
+
+
+def test_settings():
+    solver = NewtonRaphson(abs_tol=1e-8, max_steps=7)
+    assert solver.abs_tol == 1e-8
+    assert solver.max_steps == 7
+    solver = NewtonRaphson()
+    assert solver.abs_tol == 1e-12
+    assert solver.max_steps == 25
+    solver = GradientDescent(dual=True, rel_tol=1e-6)
+    assert solver.abs_tol == 1e-12
+    assert solver.dual
+    assert solver.max_steps == 250
+    assert solver.rel_tol == 1e-6
+    assert GradientDescent().rel_tol is None
+
+
+def test_newton_raphson():
+    solver = NewtonRaphson()
+    solution = np.linalg.solve(matrix, vector)
+    x = solver.minimize(quadratic, quadratic_gradient, quadratic_hessian, np.zeros(3))
+    assert np.allclose(x, solution)
+    x = solver.minimize(
+        rosenbrock, rosenbrock_gradient, rosenbrock_hessian, [-1.2, 1.0]
+    )
+    assert np.allclose(x, [1, 1])
+
+
+def test_indefinite_hessian():
+    solver = NewtonRaphson(line_search=LineSearch.armijo(), max_steps=100)
+    for initial_guess in [[0.0, 1.0], [0.0, 0.0], [0.5, 1.0]]:
+        assert np.linalg.eigvalsh(rosenbrock_hessian(initial_guess))[0] < 0
+        x = solver.minimize(
+            rosenbrock, rosenbrock_gradient, rosenbrock_hessian, initial_guess
+        )
+        assert np.allclose(x, [1, 1])
+
+
+def test_gradient_descent():
+    solution = np.linalg.solve(matrix, vector)
+    for solver in [GradientDescent(), GradientDescent(line_search=LineSearch.armijo())]:
+        x = solver.minimize(quadratic, quadratic_gradient, np.zeros(3))
+        assert np.allclose(x, solution)
+    x = GradientDescent().minimize(quadratic, quadratic_gradient, solution)
+    assert np.all(x == solution)
+
+
+def test_linear_constraint():
+    solution = np.array([6, 3, 2]) / 11
+    x = NewtonRaphson().minimize(
+        weighted, weighted_gradient, weighted_hessian, np.zeros(3), constraint
+    )
+    assert np.allclose(x, solution)
+    for dual in [False, True]:
+        x = GradientDescent(dual=dual).minimize(
+            weighted, weighted_gradient, np.zeros(3), constraint=constraint
+        )
+        assert np.allclose(x, solution)
+
+
+def test_fixed_constraint():
+    fixed = EqualityConstraint.fixed([0])
+    initial_guess = np.array([0.5, 0.0, 0.0])
+    solution = np.array([0.5, 0.0, 1.5])
+    x = NewtonRaphson().minimize(
+        quadratic, quadratic_gradient, quadratic_hessian, initial_guess, fixed
+    )
+    assert np.allclose(x, solution)
+    x = GradientDescent().minimize(
+        quadratic, quadratic_gradient, initial_guess, fixed
+    )
+    assert np.allclose(x, solution)
+
+
+def test_root():
+    solver = NewtonRaphson()
+    assert np.allclose(
+        solver.root(lambda x: x**2 - 2, lambda x: np.diag(2 * x), [1.0]),
+        [np.sqrt(2)],
+    )
+    x = solver.root(
+        lambda x: np.array([x @ x - 4, x[0] - x[1]]),
+        lambda x: np.array([2 * x, [1, -1]]),
+        [1.0, 2.0],
+    )
+    assert np.allclose(x, [np.sqrt(2), np.sqrt(2)])
+    x = solver.root(
+        lambda x: np.array([x @ x - 4, x[0] - x[1]]),
+        lambda x: np.array([2 * x, [1, -1]]),
+        [1.0, 1.0],
+        EqualityConstraint.fixed([1]),
+    )
+    assert np.allclose(x, [np.sqrt(3), 1])
+
+
+def test_errors():
+    with raises(TypeError, match="Maximum number of steps \\(1\\) reached."):
+        NewtonRaphson(max_steps=1).minimize(
+            rosenbrock, rosenbrock_gradient, rosenbrock_hessian, [-1.2, 1.0]
+        )
+    with raises(TypeError, match="Maximum number of steps \\(2\\) reached."):
+        GradientDescent(max_steps=2).minimize(
+            quadratic, quadratic_gradient, np.zeros(3)
+        )
+    with raises(TypeError, match="Expected a vector of length 3, got 2."):
+        GradientDescent().minimize(quadratic, lambda x: x[:2], np.ones(3))
+    with raises(TypeError, match="Expected a matrix of shape \\(3, 3\\)."):
+        NewtonRaphson().minimize(
+            quadratic, quadratic_gradient, lambda x: matrix[:2], np.ones(3)
+        )
+    with raises(TypeError, match="Expected a constraint matrix with 3 columns"):
+        GradientDescent().minimize(
+            weighted,
+            weighted_gradient,
+            np.zeros(3),
+            EqualityConstraint.linear(np.ones((1, 2)), np.ones(1)),
+        )
+    with raises(TypeError, match="Expected a constraint matrix with 2 rows, got 1."):
+        EqualityConstraint.linear(np.ones((1, 3)), np.ones(2))
+    with raises(TypeError, match="Fixed index 3 is out of bounds"):
+        NewtonRaphson().minimize(
+            quadratic,
+            quadratic_gradient,
+            quadratic_hessian,
+            np.zeros(3),
+            EqualityConstraint.fixed([3]),
+        )
+    with raises(TypeError, match="Matrix is singular."):
+        NewtonRaphson().minimize(
+            weighted,
+            weighted_gradient,
+            weighted_hessian,
+            np.zeros(3),
+            EqualityConstraint.linear(np.ones((2, 3)), np.ones(2)),
+        )
+    with raises(TypeError, match="A line search cannot be used with an equality"):
+        GradientDescent(line_search=LineSearch.armijo()).minimize(
+            weighted, weighted_gradient, np.zeros(3), constraint
+        )
+    with raises(TypeError, match="A line search cannot be used when finding a root."):
+        NewtonRaphson(line_search=LineSearch.armijo()).root(
+            lambda x: x**2 - 2, lambda x: np.diag(2 * x), [1.0]
+        )